@@ -124,6 +124,7 @@ impl Template for ButtonView {
                                 .build(ctx),
                         )
                         .child(NumericBox::new().max(123).step(0.123).val(0.123).build(ctx))
+                        .child(TextArea::new().water_mark("Insert lines...").build(ctx))
                         .build(ctx),
                 )
                 .build(ctx),
@@ -4,6 +4,8 @@ use std::sync::mpsc;
 
 use dces::prelude::Entity;
 
+#[cfg(target_os = "linux")]
+use crate::shell::AtSpiAdapter;
 use crate::{
     core::{application::WindowAdapter, localization::*, *},
     shell::{Shell, ShellRequest},
@@ -68,6 +70,9 @@ impl Application {
             self.localization.clone(),
         );
 
+        #[cfg(target_os = "linux")]
+        let adapter = publish_accessibility_tree(adapter, &self.name);
+
         self.shell
             .create_window_from_settings(settings, adapter)
             .request_receiver(receiver)
@@ -81,3 +86,23 @@ impl Application {
         self.shell.run();
     }
 }
+
+// Publishes the accessibility tree of the window over AT-SPI. Without the AT-SPI bus no
+// assistive technology could read the tree, so it is not created at all.
+#[cfg(target_os = "linux")]
+fn publish_accessibility_tree(mut adapter: WindowAdapter, app_name: &str) -> WindowAdapter {
+    let tree = AccessibilityTree::from_ecm(adapter.entity_component_manager());
+
+    let at_spi = match AtSpiAdapter::new(app_name, AccessKitAdapter::full_tree_update(&tree)) {
+        Some(at_spi) => at_spi,
+        None => return adapter,
+    };
+
+    adapter
+        .registry()
+        .borrow_mut()
+        .get_mut::<Accessibility>("accessibility")
+        .set_adapter(AccessKitAdapter::new(move |update| at_spi.update(update)));
+
+    adapter
+}
@@ -34,6 +34,9 @@ pub mod widgets {
 
 pub mod prelude;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub mod testing;
+
 mod application;
 
 pub use self::application::*;
@@ -0,0 +1,512 @@
+//! This module contains helpers to test widgets without opening a window.
+//!
+//! A `TestWindow` builds the widget tree from a closure and runs it on the headless shell. Input
+//! is simulated by clicking, typing and pressing keys. After `update` is called the widgets could
+//! be looked up by id or style to check their properties and bounds.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use orbtk::{prelude::*, testing::TestWindow};
+//!
+//! let mut window = TestWindow::new(|ctx| {
+//!     Window::new()
+//!         .size(200.0, 100.0)
+//!         .child(TextBox::new().id("input").build(ctx))
+//!         .build(ctx)
+//! });
+//!
+//! window.update();
+//! window.click_widget_by_id("input");
+//! window.type_text("OrbTk");
+//! window.update();
+//!
+//! assert_eq!(window.widget_by_id("input").get::<String>("text"), "OrbTk");
+//! ```
+//!
+//! # Snapshots
+//!
+//! `assert_snapshot` compares the last rendered frame with a golden PNG image. A missing golden
+//! image fails the test. Set the `ORBTK_UPDATE_SNAPSHOTS` environment variable to create new golden
+//! images or to update them after an intended visual change.
+//!
+//! ```rust,no_run
+//! use orbtk::{prelude::*, testing::TestWindow};
+//!
+//! let mut window = TestWindow::new(|ctx| {
+//!     Window::new()
+//!         .size(120.0, 60.0)
+//!         .child(Button::new().text("Click").build(ctx))
+//!         .build(ctx)
+//! });
+//!
+//! window.update();
+//! window.assert_snapshot("tests/snapshots/button.png", 2);
+//! ```
+
+use std::{cell::RefCell, path::Path, sync::mpsc};
+
+use dces::prelude::*;
+
+use crate::{
+    core::{application::WindowAdapter, *},
+    render::{snapshot::Snapshot, RenderContext2D},
+    shell::{Key, MouseButton, ShellRequest},
+    utils::{Color, Point, Rectangle},
+};
+
+use orbtk_orbclient::headless;
+
+/// Maximum number of frames `TestWindow::update` renders until it stops.
+pub const MAX_UPDATE_FRAMES: usize = 100;
+
+/// The `TestWindow` runs a widget tree on the headless shell and provides access to its widgets.
+pub struct TestWindow {
+    window: headless::Window<WindowAdapter>,
+    theme: Rc<Theme>,
+    _shell_requests: mpsc::Receiver<ShellRequest<WindowAdapter>>,
+}
+
+impl TestWindow {
+    /// Creates a new test window from the given closure with the default theme.
+    pub fn new<F: Fn(&mut BuildContext) -> Entity + 'static>(create_fn: F) -> Self {
+        Self::from_theme(crate::widgets::themes::theme_orbtk::theme_default(), create_fn)
+    }
+
+    /// Creates a new test window from the given closure with a custom theme.
+    pub fn from_theme<F: Fn(&mut BuildContext) -> Entity + 'static>(
+        theme: Theme,
+        create_fn: F,
+    ) -> Self {
+        let theme = Rc::new(theme);
+        let (sender, shell_requests) = mpsc::channel();
+
+        let (adapter, settings, receiver) = create_window("", &theme, sender, create_fn, None);
+
+        let mut render_context = RenderContext2D::new(settings.size.0, settings.size.1);
+
+        for (family, font) in settings.fonts {
+            render_context.register_font(&family, font);
+        }
+
+        for face in settings.font_faces {
+            render_context.register_font_face(face);
+        }
+
+        TestWindow {
+            window: headless::Window::new(
+                adapter,
+                render_context,
+                settings.title,
+                settings.size,
+                Some(receiver),
+            ),
+            theme,
+            _shell_requests: shell_requests,
+        }
+    }
+
+    /// Runs the system pipeline until no more changes are pending. Running animations are not
+    /// awaited, their next frames are requested by the timer of the window.
+    ///
+    /// Returns the number of rendered frames.
+    pub fn update(&mut self) -> usize {
+        self.window.run_until_idle(MAX_UPDATE_FRAMES)
+    }
+
+    /// Runs the system pipeline once if there are pending changes.
+    ///
+    /// Returns `true` if a new frame was rendered.
+    pub fn run_frame(&mut self) -> bool {
+        self.window.run_frame()
+    }
+
+    /// Gets a reference of the underlying headless shell window.
+    pub fn shell_window(&self) -> &headless::Window<WindowAdapter> {
+        &self.window
+    }
+
+    /// Gets a mutable reference of the underlying headless shell window.
+    pub fn shell_window_mut(&mut self) -> &mut headless::Window<WindowAdapter> {
+        &mut self.window
+    }
+
+    /// Gets the service registry of the window.
+    pub fn registry(&self) -> Rc<RefCell<Registry>> {
+        self.window.adapter().registry().clone()
+    }
+
+    /// Gets the theme of the window.
+    pub fn theme(&self) -> Rc<Theme> {
+        self.theme.clone()
+    }
+
+    /// Gets the entity component manager that stores the widgets of the window.
+    pub fn entity_component_manager(&mut self) -> &mut EntityComponentManager<Tree> {
+        self.window.adapter_mut().entity_component_manager()
+    }
+
+    // -- Input --
+
+    /// Moves the mouse cursor to the given position.
+    pub fn mouse_move(&mut self, x: f64, y: f64) {
+        self.window.mouse_move(x, y);
+    }
+
+    /// Presses the given mouse button at the current mouse position.
+    pub fn mouse_down(&mut self, button: MouseButton) {
+        self.window.mouse_down(button);
+    }
+
+    /// Releases the given mouse button at the current mouse position.
+    pub fn mouse_up(&mut self, button: MouseButton) {
+        self.window.mouse_up(button);
+    }
+
+    /// Clicks with the left mouse button at the given position.
+    pub fn click(&mut self, x: f64, y: f64) {
+        self.window.click(x, y);
+    }
+
+    /// Clicks with the left mouse button on the center of the given widget.
+    ///
+    /// The position of a widget is calculated on render, call `update` before to get the current position.
+    pub fn click_widget(&mut self, entity: Entity) {
+        let bounds = self.global_bounds(entity);
+        self.click(
+            bounds.x() + bounds.width() / 2.0,
+            bounds.y() + bounds.height() / 2.0,
+        );
+    }
+
+    /// Clicks with the left mouse button on the center of the widget with the given id.
+    ///
+    /// Panics if there is no widget with the given id.
+    pub fn click_widget_by_id(&mut self, id: &str) {
+        let entity = self.expect_entity_by_id(id);
+        self.click_widget(entity);
+    }
+
+    /// Scrolls by the given delta.
+    pub fn scroll(&mut self, delta_x: f64, delta_y: f64) {
+        self.window.scroll(delta_x, delta_y);
+    }
+
+    /// Presses the given key.
+    pub fn key_down(&mut self, key: Key) {
+        self.window.key_down(key, <&'static str>::from(key));
+    }
+
+    /// Releases the given key.
+    pub fn key_up(&mut self, key: Key) {
+        self.window.key_up(key);
+    }
+
+    /// Presses and releases the given key.
+    pub fn press_key(&mut self, key: Key) {
+        self.key_down(key);
+        self.key_up(key);
+    }
+
+    /// Emits a text input event for each character of the given text.
+    pub fn type_text(&mut self, text: &str) {
+        for c in text.chars() {
+            self.window.text_input(c.to_string());
+        }
+    }
+
+    /// Resizes the window.
+    pub fn resize(&mut self, width: f64, height: f64) {
+        self.window.resize(width, height);
+    }
+
+    // -- Tree queries --
+
+    /// Gets the entity of the window widget.
+    pub fn root(&mut self) -> Entity {
+        self.entity_component_manager().entity_store().root()
+    }
+
+    /// Returns the entity of the first widget with the given id.
+    pub fn entity_by_id(&mut self, id: &str) -> Option<Entity> {
+        self.find_entities(|ecm, entity| {
+            ecm.component_store()
+                .get::<String>("id", entity)
+                .map(|i| i == id)
+                .unwrap_or(false)
+        })
+        .first()
+        .copied()
+    }
+
+    /// Returns the entities of all widgets with the given style in tree order.
+    pub fn entities_by_style(&mut self, style: &str) -> Vec<Entity> {
+        self.find_entities(|ecm, entity| {
+            ecm.component_store()
+                .get::<Selector>("selector", entity)
+                .map(|s| s.style.as_deref() == Some(style))
+                .unwrap_or(false)
+        })
+    }
+
+    /// Returns the entities of all widgets with the given type name e.g. `Button` in tree order.
+    pub fn entities_by_name(&mut self, name: &str) -> Vec<Entity> {
+        self.find_entities(|ecm, entity| {
+            ecm.component_store()
+                .get::<String>("name", entity)
+                .map(|n| n == name)
+                .unwrap_or(false)
+        })
+    }
+
+    /// Returns the entity of the widget with the keyboard focus.
+    pub fn focused_entity(&mut self) -> Option<Entity> {
+        let root = self.root();
+        self.entity_component_manager()
+            .component_store()
+            .get::<FocusState>("focus_state", root)
+            .ok()
+            .and_then(|focus_state| *focus_state.focused_entity())
+    }
+
+    /// Returns the widget of the given entity.
+    pub fn widget(&mut self, entity: Entity) -> WidgetContainer<'_> {
+        let theme = &self.theme;
+        WidgetContainer::new(
+            entity,
+            self.window.adapter_mut().entity_component_manager(),
+            theme,
+            None,
+        )
+    }
+
+    /// Returns the widget with the given id.
+    ///
+    /// Panics if there is no widget with the given id.
+    pub fn widget_by_id(&mut self, id: &str) -> WidgetContainer<'_> {
+        let entity = self.expect_entity_by_id(id);
+        self.widget(entity)
+    }
+
+    /// Builds the accessibility tree of the current widgets.
+    pub fn accessibility_tree(&mut self) -> AccessibilityTree {
+        AccessibilityTree::from_ecm(self.entity_component_manager())
+    }
+
+    /// Returns the bounds of the widget with its global position inside of the window.
+    pub fn global_bounds(&mut self, entity: Entity) -> Rectangle {
+        let widget = self.widget(entity);
+        let position: Point = widget.clone("position");
+        let bounds: Rectangle = widget.clone("bounds");
+
+        Rectangle::new(position, bounds.size())
+    }
+
+    // -- Frame --
+
+    /// Returns the pixel data of the last rendered frame.
+    ///
+    /// Byteorder: BGRA
+    pub fn data(&self) -> &[u8] {
+        self.window.data()
+    }
+
+    /// Returns the color of the pixel at the given position of the last rendered frame.
+    pub fn pixel(&self, x: u32, y: u32) -> Option<Color> {
+        self.window.pixel(x, y)
+    }
+
+    /// Takes a snapshot of the last rendered frame.
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot::from_render_context(self.window.render_context())
+    }
+
+    /// Compares the last rendered frame with the golden image stored at `path`. A pixel is mismatched if
+    /// one of its color channels differs by more than `tolerance`.
+    ///
+    /// If `ORBTK_UPDATE_SNAPSHOTS` is set, the frame is stored as new golden image. Panics if the golden image is
+    /// missing or on a mismatch, in the latter case a diff image is written next to the golden image.
+    pub fn assert_snapshot<P: AsRef<Path>>(&self, path: P, tolerance: u8) {
+        if let Err(message) = self.snapshot().assert_matches(path, tolerance) {
+            panic!("TestWindow: {}", message);
+        }
+    }
+
+    // -- Helpers --
+
+    fn expect_entity_by_id(&mut self, id: &str) -> Entity {
+        match self.entity_by_id(id) {
+            Some(entity) => entity,
+            None => panic!("TestWindow: Could not find widget with id: {}.", id),
+        }
+    }
+
+    fn find_entities<F>(&mut self, predicate: F) -> Vec<Entity>
+    where
+        F: Fn(&EntityComponentManager<Tree>, Entity) -> bool,
+    {
+        let ecm: &EntityComponentManager<Tree> = self.entity_component_manager();
+        let root = ecm.entity_store().root();
+
+        ecm.entity_store()
+            .start_node(root)
+            .into_iter()
+            .filter(|entity| predicate(ecm, *entity))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+    use crate::widgets::*;
+
+    #[test]
+    fn test_query_widgets() {
+        let mut window = TestWindow::new(|ctx| {
+            Window::new()
+                .size(200.0, 100.0)
+                .child(
+                    Stack::new()
+                        .child(TextBlock::new().id("first").text("first").build(ctx))
+                        .child(Button::new().text("button").build(ctx))
+                        .build(ctx),
+                )
+                .build(ctx)
+        });
+
+        window.update();
+
+        assert!(window.entity_by_id("first").is_some());
+        assert!(window.entity_by_id("second").is_none());
+        assert_eq!(window.entities_by_style("button").len(), 1);
+        assert_eq!(window.entities_by_name("Button").len(), 1);
+        assert_eq!(window.widget_by_id("first").get::<String>("text"), "first");
+
+        let button = window.entities_by_style("button")[0];
+        let bounds = window.global_bounds(button);
+        assert!(bounds.y() > 0.0);
+        assert!(bounds.height() > 0.0);
+    }
+
+    #[test]
+    fn test_click() {
+        let clicked = Rc::new(Cell::new(0));
+        let counter = clicked.clone();
+
+        let mut window = TestWindow::new(move |ctx| {
+            let counter = counter.clone();
+            Window::new()
+                .size(200.0, 100.0)
+                .child(
+                    Button::new()
+                        .id("button")
+                        .h_align("start")
+                        .v_align("start")
+                        .on_click(move |_, _| {
+                            counter.set(counter.get() + 1);
+                            true
+                        })
+                        .build(ctx),
+                )
+                .build(ctx)
+        });
+
+        window.update();
+        window.click_widget_by_id("button");
+        window.update();
+
+        assert_eq!(clicked.get(), 1);
+
+        window.click(190.0, 90.0);
+        window.update();
+
+        assert_eq!(clicked.get(), 1);
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let mut window = TestWindow::from_theme(Theme::default(), |ctx| {
+            Window::new()
+                .size(40.0, 20.0)
+                .background("#ff0000")
+                .child(
+                    Container::new()
+                        .width(10.0)
+                        .height(10.0)
+                        .h_align("start")
+                        .v_align("start")
+                        .background("#0000ff")
+                        .build(ctx),
+                )
+                .build(ctx)
+        });
+
+        window.update();
+
+        let snapshot = window.snapshot();
+        assert_eq!(snapshot.width(), 40);
+        assert_eq!(snapshot.height(), 20);
+        assert_eq!(&snapshot.data()[0..4], &[0, 0, 255, 255]);
+        assert_eq!(&snapshot.data()[(5 * 40 + 30) * 4..(5 * 40 + 31) * 4], &[255, 0, 0, 255]);
+
+        window.assert_snapshot("tests/snapshots/snapshot.png", 0);
+    }
+
+    #[test]
+    fn test_snapshot_rectangle_radius() {
+        let mut window = TestWindow::from_theme(Theme::default(), |ctx| {
+            Window::new()
+                .size(40.0, 30.0)
+                .background("#ffffff")
+                .child(
+                    Container::new()
+                        .width(30.0)
+                        .height(20.0)
+                        .margin(5.0)
+                        .h_align("start")
+                        .v_align("start")
+                        .background("#0000ff")
+                        .border_radius(6.0)
+                        .border_width(2.0)
+                        .border_brush("#ff0000")
+                        .build(ctx),
+                )
+                .build(ctx)
+        });
+
+        window.update();
+        window.assert_snapshot("tests/snapshots/rectangle_radius.png", 2);
+    }
+
+    #[test]
+    fn test_snapshot_text_alignment() {
+        let theme = Theme::default().register_font(
+            "Roboto-Regular",
+            crate::widgets::themes::theme_orbtk::orbtk_fonts::ROBOTO_REGULAR_FONT,
+        );
+
+        let mut window = TestWindow::from_theme(theme, |ctx| {
+            Window::new()
+                .size(80.0, 40.0)
+                .background("#ffffff")
+                .child(
+                    TextBlock::new()
+                        .text("Text\nalignment")
+                        .text_align("center")
+                        .foreground("#000000")
+                        .localizable(false)
+                        .margin(4.0)
+                        .h_align("start")
+                        .v_align("start")
+                        .build(ctx),
+                )
+                .build(ctx)
+        });
+
+        window.update();
+        window.assert_snapshot("tests/snapshots/text_alignment.png", 2);
+    }
+}
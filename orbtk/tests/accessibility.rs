@@ -0,0 +1,150 @@
+use orbtk::{
+    prelude::*,
+    shell::accesskit::{self, CheckedState, NodeId},
+    testing::TestWindow,
+};
+
+#[test]
+fn test_accessibility_tree() {
+    let mut window = TestWindow::new(|ctx| {
+        Window::new()
+            .title("Settings")
+            .size(200.0, 200.0)
+            .child(
+                Stack::new()
+                    .margin(10.0)
+                    .child(Button::new().id("button").text("Save").build(ctx))
+                    .child(
+                        CheckBox::new()
+                            .id("check_box")
+                            .text("Backup")
+                            .selected(true)
+                            .build(ctx),
+                    )
+                    .child(
+                        PasswordBox::new()
+                            .id("password_box")
+                            .text("secret")
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+            .build(ctx)
+    });
+
+    window.update();
+
+    let root = window.root();
+    let button = window.entity_by_id("button").unwrap();
+    let check_box = window.entity_by_id("check_box").unwrap();
+    let password_box = window.entity_by_id("password_box").unwrap();
+
+    let tree = window.accessibility_tree();
+
+    let node = tree.get(root).unwrap();
+    assert_eq!(node.role, Role::Window);
+    assert_eq!(node.name, "Settings");
+    assert_eq!(node.children, vec![button, check_box, password_box]);
+
+    let node = tree.get(button).unwrap();
+    assert_eq!(node.role, Role::Button);
+    assert_eq!(node.name, "Save");
+    assert_eq!(node.checked, None);
+    assert_eq!(node.parent, Some(root));
+    assert_eq!(node.bounds, window.global_bounds(button));
+    assert_eq!(node.bounds.x(), 10.0);
+    assert_eq!(node.bounds.y(), 10.0);
+
+    let node = tree.get(check_box).unwrap();
+    assert_eq!(node.role, Role::CheckBox);
+    assert_eq!(node.name, "Backup");
+    assert_eq!(node.checked, Some(true));
+    assert_eq!(node.bounds, window.global_bounds(check_box));
+    assert!(node.bounds.y() > 10.0);
+
+    let node = tree.get(password_box).unwrap();
+    assert_eq!(node.role, Role::PasswordInput);
+    assert_eq!(node.name, "Password");
+    assert_eq!(node.value, None);
+    assert_eq!(node.bounds, window.global_bounds(password_box));
+
+    // the password is not exposed by any node
+    assert!(tree.iter().all(|(_, node)| {
+        !node.name.contains("secret")
+            && !node.description.contains("secret")
+            && !node.value.iter().any(|value| value.contains("secret"))
+    }));
+}
+
+#[test]
+fn test_accesskit_adapter() {
+    let updates = Rc::new(RefCell::new(vec![]));
+
+    let mut window = TestWindow::new(|ctx| {
+        Window::new()
+            .title("Settings")
+            .size(200.0, 200.0)
+            .child(
+                Stack::new()
+                    .child(Button::new().id("button").text("Save").build(ctx))
+                    .child(CheckBox::new().id("check_box").text("Backup").build(ctx))
+                    .build(ctx),
+            )
+            .build(ctx)
+    });
+
+    let sink = updates.clone();
+    window
+        .registry()
+        .borrow_mut()
+        .get_mut::<Accessibility>("accessibility")
+        .set_adapter(AccessKitAdapter::new(move |update| {
+            sink.borrow_mut().push(update)
+        }));
+
+    window.update();
+
+    let root = AccessKitAdapter::node_id(window.root());
+    let button = AccessKitAdapter::node_id(window.entity_by_id("button").unwrap());
+    let check_box = AccessKitAdapter::node_id(window.entity_by_id("check_box").unwrap());
+
+    // the first update contains the whole tree
+    {
+        let updates = updates.borrow();
+        let update = &updates[0];
+        let node = |id: NodeId| {
+            update
+                .nodes
+                .iter()
+                .find(|(node_id, _)| *node_id == id)
+                .map(|(_, node)| node.clone())
+                .unwrap()
+        };
+
+        assert_eq!(update.tree.as_ref().map(|tree| tree.root), Some(root));
+        assert_eq!(node(root).role, accesskit::Role::Window);
+        assert_eq!(node(root).name.as_deref(), Some("Settings"));
+        assert_eq!(node(root).children, vec![button, check_box]);
+        assert_eq!(node(button).role, accesskit::Role::Button);
+        assert_eq!(node(button).name.as_deref(), Some("Save"));
+        assert_eq!(node(check_box).checked_state, Some(CheckedState::False));
+    }
+
+    // afterwards only the changed widgets and the focus are sent
+    updates.borrow_mut().clear();
+    window.click_widget_by_id("check_box");
+    window.update();
+
+    let updates = updates.borrow();
+    assert!(!updates.is_empty());
+    assert!(updates.iter().all(|update| update.tree.is_none()));
+    assert!(updates
+        .iter()
+        .flat_map(|update| update.nodes.iter())
+        .all(|(id, _)| *id != button));
+
+    assert!(updates
+        .iter()
+        .flat_map(|update| update.nodes.iter())
+        .any(|(id, node)| *id == check_box && node.checked_state == Some(CheckedState::True)));
+}
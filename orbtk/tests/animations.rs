@@ -0,0 +1,53 @@
+use std::time::Duration;
+
+use orbtk::{prelude::*, testing::TestWindow};
+
+// Updates the animations to the given frame time in milliseconds.
+fn tick(animations: &mut Animations, window: &mut TestWindow, millis: u64) -> bool {
+    let theme = window.theme();
+    animations.tick(
+        Duration::from_millis(millis),
+        window.entity_component_manager(),
+        &theme,
+        None,
+    )
+}
+
+#[test]
+fn test_animations_tick() {
+    let mut window = TestWindow::new(|ctx| {
+        Window::new()
+            .size(100.0, 100.0)
+            .child(Container::new().id("container").opacity(0.0).build(ctx))
+            .build(ctx)
+    });
+
+    window.update();
+
+    let container = window.entity_by_id("container").unwrap();
+    let mut animations = Animations::new();
+
+    animations.add(
+        container,
+        "opacity",
+        Animation::new(0.0_f32, 1.0).duration(Duration::from_millis(100)),
+    );
+
+    // the first tick starts the animation
+    assert!(tick(&mut animations, &mut window, 1000));
+    assert_eq!(*window.widget(container).get::<f32>("opacity"), 0.0);
+
+    assert!(tick(&mut animations, &mut window, 1050));
+    assert_eq!(*window.widget(container).get::<f32>("opacity"), 0.5);
+    assert!(animations.contains(container, "opacity"));
+
+    // finished animations are removed and keep their end value
+    assert!(!tick(&mut animations, &mut window, 1100));
+    assert_eq!(*window.widget(container).get::<f32>("opacity"), 1.0);
+    assert!(animations.is_empty());
+
+    // animations of unknown properties are dropped
+    animations.add(container, "unknown", Animation::new(0.0_f32, 1.0));
+    assert!(!tick(&mut animations, &mut window, 1200));
+    assert!(animations.is_empty());
+}
@@ -0,0 +1,73 @@
+use std::cell::Cell;
+
+use orbtk::{prelude::*, shell::Key, testing::TestWindow};
+
+#[test]
+fn test_command() {
+    let executed = Rc::new(Cell::new(0));
+    let executable = Rc::new(Cell::new(false));
+
+    let mut window = TestWindow::new(|ctx| {
+        Window::new()
+            .size(200.0, 100.0)
+            .child(
+                Stack::new()
+                    .child(Button::new().id("save").command("save").build(ctx))
+                    .child(
+                        Button::new()
+                            .id("disabled")
+                            .command("save")
+                            .enabled(false)
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+            .build(ctx)
+    });
+
+    let counter = executed.clone();
+    let can_execute = executable.clone();
+    window
+        .registry()
+        .borrow_mut()
+        .get_mut::<Commands>("commands")
+        .register(
+            "save",
+            Command::new("Save", move |_, _| counter.set(counter.get() + 1))
+                .can_execute(move || can_execute.get()),
+        );
+
+    window.update();
+    assert!(!window.widget_by_id("save").is_enabled());
+
+    window.click_widget_by_id("save");
+    window.update();
+    assert_eq!(executed.get(), 0);
+
+    // the executable state is checked after the next event
+    executable.set(true);
+    window.mouse_move(0.0, 0.0);
+    window.update();
+
+    assert!(window.widget_by_id("save").is_enabled());
+    assert!(!window.widget_by_id("disabled").is_enabled());
+    assert!(*window
+        .widget_by_id("disabled")
+        .get::<bool>("command_enabled"));
+
+    window.click_widget_by_id("save");
+    window.update();
+    assert_eq!(executed.get(), 1);
+
+    window
+        .registry()
+        .borrow_mut()
+        .get_mut::<Shortcuts>("shortcuts")
+        .register_command(ShortcutScope::Window, "Ctrl+S", "save");
+
+    window.key_down(Key::Control);
+    window.press_key(Key::S(false));
+    window.key_up(Key::Control);
+    window.update();
+    assert_eq!(executed.get(), 2);
+}
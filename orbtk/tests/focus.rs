@@ -0,0 +1,103 @@
+use orbtk::{prelude::*, shell::Key, testing::TestWindow};
+
+#[test]
+fn test_focus_tab_order() {
+    let mut window = TestWindow::new(|ctx| {
+        Window::new()
+            .size(200.0, 200.0)
+            .child(
+                Stack::new()
+                    .child(Button::new().id("first").build(ctx))
+                    .child(Button::new().id("disabled").enabled(false).build(ctx))
+                    .child(Button::new().id("second").build(ctx))
+                    .child(
+                        Button::new()
+                            .id("collapsed")
+                            .visibility("collapsed")
+                            .build(ctx),
+                    )
+                    .child(Button::new().id("third").build(ctx))
+                    .build(ctx),
+            )
+            .build(ctx)
+    });
+
+    window.update();
+    assert_eq!(window.focused_entity(), None);
+
+    let first = window.entity_by_id("first");
+    let second = window.entity_by_id("second");
+    let third = window.entity_by_id("third");
+
+    window.press_key(Key::Tab);
+    window.update();
+    assert_eq!(window.focused_entity(), first);
+    assert!(*window.widget_by_id("first").get::<bool>("focused"));
+
+    // the disabled and the collapsed button are skipped
+    window.press_key(Key::Tab);
+    window.update();
+    assert_eq!(window.focused_entity(), second);
+    assert!(!*window.widget_by_id("first").get::<bool>("focused"));
+
+    window.press_key(Key::Tab);
+    window.update();
+    assert_eq!(window.focused_entity(), third);
+
+    window.press_key(Key::Tab);
+    window.update();
+    assert_eq!(window.focused_entity(), first);
+
+    window.key_down(Key::ShiftL);
+    window.press_key(Key::Tab);
+    window.key_up(Key::ShiftL);
+    window.update();
+    assert_eq!(window.focused_entity(), third);
+}
+
+#[test]
+fn test_focus_modal_scope() {
+    let mut window = TestWindow::new(|ctx| {
+        let popup = Popup::new()
+            .open(true)
+            .modal(true)
+            .child(
+                Stack::new()
+                    .child(Button::new().id("ok").build(ctx))
+                    .child(Button::new().id("cancel").build(ctx))
+                    .build(ctx),
+            )
+            .build(ctx);
+        ctx.append_child_to_overlay(popup).unwrap();
+
+        Window::new()
+            .size(200.0, 200.0)
+            .child(Button::new().id("outside").build(ctx))
+            .build(ctx)
+    });
+
+    window.update();
+
+    let ok = window.entity_by_id("ok");
+    let cancel = window.entity_by_id("cancel");
+
+    window.press_key(Key::Tab);
+    window.update();
+    assert_eq!(window.focused_entity(), ok);
+
+    window.press_key(Key::Tab);
+    window.update();
+    assert_eq!(window.focused_entity(), cancel);
+
+    // the focus wraps around inside of the popup
+    window.press_key(Key::Tab);
+    window.update();
+    assert_eq!(window.focused_entity(), ok);
+
+    window.key_down(Key::ShiftL);
+    window.press_key(Key::Tab);
+    window.key_up(Key::ShiftL);
+    window.update();
+    assert_eq!(window.focused_entity(), cancel);
+    assert!(!*window.widget_by_id("outside").get::<bool>("focused"));
+}
@@ -0,0 +1,165 @@
+use std::cell::Cell;
+
+use orbtk::{prelude::*, shell::Key, testing::TestWindow};
+
+#[test]
+fn test_keyboard_click() {
+    let clicks = Rc::new(Cell::new((0, 0)));
+    let counter = clicks.clone();
+
+    let mut window = TestWindow::new(move |ctx| {
+        let first = counter.clone();
+        let second = counter.clone();
+
+        Window::new()
+            .size(200.0, 100.0)
+            .child(
+                Stack::new()
+                    .child(
+                        Button::new()
+                            .id("first")
+                            .on_click(move |_, _| {
+                                let (count, other) = first.get();
+                                first.set((count + 1, other));
+                                true
+                            })
+                            .build(ctx),
+                    )
+                    .child(
+                        Button::new()
+                            .id("second")
+                            .on_click(move |_, _| {
+                                let (other, count) = second.get();
+                                second.set((other, count + 1));
+                                true
+                            })
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+            .build(ctx)
+    });
+
+    window.update();
+
+    // no button is focused
+    window.press_key(Key::Space);
+    window.press_key(Key::Enter);
+    window.update();
+    assert_eq!(clicks.get(), (0, 0));
+
+    window.press_key(Key::Tab);
+    window.update();
+    window.press_key(Key::Space);
+    window.update();
+    assert_eq!(clicks.get(), (1, 0));
+
+    window.press_key(Key::Enter);
+    window.update();
+    assert_eq!(clicks.get(), (2, 0));
+
+    // only the focused button is clicked
+    window.press_key(Key::Tab);
+    window.update();
+    window.press_key(Key::Enter);
+    window.update();
+    assert_eq!(clicks.get(), (2, 1));
+}
+
+#[test]
+fn test_combo_box_keys() {
+    let mut window = TestWindow::new(|ctx| {
+        Window::new()
+            .size(200.0, 100.0)
+            .child(
+                ComboBox::new()
+                    .id("combo_box")
+                    .count(3)
+                    .items_builder(|bc, index| TextBlock::new().text(index.to_string()).build(bc))
+                    .selected_index(0)
+                    .build(ctx),
+            )
+            .build(ctx)
+    });
+
+    let selected_index = |window: &mut TestWindow| {
+        *window
+            .widget_by_id("combo_box")
+            .get::<i32>("selected_index")
+    };
+
+    window.update();
+
+    // the arrow keys are ignored without focus
+    window.press_key(Key::Down);
+    window.update();
+    assert_eq!(selected_index(&mut window), 0);
+
+    window.press_key(Key::Tab);
+    window.update();
+    window.press_key(Key::Down);
+    window.update();
+    assert_eq!(selected_index(&mut window), 1);
+
+    window.press_key(Key::End);
+    window.update();
+    assert_eq!(selected_index(&mut window), 2);
+
+    // the selection stops at the last item
+    window.press_key(Key::Down);
+    window.update();
+    assert_eq!(selected_index(&mut window), 2);
+
+    window.press_key(Key::Up);
+    window.update();
+    assert_eq!(selected_index(&mut window), 1);
+}
+
+#[test]
+fn test_tab_widget_keys() {
+    let mut window = TestWindow::new(|ctx| {
+        Window::new()
+            .size(200.0, 100.0)
+            .child(
+                TabWidget::new()
+                    .tab("One", TextBlock::new().id("one").text("one").build(ctx))
+                    .tab("Two", TextBlock::new().id("two").text("two").build(ctx))
+                    .build(ctx),
+            )
+            .build(ctx)
+    });
+
+    window.update();
+
+    window.press_key(Key::Tab);
+    window.update();
+    window.press_key(Key::Right);
+    window.update();
+    assert_eq!(
+        *window.widget_by_id("one").get::<Visibility>("visibility"),
+        Visibility::Collapsed
+    );
+    assert_eq!(
+        *window.widget_by_id("two").get::<Visibility>("visibility"),
+        Visibility::Visible
+    );
+
+    // the selection stops at the last tab
+    window.press_key(Key::Right);
+    window.update();
+    assert_eq!(
+        *window.widget_by_id("two").get::<Visibility>("visibility"),
+        Visibility::Visible
+    );
+
+    window.press_key(Key::Left);
+    window.update();
+    assert_eq!(
+        *window.widget_by_id("one").get::<Visibility>("visibility"),
+        Visibility::Visible
+    );
+    assert_eq!(
+        *window.widget_by_id("two").get::<Visibility>("visibility"),
+        Visibility::Collapsed
+    );
+}
@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+use orbtk::{prelude::*, testing::TestWindow};
+
+#[test]
+fn test_popup_fade() {
+    // the frames of the fade are requested by the timer
+    fn wait_for_opacity(window: &mut TestWindow, opacity: f32) {
+        for _ in 0..20 {
+            if *window.widget_by_id("popup").get::<f32>("opacity") == opacity {
+                return;
+            }
+
+            std::thread::sleep(Duration::from_millis(50));
+            window.update();
+        }
+    }
+
+    let mut window = TestWindow::new(|ctx| {
+        let popup = Popup::new().id("popup").open(true).build(ctx);
+        ctx.append_child_to_overlay(popup).unwrap();
+
+        Window::new().size(100.0, 100.0).build(ctx)
+    });
+
+    window.update();
+    assert_eq!(
+        *window.widget_by_id("popup").get::<Visibility>("visibility"),
+        Visibility::Visible
+    );
+    assert!(*window.widget_by_id("popup").get::<f32>("opacity") < 1.0);
+
+    wait_for_opacity(&mut window, 1.0);
+    assert_eq!(*window.widget_by_id("popup").get::<f32>("opacity"), 1.0);
+
+    // the popup stays visible until it is faded out
+    window.widget_by_id("popup").set("open", false);
+    window.update();
+    assert_eq!(
+        *window.widget_by_id("popup").get::<Visibility>("visibility"),
+        Visibility::Visible
+    );
+
+    wait_for_opacity(&mut window, 0.0);
+    assert_eq!(*window.widget_by_id("popup").get::<f32>("opacity"), 0.0);
+    assert_eq!(
+        *window.widget_by_id("popup").get::<Visibility>("visibility"),
+        Visibility::Hidden
+    );
+}
@@ -0,0 +1,111 @@
+use orbtk::{prelude::*, testing::TestWindow};
+
+#[test]
+fn test_damaged_region() {
+    let mut window = TestWindow::from_theme(Theme::default(), |ctx| {
+        Window::new()
+            .size(60.0, 20.0)
+            .background("#ffffff")
+            .child(
+                Stack::new()
+                    .orientation("horizontal")
+                    .h_align("start")
+                    .v_align("start")
+                    .child(
+                        Container::new()
+                            .id("left")
+                            .width(20.0)
+                            .height(20.0)
+                            .background("#ff0000")
+                            .build(ctx),
+                    )
+                    .child(
+                        Container::new()
+                            .id("right")
+                            .width(20.0)
+                            .height(20.0)
+                            .background("#0000ff")
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+            .build(ctx)
+    });
+
+    window.update();
+    assert_eq!(window.pixel(10, 10), Some(Color::rgb(255, 0, 0)));
+    assert_eq!(window.pixel(30, 10), Some(Color::rgb(0, 0, 255)));
+
+    // only the area of the dirty widget is repainted, the change of the left widget is not
+    // visible because it is not dirty
+    window
+        .widget_by_id("left")
+        .set_non_dirty("background", Brush::from("#00ff00"));
+    window
+        .widget_by_id("right")
+        .set("background", Brush::from("#ffff00"));
+    window.mouse_move(59.0, 19.0);
+    window.update();
+
+    assert_eq!(window.pixel(10, 10), Some(Color::rgb(255, 0, 0)));
+    assert_eq!(window.pixel(30, 10), Some(Color::rgb(255, 255, 0)));
+
+    // a resized window is repainted completely
+    window.resize(80.0, 30.0);
+    window.update();
+
+    assert_eq!(window.pixel(10, 10), Some(Color::rgb(0, 255, 0)));
+    assert_eq!(window.pixel(30, 10), Some(Color::rgb(255, 255, 0)));
+    assert_eq!(window.pixel(70, 25), Some(Color::rgb(255, 255, 255)));
+}
+
+#[test]
+fn test_layer_cache() {
+    let mut window = TestWindow::from_theme(Theme::default(), |ctx| {
+        Window::new()
+            .size(40.0, 40.0)
+            .background("#ffffff")
+            .child(
+                Container::new()
+                    .cache(true)
+                    .width(20.0)
+                    .height(20.0)
+                    .h_align("start")
+                    .v_align("start")
+                    .child(
+                        Container::new()
+                            .id("child")
+                            .background("#ff0000")
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+            .build(ctx)
+    });
+
+    window.update();
+    assert_eq!(window.pixel(10, 10), Some(Color::rgb(255, 0, 0)));
+
+    // the child is changed without being dirty, the repainted window reuses the layer
+    window
+        .widget_by_id("child")
+        .set_non_dirty("background", Brush::from("#0000ff"));
+    let root = window.root();
+    window
+        .widget(root)
+        .set("background", Brush::from("#eeeeee"));
+    window.mouse_move(39.0, 39.0);
+    window.update();
+
+    assert_eq!(window.pixel(30, 30), Some(Color::rgb(238, 238, 238)));
+    assert_eq!(window.pixel(10, 10), Some(Color::rgb(255, 0, 0)));
+
+    // a dirty child removes the layer of the subtree
+    window
+        .widget_by_id("child")
+        .set("background", Brush::from("#00ff00"));
+    window.mouse_move(38.0, 38.0);
+    window.update();
+
+    assert_eq!(window.pixel(10, 10), Some(Color::rgb(0, 255, 0)));
+}
@@ -0,0 +1,82 @@
+use orbtk::{prelude::*, shell::Key, testing::TestWindow};
+
+#[test]
+fn test_text_area() {
+    let mut window = TestWindow::new(|ctx| {
+        Window::new()
+            .size(200.0, 200.0)
+            .child(
+                TextArea::new()
+                    .id("text_area")
+                    .h_align("start")
+                    .v_align("start")
+                    .build(ctx),
+            )
+            .build(ctx)
+    });
+
+    let cursor = |window: &mut TestWindow| {
+        window
+            .widget_by_id("text_area")
+            .get::<TextSelection>("selection")
+            .start()
+    };
+    let scroll_top = |window: &mut TestWindow| {
+        window
+            .widget_by_id("id_scroll_viewer")
+            .get::<Thickness>("padding")
+            .top()
+    };
+
+    window.update();
+    window.click_widget_by_id("text_area");
+    window.update();
+
+    window.type_text("111");
+    window.press_key(Key::Enter);
+    window.type_text("222");
+    window.press_key(Key::Enter);
+    window.type_text("333");
+    window.update();
+
+    assert_eq!(
+        window.widget_by_id("text_area").get::<String>("text"),
+        "111\n222\n333"
+    );
+    assert_eq!(cursor(&mut window), 11);
+
+    // the cursor keeps its position inside of the line
+    window.press_key(Key::Up);
+    window.update();
+    assert_eq!(cursor(&mut window), 7);
+
+    window.press_key(Key::Up);
+    window.update();
+    assert_eq!(cursor(&mut window), 3);
+
+    // moves to the start of the first line
+    window.press_key(Key::Up);
+    window.update();
+    assert_eq!(cursor(&mut window), 0);
+
+    window.press_key(Key::Down);
+    window.update();
+    assert_eq!(cursor(&mut window), 4);
+    assert_eq!(scroll_top(&mut window), 0.0);
+
+    // the text is scrolled to keep the cursor visible
+    window.press_key(Key::End);
+    for _ in 0..10 {
+        window.press_key(Key::Enter);
+    }
+    window.update();
+    assert_eq!(cursor(&mut window), 17);
+    assert!(scroll_top(&mut window) < 0.0);
+
+    for _ in 0..20 {
+        window.press_key(Key::Up);
+    }
+    window.update();
+    assert_eq!(cursor(&mut window), 0);
+    assert_eq!(scroll_top(&mut window), 0.0);
+}
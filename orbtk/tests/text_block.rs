@@ -0,0 +1,44 @@
+use orbtk::{prelude::*, testing::TestWindow};
+
+#[test]
+fn test_link_clicked() {
+    let links = Rc::new(RefCell::new(vec![]));
+    let clicked_links = links.clone();
+
+    let mut window = TestWindow::new(move |ctx| {
+        let clicked_links = clicked_links.clone();
+        Window::new()
+            .size(200.0, 100.0)
+            .child(
+                TextBlock::new()
+                    .id("text")
+                    .h_align("start")
+                    .v_align("start")
+                    .spans(
+                        TextSpans::new()
+                            .span(TextSpan::new("docs").link("https://docs.rs/orbtk"))
+                            .span(" and some more text"),
+                    )
+                    .on_link_clicked(move |_, _, link| clicked_links.borrow_mut().push(link))
+                    .build(ctx),
+            )
+            .build(ctx)
+    });
+
+    window.update();
+
+    let text = window.entity_by_id("text").unwrap();
+    let bounds = window.global_bounds(text);
+    let y = bounds.y() + bounds.height() / 2.0;
+
+    window.click(bounds.x() + 4.0, y);
+    window.update();
+
+    assert_eq!(*links.borrow(), vec!["https://docs.rs/orbtk".to_string()]);
+
+    // the last span is no link
+    window.click(bounds.x() + bounds.width() - 4.0, y);
+    window.update();
+
+    assert_eq!(links.borrow().len(), 1);
+}
@@ -0,0 +1,324 @@
+use std::collections::BTreeMap;
+
+use dces::prelude::{Component, Entity, EntityComponentManager};
+
+use crate::{
+    properties::FocusState,
+    tree::Tree,
+    utils::{Point, Rectangle, Visibility},
+};
+
+use super::Role;
+
+/// Describes a widget for assistive technologies.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AccessibleNode {
+    /// The kind of the widget.
+    pub role: Role,
+
+    /// The name that is read by screen readers, e.g. the text of a button.
+    pub name: String,
+
+    /// An additional description of the widget.
+    pub description: String,
+
+    /// The current value of inputs, sliders and progress bars.
+    pub value: Option<String>,
+
+    /// The state of check boxes, switches and toggle buttons.
+    pub checked: Option<bool>,
+
+    /// The selection state of list items and tabs.
+    pub selected: Option<bool>,
+
+    /// `true` if the widget has the keyboard focus.
+    pub focused: bool,
+
+    /// `false` if the widget is disabled.
+    pub enabled: bool,
+
+    /// The area of the window the widget covers.
+    pub bounds: Rectangle,
+
+    /// The next described parent of the widget.
+    pub parent: Option<Entity>,
+
+    /// The described children of the widget.
+    pub children: Vec<Entity>,
+}
+
+/// Describes a change of the accessibility tree.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccessibilityEvent {
+    /// A widget is added to the tree.
+    Added(Entity),
+
+    /// A widget is removed from the tree.
+    Removed(Entity),
+
+    /// A property or the children of a widget have changed.
+    Changed(Entity),
+
+    /// The keyboard focus has moved to the given widget.
+    FocusChanged(Option<Entity>),
+}
+
+/// The `AccessibilityTree` describes the widgets of a window for assistive technologies. It is
+/// derived from the widget tree, only visible widgets with a `role` are part of it.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AccessibilityTree {
+    root: Option<Entity>,
+    focused: Option<Entity>,
+    nodes: BTreeMap<Entity, AccessibleNode>,
+}
+
+impl AccessibilityTree {
+    /// Creates a new empty accessibility tree.
+    pub fn new() -> Self {
+        AccessibilityTree::default()
+    }
+
+    /// Creates the accessibility tree of the widgets of a window.
+    pub fn from_ecm(ecm: &EntityComponentManager<Tree>) -> Self {
+        let root = ecm.entity_store().root();
+        let mut tree = AccessibilityTree::new();
+
+        tree.root = Some(root);
+        tree.focused = ecm
+            .component_store()
+            .get::<FocusState>("focus_state", root)
+            .ok()
+            .and_then(|focus_state| *focus_state.focused_entity());
+
+        tree.collect(ecm, root, None);
+        tree
+    }
+
+    /// Returns the root of the tree.
+    pub fn root(&self) -> Option<Entity> {
+        self.root
+    }
+
+    /// Returns the widget with the keyboard focus.
+    pub fn focused(&self) -> Option<Entity> {
+        self.focused
+    }
+
+    /// Returns the description of the given widget.
+    pub fn get(&self, entity: Entity) -> Option<&AccessibleNode> {
+        self.nodes.get(&entity)
+    }
+
+    /// Returns an iterator over all described widgets.
+    pub fn iter(&self) -> impl Iterator<Item = (&Entity, &AccessibleNode)> {
+        self.nodes.iter()
+    }
+
+    /// Inserts the description of a widget.
+    pub fn insert(&mut self, entity: Entity, node: AccessibleNode) {
+        if self.root.is_none() {
+            self.root = Some(entity);
+        }
+
+        self.nodes.insert(entity, node);
+    }
+
+    /// Sets the widget with the keyboard focus.
+    pub fn set_focused(&mut self, focused: Option<Entity>) {
+        self.focused = focused;
+    }
+
+    /// Returns the number of described widgets.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if no widget is described.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the changes from the `previous` tree to this tree.
+    pub fn changes(&self, previous: &AccessibilityTree) -> Vec<AccessibilityEvent> {
+        let mut events = vec![];
+
+        for entity in previous.nodes.keys() {
+            if !self.nodes.contains_key(entity) {
+                events.push(AccessibilityEvent::Removed(*entity));
+            }
+        }
+
+        for (entity, node) in &self.nodes {
+            match previous.nodes.get(entity) {
+                None => events.push(AccessibilityEvent::Added(*entity)),
+                Some(previous_node) if previous_node != node => {
+                    events.push(AccessibilityEvent::Changed(*entity))
+                }
+                _ => {}
+            }
+        }
+
+        if self.focused != previous.focused {
+            events.push(AccessibilityEvent::FocusChanged(self.focused));
+        }
+
+        events
+    }
+
+    // Adds the widget if it has a role and walks down its children. The root is always added.
+    fn collect(
+        &mut self,
+        ecm: &EntityComponentManager<Tree>,
+        entity: Entity,
+        parent: Option<Entity>,
+    ) {
+        if !is_visible(ecm, entity) {
+            return;
+        }
+
+        let role = property(ecm, entity, "role", Role::Unknown);
+
+        let parent = if role != Role::Unknown || parent.is_none() {
+            let mut node = describe(ecm, entity, role);
+            node.parent = parent;
+            node.focused = self.focused == Some(entity);
+
+            if let Some(parent) = parent.and_then(|parent| self.nodes.get_mut(&parent)) {
+                parent.children.push(entity);
+            }
+
+            self.nodes.insert(entity, node);
+
+            if !role.has_children() {
+                return;
+            }
+
+            Some(entity)
+        } else {
+            parent
+        };
+
+        if let Some(children) = ecm.entity_store().children.get(&entity) {
+            for child in children {
+                self.collect(ecm, *child, parent);
+            }
+        }
+    }
+}
+
+// -- Helpers --
+
+fn property<T: Clone + Component>(
+    ecm: &EntityComponentManager<Tree>,
+    entity: Entity,
+    key: &str,
+    default: T,
+) -> T {
+    ecm.component_store()
+        .get::<T>(key, entity)
+        .map_or(default, |value| value.clone())
+}
+
+fn try_property<T: Clone + Component>(
+    ecm: &EntityComponentManager<Tree>,
+    entity: Entity,
+    key: &str,
+) -> Option<T> {
+    ecm.component_store().get::<T>(key, entity).ok().cloned()
+}
+
+fn is_visible(ecm: &EntityComponentManager<Tree>, entity: Entity) -> bool {
+    ecm.component_store()
+        .get::<Visibility>("visibility", entity)
+        .map_or(true, |visibility| *visibility == Visibility::Visible)
+}
+
+// Reads the accessible properties of a widget. Without an accessible name the text of the
+// widget is used, the text of a password box is never exposed.
+fn describe(ecm: &EntityComponentManager<Tree>, entity: Entity, role: Role) -> AccessibleNode {
+    let mut name = property(ecm, entity, "accessible_name", String::new());
+
+    if name.is_empty() {
+        let key = match role {
+            Role::Window => "title",
+            Role::TextInput | Role::TextArea | Role::PasswordInput => "water_mark",
+            _ => "text",
+        };
+        name = property(ecm, entity, key, String::new());
+    }
+
+    let value = match role {
+        Role::TextInput | Role::TextArea => try_property::<String>(ecm, entity, "text"),
+        Role::Slider | Role::SpinButton | Role::ProgressBar => {
+            try_property::<f64>(ecm, entity, "val").map(|val| val.to_string())
+        }
+        _ => None,
+    };
+
+    let selected = try_property::<bool>(ecm, entity, "selected");
+
+    let (checked, selected) = match role {
+        Role::CheckBox | Role::Switch | Role::ToggleButton => (selected, None),
+        Role::ListItem | Role::Tab => (None, selected),
+        _ => (None, None),
+    };
+
+    let bounds = property(ecm, entity, "bounds", Rectangle::default());
+    let position = property(ecm, entity, "position", Point::default());
+
+    AccessibleNode {
+        role,
+        name,
+        description: property(ecm, entity, "accessible_description", String::new()),
+        value,
+        checked,
+        selected,
+        focused: false,
+        enabled: property(ecm, entity, "enabled", true)
+            && property(ecm, entity, "command_enabled", true),
+        bounds: Rectangle::new(position, bounds.size()),
+        parent: None,
+        children: vec![],
+    }
+}
+
+// -- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(role: Role, name: &str) -> AccessibleNode {
+        AccessibleNode {
+            role,
+            name: name.to_string(),
+            enabled: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_changes() {
+        let mut previous = AccessibilityTree::new();
+        previous.insert(1.into(), node(Role::Window, "Window"));
+        previous.insert(2.into(), node(Role::Button, "Save"));
+        previous.insert(3.into(), node(Role::Label, "Saved"));
+
+        let mut tree = previous.clone();
+        tree.insert(2.into(), node(Role::Button, "Save all"));
+        tree.insert(4.into(), node(Role::CheckBox, "Backup"));
+        tree.nodes.remove(&Entity::from(3));
+        tree.set_focused(Some(4.into()));
+
+        assert_eq!(
+            tree.changes(&previous),
+            vec![
+                AccessibilityEvent::Removed(3.into()),
+                AccessibilityEvent::Changed(2.into()),
+                AccessibilityEvent::Added(4.into()),
+                AccessibilityEvent::FocusChanged(Some(4.into())),
+            ]
+        );
+        assert!(tree.changes(&tree).is_empty());
+    }
+}
@@ -0,0 +1,239 @@
+use std::{num::NonZeroU128, sync::Arc};
+
+use dces::prelude::Entity;
+
+use crate::{
+    services::AccessibilityAdapter,
+    shell::accesskit::{self, kurbo::Rect, CheckedState, Node, NodeId, TreeUpdate},
+};
+
+use super::{AccessibilityEvent, AccessibilityTree, AccessibleNode, Role};
+
+/// The `AccessKitAdapter` converts the accessibility tree of a window and its changes to AccessKit
+/// tree updates and passes them to the given handler, e.g. to the `AtSpiAdapter` of the shell.
+///
+/// The first update contains the whole tree, the following updates only the added and changed
+/// widgets.
+///
+/// # Examples
+/// ```
+/// let updates = Rc::new(RefCell::new(vec![]));
+/// let sink = updates.clone();
+///
+/// registry
+///     .get_mut::<Accessibility>("accessibility")
+///     .set_adapter(AccessKitAdapter::new(move |update| sink.borrow_mut().push(update)));
+/// ```
+pub struct AccessKitAdapter {
+    handler: Box<dyn FnMut(TreeUpdate)>,
+}
+
+impl AccessKitAdapter {
+    /// Creates a new adapter that passes the tree updates to the given handler.
+    pub fn new(handler: impl FnMut(TreeUpdate) + 'static) -> Self {
+        AccessKitAdapter {
+            handler: Box::new(handler),
+        }
+    }
+
+    /// Returns the AccessKit node id of the given widget.
+    pub fn node_id(entity: Entity) -> NodeId {
+        // entity ids start at zero, node ids at one
+        NodeId(NonZeroU128::new(u128::from(entity.0) + 1).unwrap())
+    }
+
+    /// Creates an update that contains the whole tree.
+    pub fn full_tree_update(tree: &AccessibilityTree) -> TreeUpdate {
+        TreeUpdate {
+            nodes: tree
+                .iter()
+                .map(|(entity, node)| accesskit_entry(*entity, node))
+                .collect(),
+            tree: tree
+                .root()
+                .map(|root| accesskit::Tree::new(Self::node_id(root))),
+            focus: focus(tree),
+        }
+    }
+
+    /// Creates the update of the given changes of the tree. Removed widgets are dropped by
+    /// AccessKit after they are removed from the children of their parent, which is reported as
+    /// change of the parent.
+    pub fn tree_update(tree: &AccessibilityTree, events: &[AccessibilityEvent]) -> TreeUpdate {
+        // a new root means that there was no tree before
+        if let Some(root) = tree.root() {
+            if events.contains(&AccessibilityEvent::Added(root)) {
+                return Self::full_tree_update(tree);
+            }
+        }
+
+        TreeUpdate {
+            nodes: events
+                .iter()
+                .filter_map(|event| match event {
+                    AccessibilityEvent::Added(entity) | AccessibilityEvent::Changed(entity) => {
+                        tree.get(*entity).map(|node| accesskit_entry(*entity, node))
+                    }
+                    _ => None,
+                })
+                .collect(),
+            tree: None,
+            focus: focus(tree),
+        }
+    }
+}
+
+impl AccessibilityAdapter for AccessKitAdapter {
+    fn update(&mut self, tree: &AccessibilityTree, events: &[AccessibilityEvent]) {
+        (self.handler)(AccessKitAdapter::tree_update(tree, events));
+    }
+}
+
+// -- Helpers --
+
+fn accesskit_role(role: Role) -> accesskit::Role {
+    match role {
+        Role::Unknown => accesskit::Role::Unknown,
+        Role::Window => accesskit::Role::Window,
+        Role::Group => accesskit::Role::Group,
+        Role::Button => accesskit::Role::Button,
+        Role::ToggleButton => accesskit::Role::ToggleButton,
+        Role::CheckBox => accesskit::Role::CheckBox,
+        Role::Switch => accesskit::Role::Switch,
+        Role::Slider => accesskit::Role::Slider,
+        Role::SpinButton => accesskit::Role::SpinButton,
+        Role::ProgressBar => accesskit::Role::ProgressIndicator,
+        Role::ComboBox => accesskit::Role::ComboBoxMenuButton,
+        Role::List => accesskit::Role::List,
+        Role::ListItem => accesskit::Role::ListItem,
+        Role::TabList => accesskit::Role::TabList,
+        Role::Tab => accesskit::Role::Tab,
+        Role::TextInput | Role::TextArea | Role::PasswordInput => accesskit::Role::TextField,
+        Role::Label => accesskit::Role::StaticText,
+        Role::Image => accesskit::Role::Image,
+    }
+}
+
+fn accesskit_entry(entity: Entity, node: &AccessibleNode) -> (NodeId, Arc<Node>) {
+    (
+        AccessKitAdapter::node_id(entity),
+        Arc::new(accesskit_node(node)),
+    )
+}
+
+fn accesskit_node(node: &AccessibleNode) -> Node {
+    let text = |text: &str| {
+        if text.is_empty() {
+            None
+        } else {
+            Some(text.into())
+        }
+    };
+
+    Node {
+        role: accesskit_role(node.role),
+        bounds: Some(Rect::new(
+            node.bounds.x(),
+            node.bounds.y(),
+            node.bounds.x() + node.bounds.width(),
+            node.bounds.y() + node.bounds.height(),
+        )),
+        children: node
+            .children
+            .iter()
+            .map(|child| AccessKitAdapter::node_id(*child))
+            .collect(),
+        name: text(&node.name),
+        description: text(&node.description),
+        value: node.value.as_deref().map(Into::into),
+        checked_state: node.checked.map(|checked| {
+            if checked {
+                CheckedState::True
+            } else {
+                CheckedState::False
+            }
+        }),
+        selected: node.selected,
+        disabled: !node.enabled,
+        multiline: node.role == Role::TextArea,
+        protected: node.role == Role::PasswordInput,
+        ..Default::default()
+    }
+}
+
+// The focused widget if it is part of the tree.
+fn focus(tree: &AccessibilityTree) -> Option<NodeId> {
+    tree.focused()
+        .filter(|focused| tree.get(*focused).is_some())
+        .map(AccessKitAdapter::node_id)
+}
+
+// -- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(role: Role, name: &str, children: Vec<Entity>) -> AccessibleNode {
+        AccessibleNode {
+            role,
+            name: name.to_string(),
+            enabled: true,
+            children,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_tree_update() {
+        let mut tree = AccessibilityTree::new();
+        tree.insert(
+            1.into(),
+            node(Role::Window, "Window", vec![2.into(), 3.into()]),
+        );
+        tree.insert(2.into(), node(Role::Button, "Save", vec![]));
+        tree.insert(
+            3.into(),
+            AccessibleNode {
+                checked: Some(true),
+                ..node(Role::CheckBox, "Backup", vec![])
+            },
+        );
+        tree.set_focused(Some(3.into()));
+
+        // the whole tree is sent with the root
+        let changes = tree.changes(&AccessibilityTree::new());
+        let update = AccessKitAdapter::tree_update(&tree, &changes);
+        let root = AccessKitAdapter::node_id(1.into());
+        let check_box = AccessKitAdapter::node_id(3.into());
+
+        assert_eq!(update.tree.map(|tree| tree.root), Some(root));
+        assert_eq!(update.focus, Some(check_box));
+        assert_eq!(update.nodes.len(), 3);
+
+        let (_, window) = update.nodes.iter().find(|(id, _)| *id == root).unwrap();
+        assert_eq!(window.role, accesskit::Role::Window);
+        assert_eq!(window.name.as_deref(), Some("Window"));
+        assert_eq!(
+            window.children,
+            vec![AccessKitAdapter::node_id(2.into()), check_box]
+        );
+
+        // only changed widgets are sent afterwards
+        let previous = tree.clone();
+        tree.insert(
+            3.into(),
+            AccessibleNode {
+                checked: Some(false),
+                ..node(Role::CheckBox, "Backup", vec![])
+            },
+        );
+
+        let update = AccessKitAdapter::tree_update(&tree, &tree.changes(&previous));
+        assert!(update.tree.is_none());
+        assert_eq!(update.nodes.len(), 1);
+        assert_eq!(update.nodes[0].0, check_box);
+        assert_eq!(update.nodes[0].1.role, accesskit::Role::CheckBox);
+        assert_eq!(update.nodes[0].1.checked_state, Some(CheckedState::False));
+    }
+}
@@ -0,0 +1,10 @@
+//! This module contains the description of widgets for assistive technologies like screen
+//! readers.
+
+pub use self::accessibility_tree::*;
+pub use self::accesskit_adapter::*;
+pub use self::role::*;
+
+mod accessibility_tree;
+mod accesskit_adapter;
+mod role;
@@ -0,0 +1,118 @@
+/// Describes the kind of a widget for assistive technologies like screen readers. Widgets with
+/// the role `Unknown` are not part of the accessibility tree, their children are attached to the
+/// next described parent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Role {
+    Unknown,
+    Window,
+    Group,
+    Button,
+    ToggleButton,
+    CheckBox,
+    Switch,
+    Slider,
+    SpinButton,
+    ProgressBar,
+    ComboBox,
+    List,
+    ListItem,
+    TabList,
+    Tab,
+    TextInput,
+    TextArea,
+    PasswordInput,
+    Label,
+    Image,
+}
+
+impl Role {
+    /// Returns the name of the role in the AT-SPI specification.
+    pub fn atspi_name(&self) -> &'static str {
+        match self {
+            Role::Unknown => "unknown",
+            Role::Window => "frame",
+            Role::Group => "panel",
+            Role::Button => "push button",
+            Role::ToggleButton | Role::Switch => "toggle button",
+            Role::CheckBox => "check box",
+            Role::Slider => "slider",
+            Role::SpinButton => "spin button",
+            Role::ProgressBar => "progress bar",
+            Role::ComboBox => "combo box",
+            Role::List => "list",
+            Role::ListItem => "list item",
+            Role::TabList => "page tab list",
+            Role::Tab => "page tab",
+            Role::TextInput => "entry",
+            Role::TextArea => "text",
+            Role::PasswordInput => "password text",
+            Role::Label => "label",
+            Role::Image => "image",
+        }
+    }
+
+    /// Returns `false` for controls whose children are only parts of their visual appearance.
+    /// The children of these widgets are not part of the accessibility tree.
+    pub fn has_children(&self) -> bool {
+        matches!(
+            self,
+            Role::Unknown
+                | Role::Window
+                | Role::Group
+                | Role::List
+                | Role::ListItem
+                | Role::TabList
+        )
+    }
+}
+
+impl Default for Role {
+    fn default() -> Self {
+        Role::Unknown
+    }
+}
+
+impl From<&str> for Role {
+    fn from(s: &str) -> Self {
+        match s {
+            "Window" | "window" => Role::Window,
+            "Group" | "group" => Role::Group,
+            "Button" | "button" => Role::Button,
+            "ToggleButton" | "toggle_button" => Role::ToggleButton,
+            "CheckBox" | "check_box" => Role::CheckBox,
+            "Switch" | "switch" => Role::Switch,
+            "Slider" | "slider" => Role::Slider,
+            "SpinButton" | "spin_button" => Role::SpinButton,
+            "ProgressBar" | "progress_bar" => Role::ProgressBar,
+            "ComboBox" | "combo_box" => Role::ComboBox,
+            "List" | "list" => Role::List,
+            "ListItem" | "list_item" => Role::ListItem,
+            "TabList" | "tab_list" => Role::TabList,
+            "Tab" | "tab" => Role::Tab,
+            "TextInput" | "text_input" => Role::TextInput,
+            "TextArea" | "text_area" => Role::TextArea,
+            "PasswordInput" | "password_input" => Role::PasswordInput,
+            "Label" | "label" => Role::Label,
+            "Image" | "image" => Role::Image,
+            _ => Role::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into() {
+        let role: Role = "CheckBox".into();
+        assert_eq!(role, Role::CheckBox);
+
+        let role: Role = "check_box".into();
+        assert_eq!(role, Role::CheckBox);
+
+        let role: Role = "other".into();
+        assert_eq!(role, Role::Unknown);
+        assert_eq!(Role::Button.atspi_name(), "push button");
+    }
+}
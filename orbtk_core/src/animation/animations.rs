@@ -0,0 +1,154 @@
+use std::{any::Any, time::Duration};
+
+use dces::prelude::*;
+
+use super::Animation;
+use crate::{
+    event::EventAdapter, theming::Theme, tree::Tree, utils::Lerp, widget_base::WidgetContainer,
+};
+
+// Type erased animation of a single property.
+trait AnimationObject {
+    // Sets the value of the animation after `elapsed` time. Returns `false` if the widget does
+    // not have the property.
+    fn apply(&self, widget: &mut WidgetContainer, key: &str, elapsed: Duration) -> bool;
+
+    fn is_finished(&self, elapsed: Duration) -> bool;
+
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<P> AnimationObject for Animation<P>
+where
+    P: Lerp + Component + Clone + PartialEq,
+{
+    fn apply(&self, widget: &mut WidgetContainer, key: &str, elapsed: Duration) -> bool {
+        if !widget.has::<P>(key) {
+            return false;
+        }
+
+        widget.set(key, self.value(elapsed));
+        true
+    }
+
+    fn is_finished(&self, elapsed: Duration) -> bool {
+        Animation::is_finished(self, elapsed)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+struct Entry {
+    entity: Entity,
+    key: String,
+    animation: Box<dyn AnimationObject>,
+
+    // Frame time of the first tick of the animation.
+    start: Option<Duration>,
+}
+
+/// `Animations` stores the running property animations of a window. The animations are updated
+/// by the `WindowAdapter` on each frame and removed after they are finished.
+#[derive(Default)]
+pub struct Animations {
+    entries: Vec<Entry>,
+}
+
+impl Animations {
+    /// Creates a new animation store.
+    pub fn new() -> Self {
+        Animations::default()
+    }
+
+    /// Adds an animation of the property `key` of the given widget. A running animation of the
+    /// same property is replaced.
+    pub fn add<P>(&mut self, entity: Entity, key: impl Into<String>, animation: Animation<P>)
+    where
+        P: Lerp + Component + Clone + PartialEq,
+    {
+        let key = key.into();
+        self.remove(entity, &key);
+        self.entries.push(Entry {
+            entity,
+            key,
+            animation: Box::new(animation),
+            start: None,
+        });
+    }
+
+    /// Removes the animation of the property `key` of the given widget. The property keeps its
+    /// current value.
+    pub fn remove(&mut self, entity: Entity, key: &str) {
+        self.entries
+            .retain(|entry| entry.entity != entity || entry.key != key);
+    }
+
+    /// Removes all animations of the given widget.
+    pub fn remove_entity(&mut self, entity: Entity) {
+        self.entries.retain(|entry| entry.entity != entity);
+    }
+
+    /// Returns the animation of the property `key` of the given widget if it animates a value
+    /// of type `P`.
+    pub fn get<P>(&self, entity: Entity, key: &str) -> Option<&Animation<P>>
+    where
+        P: Lerp + Component + Clone + PartialEq,
+    {
+        self.entries
+            .iter()
+            .find(|entry| entry.entity == entity && entry.key == key)
+            .and_then(|entry| entry.animation.as_any().downcast_ref::<Animation<P>>())
+    }
+
+    /// Returns `true` if the property `key` of the given widget is animated.
+    pub fn contains(&self, entity: Entity, key: &str) -> bool {
+        self.entries
+            .iter()
+            .any(|entry| entry.entity == entity && entry.key == key)
+    }
+
+    /// Returns the number of running animations.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no animation is running.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Updates all animations to the given frame time and removes the finished ones. Animations
+    /// of removed widgets are dropped. Returns `true` if animations are still running.
+    pub fn tick(
+        &mut self,
+        time: Duration,
+        ecm: &mut EntityComponentManager<Tree>,
+        theme: &Theme,
+        event_adapter: Option<&EventAdapter>,
+    ) -> bool {
+        let mut i = 0;
+
+        while i < self.entries.len() {
+            let entry = &mut self.entries[i];
+
+            let running = ecm.entity_store().parent.contains_key(&entry.entity) && {
+                let start = *entry.start.get_or_insert(time);
+                let elapsed = time.checked_sub(start).unwrap_or_default();
+
+                let mut widget = WidgetContainer::new(entry.entity, ecm, theme, event_adapter);
+                entry.animation.apply(&mut widget, &entry.key, elapsed)
+                    && !entry.animation.is_finished(elapsed)
+            };
+
+            if running {
+                i += 1;
+            } else {
+                self.entries.remove(i);
+            }
+        }
+
+        !self.entries.is_empty()
+    }
+}
@@ -0,0 +1,58 @@
+use std::time::Duration;
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+
+/// Time between two frames while animations are running, about 60 frames per second.
+pub const FRAME_INTERVAL: Duration = Duration::from_millis(16);
+
+/// The `FrameClock` measures the time since the window was started. It is used to drive the
+/// animations of a window.
+#[derive(Debug, Clone)]
+pub struct FrameClock {
+    #[cfg(not(target_arch = "wasm32"))]
+    start: Instant,
+
+    #[cfg(target_arch = "wasm32")]
+    start: f64,
+}
+
+impl Default for FrameClock {
+    fn default() -> Self {
+        FrameClock::new()
+    }
+}
+
+impl FrameClock {
+    /// Creates a new frame clock that starts now.
+    pub fn new() -> Self {
+        FrameClock {
+            #[cfg(not(target_arch = "wasm32"))]
+            start: Instant::now(),
+
+            #[cfg(target_arch = "wasm32")]
+            start: stdweb::web::Date::now(),
+        }
+    }
+
+    /// Returns the time since the clock was started.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn now(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    /// Returns the time since the clock was started.
+    #[cfg(target_arch = "wasm32")]
+    pub fn now(&self) -> Duration {
+        let elapsed = (stdweb::web::Date::now() - self.start).max(0.0);
+        Duration::from_micros((elapsed * 1000.0) as u64)
+    }
+
+    /// Returns the time from now until the frame that follows the frame started at
+    /// `frame_start`. It is zero if the frame took longer than `FRAME_INTERVAL`.
+    pub fn until_next_frame(&self, frame_start: Duration) -> Duration {
+        (frame_start + FRAME_INTERVAL)
+            .checked_sub(self.now())
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,11 @@
+//! This module contains the animation of widget properties.
+
+pub use self::animations::*;
+pub use self::frame_clock::*;
+pub use self::property_animation::*;
+pub use self::transition::*;
+
+mod animations;
+mod frame_clock;
+mod property_animation;
+mod transition;
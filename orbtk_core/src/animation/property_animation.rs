@@ -0,0 +1,126 @@
+use std::time::Duration;
+
+use crate::utils::{Easing, Lerp};
+
+/// Default duration of animations and transitions.
+pub const DEFAULT_ANIMATION_DURATION: Duration = Duration::from_millis(200);
+
+/// An `Animation` changes a property of a widget from `from` to `to` over the given duration.
+///
+/// # Example
+///
+/// ```rust
+/// ctx.animate(
+///     entity,
+///     "opacity",
+///     Animation::new(0.0_f32, 1.0)
+///         .duration(Duration::from_millis(150))
+///         .easing(Easing::EaseOut),
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Animation<P> {
+    /// The value at the start of the animation.
+    pub from: P,
+
+    /// The value at the end of the animation.
+    pub to: P,
+
+    /// The time the animation takes from `from` to `to`.
+    pub duration: Duration,
+
+    /// The time to wait before the animation starts.
+    pub delay: Duration,
+
+    /// The easing curve of the animation.
+    pub easing: Easing,
+}
+
+impl<P> Animation<P>
+where
+    P: Lerp + Clone,
+{
+    /// Creates a new animation from `from` to `to` with the default duration.
+    pub fn new(from: P, to: P) -> Self {
+        Animation {
+            from,
+            to,
+            duration: DEFAULT_ANIMATION_DURATION,
+            delay: Duration::default(),
+            easing: Easing::default(),
+        }
+    }
+
+    /// Sets the duration.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets the delay.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Sets the easing curve.
+    pub fn easing(mut self, easing: impl Into<Easing>) -> Self {
+        self.easing = easing.into();
+        self
+    }
+
+    /// Returns the value of the animation after `elapsed` time since its start.
+    pub fn value(&self, elapsed: Duration) -> P {
+        if elapsed <= self.delay {
+            return self.from.clone();
+        }
+
+        let elapsed = elapsed - self.delay;
+        if elapsed >= self.duration {
+            return self.to.clone();
+        }
+
+        let t = elapsed.as_secs_f64() / self.duration.as_secs_f64();
+        self.from.lerp(&self.to, self.easing.ease(t))
+    }
+
+    /// Returns `true` if the animation is finished after `elapsed` time since its start.
+    pub fn is_finished(&self, elapsed: Duration) -> bool {
+        elapsed >= self.delay + self.duration
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value() {
+        let animation = Animation::new(0.0, 10.0)
+            .duration(Duration::from_millis(100))
+            .delay(Duration::from_millis(50));
+
+        assert!(crate::utils::f64_cmp(
+            animation.value(Duration::from_millis(20)),
+            0.0
+        ));
+        assert!(crate::utils::f64_cmp(
+            animation.value(Duration::from_millis(100)),
+            5.0
+        ));
+        assert!(!animation.is_finished(Duration::from_millis(100)));
+        assert!(crate::utils::f64_cmp(
+            animation.value(Duration::from_millis(200)),
+            10.0
+        ));
+        assert!(animation.is_finished(Duration::from_millis(150)));
+
+        // animations without duration jump to the end
+        let animation = Animation::new(0.0, 10.0).duration(Duration::default());
+        assert!(crate::utils::f64_cmp(
+            animation.value(Duration::default()),
+            0.0
+        ));
+        assert!(animation.is_finished(Duration::default()));
+    }
+}
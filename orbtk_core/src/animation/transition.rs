@@ -0,0 +1,64 @@
+use std::time::Duration;
+
+use super::{Animation, DEFAULT_ANIMATION_DURATION};
+use crate::utils::{Easing, Lerp};
+
+/// A `Transition` describes how a property changes to a new value. It is used to create the
+/// animation from the current to the new value of the property.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transition {
+    /// The time the transition takes.
+    pub duration: Duration,
+
+    /// The time to wait before the transition starts.
+    pub delay: Duration,
+
+    /// The easing curve of the transition.
+    pub easing: Easing,
+}
+
+impl Default for Transition {
+    fn default() -> Self {
+        Transition::new(DEFAULT_ANIMATION_DURATION)
+    }
+}
+
+impl Transition {
+    /// Creates a new transition with the given duration.
+    pub fn new(duration: Duration) -> Self {
+        Transition {
+            duration,
+            delay: Duration::default(),
+            easing: Easing::default(),
+        }
+    }
+
+    /// Sets the delay.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Sets the easing curve.
+    pub fn easing(mut self, easing: impl Into<Easing>) -> Self {
+        self.easing = easing.into();
+        self
+    }
+
+    /// Creates the animation from `from` to `to`.
+    pub fn animation<P>(&self, from: P, to: P) -> Animation<P>
+    where
+        P: Lerp + Clone,
+    {
+        Animation::new(from, to)
+            .duration(self.duration)
+            .delay(self.delay)
+            .easing(self.easing)
+    }
+}
+
+impl From<Duration> for Transition {
+    fn from(duration: Duration) -> Self {
+        Transition::new(duration)
+    }
+}
@@ -10,6 +10,7 @@ use dces::prelude::*;
 use super::WindowAdapter;
 
 use crate::{
+    animation::Animations,
     event::*,
     layout::*,
     localization::Localization,
@@ -33,6 +34,9 @@ pub struct ContextProvider {
     pub shell_sender: mpsc::Sender<ShellRequest<WindowAdapter>>,
     pub application_name: String,
     pub first_run: Rc<Cell<bool>>,
+    pub damage_tracker: Rc<RefCell<DamageTracker>>,
+    pub layer_cache: Rc<RefCell<LayerCache>>,
+    pub animations: Rc<RefCell<Animations>>,
     pub raw_window_handle: Option<raw_window_handle::RawWindowHandle>,
     // todo thread safe
     pub localization: Option<Rc<RefCell<Box<dyn Localization>>>>,
@@ -58,6 +62,9 @@ impl ContextProvider {
             shell_sender,
             application_name: application_name.into(),
             first_run: Rc::new(Cell::new(true)),
+            damage_tracker: Rc::new(RefCell::new(DamageTracker::new())),
+            layer_cache: Rc::new(RefCell::new(LayerCache::new())),
+            animations: Rc::new(RefCell::new(Animations::new())),
             raw_window_handle: None,
             localization,
         }
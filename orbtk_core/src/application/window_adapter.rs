@@ -1,13 +1,14 @@
-use std::{cell::RefCell, sync::mpsc};
+use std::{cell::RefCell, sync::mpsc, time::Duration};
 
 use dces::prelude::*;
 
 use crate::{
+    animation::FrameClock,
     application::*,
     event::*,
     localization::Localization,
     render,
-    services::{Clipboard, Settings},
+    services::{Accessibility, Clipboard, Commands, Settings, Shortcuts, Tasks, Timer},
     shell,
     shell::{ShellRequest, WindowRequest, WindowSettings},
     systems::*,
@@ -23,6 +24,7 @@ pub struct WindowAdapter {
     ctx: ContextProvider,
     registry: Rc<RefCell<Registry>>,
     old_clipboard_value: Option<String>,
+    frame_clock: FrameClock,
 }
 
 impl WindowAdapter {
@@ -37,11 +39,22 @@ impl WindowAdapter {
             ctx,
             registry,
             old_clipboard_value: None,
+            frame_clock: FrameClock::new(),
         }
     }
 }
 
 impl WindowAdapter {
+    /// Gets the entity component manager of the window. Could be used to query the widget tree from outside e.g. in tests.
+    pub fn entity_component_manager(&mut self) -> &mut EntityComponentManager<Tree> {
+        self.world.entity_component_manager()
+    }
+
+    /// Gets the service registry of the window.
+    pub fn registry(&self) -> &Rc<RefCell<Registry>> {
+        &self.registry
+    }
+
     fn root(&mut self) -> Entity {
         self.world
             .entity_component_manager()
@@ -49,6 +62,29 @@ impl WindowAdapter {
             .root
             .unwrap()
     }
+
+    // Updates the animated properties to the given frame time.
+    fn animate(&mut self, time: Duration) {
+        if self.ctx.animations.borrow().is_empty() {
+            return;
+        }
+
+        let root = self.root();
+        let theme = self
+            .world
+            .entity_component_manager()
+            .component_store()
+            .get::<Rc<Theme>>("theme", root)
+            .unwrap()
+            .clone();
+
+        self.ctx.animations.borrow_mut().tick(
+            time,
+            self.world.entity_component_manager(),
+            &theme,
+            Some(&self.ctx.event_adapter),
+        );
+    }
 }
 
 impl shell::WindowAdapter for WindowAdapter {
@@ -172,7 +208,24 @@ impl shell::WindowAdapter for WindowAdapter {
     }
 
     fn run(&mut self, render_context: &mut render::RenderContext2D) {
+        let frame_start = self.frame_clock.now();
+
+        if let Some(timer) = self.registry.borrow_mut().try_get_mut::<Timer>("timer") {
+            timer.run();
+        }
+
+        self.animate(frame_start);
         self.world.run_with_context(render_context);
+
+        // the timer wakes up the window for the next frame while animations are running, the
+        // window sleeps in between
+        if !self.ctx.animations.borrow().is_empty() {
+            let delay = self.frame_clock.until_next_frame(frame_start);
+
+            if let Some(timer) = self.registry.borrow_mut().try_get_mut::<Timer>("timer") {
+                timer.request_redraw(delay);
+            }
+        }
     }
 
     fn file_drop_event(&mut self, file_name: String) {
@@ -220,22 +273,43 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
     let context_provider =
         ContextProvider::new(sender, request_sender, app_name.clone(), localization);
 
+    let tasks = Tasks::new(context_provider.message_adapter.clone());
+
     if app_name.is_empty() {
-        registry.borrow_mut().register(
-            "settings",
-            Settings::new(context_provider.message_adapter.clone()),
-        );
+        registry
+            .borrow_mut()
+            .register("settings", Settings::new(tasks.clone()));
     } else {
-        registry.borrow_mut().register(
-            "settings",
-            Settings::from_name(app_name, context_provider.message_adapter.clone()),
-        );
+        registry
+            .borrow_mut()
+            .register("settings", Settings::from_name(app_name, tasks.clone()));
     };
 
+    registry
+        .borrow_mut()
+        .register("accessibility", Accessibility::new());
+
     registry
         .borrow_mut()
         .register("clipboard", Clipboard::new());
 
+    registry.borrow_mut().register("commands", Commands::new());
+
+    registry.borrow_mut().register(
+        "shortcuts",
+        Shortcuts::new(context_provider.message_adapter.clone()),
+    );
+
+    registry.borrow_mut().register("tasks", tasks);
+
+    registry.borrow_mut().register(
+        "timer",
+        Timer::new(
+            context_provider.message_adapter.clone(),
+            context_provider.window_sender.clone(),
+        ),
+    );
+
     let window = {
         let overlay = Overlay::new().build(&mut BuildContext::new(
             world.entity_component_manager(),
@@ -282,7 +356,8 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
         .get::<Point>("position", window)
         .unwrap();
 
-    let fonts = theme.fonts().clone();
+    let fonts = theme.fonts().to_vec();
+    let font_faces = theme.font_faces().to_vec();
 
     let settings = WindowSettings {
         title: world
@@ -309,6 +384,7 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
         position: (position.x(), position.y()),
         size: (constraint.width(), constraint.height()),
         fonts,
+        font_faces,
     };
 
     // let mut global = Global::default();
@@ -339,6 +415,7 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
             context_provider.clone(),
             registry.clone(),
             RefCell::new(vec![]),
+            RefCell::new(vec![]),
         ))
         .with_priority(0)
         .build();
@@ -10,6 +10,12 @@ use crate::{
 pub enum FocusEvent {
     RequestFocus(Entity),
     RemoveFocus(Entity),
+
+    /// Moves the focus to the next focusable widget in tab order.
+    FocusNext,
+
+    /// Moves the focus to the previous focusable widget in tab order.
+    FocusPrevious,
 }
 
 pub type FocusHandlerFn = dyn Fn(&mut StatesContext, FocusEvent) -> bool + 'static;
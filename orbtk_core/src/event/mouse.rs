@@ -1,5 +1,7 @@
 use std::rc::Rc;
 
+use dces::prelude::Entity;
+
 use crate::{
     prelude::*,
     proc_macros::{Event, IntoHandler},
@@ -9,9 +11,7 @@ use crate::{
 
 /// Checks if the given point is inside of a widget.
 pub fn check_mouse_condition(mouse_position: Point, widget: &WidgetContainer<'_>) -> bool {
-    let enabled = widget.get::<bool>("enabled");
-
-    if !enabled {
+    if !widget.is_enabled() {
         return false;
     }
 
@@ -81,6 +81,11 @@ pub struct ClickEvent {
     pub position: Point,
 }
 
+/// `LinkClickedEvent` occurs when a user clicked on a link of a rich text. It contains the entity
+/// of the widget that draws the text and the link target.
+#[derive(Clone, Event)]
+pub struct LinkClickedEvent(pub Entity, pub String);
+
 /// `MouseDownEvent` occurs when a mouse button is pressed.
 #[derive(Event)]
 pub struct MouseDownEvent {
@@ -118,6 +123,9 @@ pub type PositionDirectHandlerFunction = dyn Fn(&mut StatesContext, Point) + 'st
 /// Defines the global bouse handler function.
 pub type GlobalMouseHandlerFunction = dyn Fn(&mut StatesContext, Mouse) + 'static;
 
+/// Defines the link clicked handler function.
+pub type LinkClickedHandlerFunction = dyn Fn(&mut StatesContext, Entity, String) + 'static;
+
 /// Used to handle click events. Could be attached to a widget.
 #[derive(IntoHandler)]
 pub struct ClickEventHandler {
@@ -296,6 +304,39 @@ impl EventHandler for ScrollEventHandler {
     }
 }
 
+/// Used to handle clicks on links of a rich text. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct LinkClickedEventHandler {
+    handler: Rc<LinkClickedHandlerFunction>,
+}
+
+impl EventHandler for LinkClickedEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<LinkClickedEvent>() {
+            (self.handler)(state_context, event.0, event.1.clone());
+            return true;
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<LinkClickedEvent>()
+    }
+}
+
+pub trait LinkClickedHandler: Sized + Widget {
+    /// Inserts a handler that is called if a link of a rich text is clicked.
+    fn on_link_clicked<H: Fn(&mut StatesContext, Entity, String) + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(LinkClickedEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
+
 pub trait MouseHandler: Sized + Widget {
     /// Inserts a click handler.
     fn on_click<H: Fn(&mut StatesContext, Point) -> bool + 'static>(self, handler: H) -> Self {
@@ -1,15 +1,13 @@
 use std::{
     cell::{Cell, RefCell},
     collections::BTreeMap,
-    iter,
 };
 
 use dces::prelude::*;
-use memchr::memchr_iter;
-use smallvec::SmallVec;
 
 use crate::{
     proc_macros::IntoLayout,
+    properties::TextSpans,
     render::Image,
     render::RenderContext2D,
     theming::*,
@@ -20,11 +18,16 @@ use crate::{
 
 use super::{component, component_try_mut, Layout};
 
+/// Default line height of a text as factor of its font size.
+pub const DEFAULT_LINE_HEIGHT: f64 = 1.15;
+
 /// Fixed size layout is defined by fixed bounds like the size of an image or the size of a text.
 #[derive(Default, IntoLayout)]
 pub struct FixedSizeLayout {
     desired_size: RefCell<DirtySize>,
     old_alignment: Cell<(Alignment, Alignment)>,
+    // width the parent has provided on the last arrange, used to wrap text
+    available_width: Cell<Option<f64>>,
 }
 
 impl FixedSizeLayout {
@@ -62,31 +65,19 @@ impl Layout for FixedSizeLayout {
             .try_get::<Image>("image")
             .map(|image| (Size::new(image.width(), image.height())))
             .or_else(|| {
-                text(&widget).and_then(|text| {
-                    let font = widget.get::<String>("font");
-                    let font_size = widget.get::<f64>("font_size");
-
-                    if text.is_empty() {
-                        widget
-                            .try_get::<String>("water_mark")
-                            .filter(|water_mark| !water_mark.is_empty())
-                            .map(|water_mark| {
-                                measure_text(
-                                    render_context_2_d,
-                                    &water_mark,
-                                    font.as_str(),
-                                    *font_size,
-                                )
-                            })
-                    } else {
-                        Some(measure_text(
-                            render_context_2_d,
-                            &text,
-                            font.as_str(),
-                            *font_size,
-                        ))
-                    }
-                })
+                // wraps the text to the width that is available since the last arrange
+                let max_width = if text_wrap(&widget) {
+                    let max_width = widget.get::<Constraint>("constraint").max_width();
+                    Some(
+                        self.available_width
+                            .get()
+                            .map_or(max_width, |available_width| available_width.min(max_width)),
+                    )
+                } else {
+                    None
+                };
+
+                measure_widget_text(render_context_2_d, &widget, max_width)
             })
             .or_else(|| {
                 widget
@@ -94,11 +85,15 @@ impl Layout for FixedSizeLayout {
                     .filter(|font_icon| !font_icon.is_empty())
                     .map(|font_icon| {
                         let icon_size = widget.get::<f64>("icon_size");
+                        render_context_2_d.set_font_weight(FontWeight::Normal);
+                        render_context_2_d.set_font_style(FontStyle::Normal);
                         measure_text(
                             render_context_2_d,
                             &font_icon,
                             widget.get::<String>("icon_font").as_str(),
                             *icon_size,
+                            None,
+                            DEFAULT_LINE_HEIGHT,
                         )
                     })
             });
@@ -140,7 +135,7 @@ impl Layout for FixedSizeLayout {
     fn arrange(
         &self,
         render_context_2_d: &mut RenderContext2D,
-        _parent_size: (f64, f64),
+        parent_size: (f64, f64),
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree>,
         layouts: &BTreeMap<Entity, Box<dyn Layout>>,
@@ -151,6 +146,39 @@ impl Layout for FixedSizeLayout {
             return (0.0, 0.0);
         }
 
+        // wraps the text again if the available width has changed since it was measured
+        let wrapped_size = {
+            let widget = WidgetContainer::new(entity, ecm, theme, None);
+            let margin = *widget.get::<Thickness>("margin");
+            let available_width = parent_size.0 - margin.left() - margin.right();
+
+            if text_wrap(&widget)
+                && available_width > 0.0
+                && self.available_width.get() != Some(available_width)
+            {
+                self.available_width.set(Some(available_width));
+                let max_width = widget.get::<Constraint>("constraint").max_width();
+                measure_widget_text(
+                    render_context_2_d,
+                    &widget,
+                    Some(available_width.min(max_width)),
+                )
+            } else {
+                None
+            }
+        };
+
+        if let Some(size) = wrapped_size {
+            if let Some(constraint) = component_try_mut::<Constraint>(ecm, entity, "constraint") {
+                constraint.set_width(size.width());
+                constraint.set_height(size.height());
+            }
+
+            self.desired_size
+                .borrow_mut()
+                .set_size(size.width(), size.height());
+        }
+
         if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
             bounds.set_width(self.desired_size.borrow().width());
             bounds.set_height(self.desired_size.borrow().height());
@@ -196,27 +224,99 @@ fn text(widget: &WidgetContainer) -> Option<String> {
     None
 }
 
+fn text_wrap(widget: &WidgetContainer) -> bool {
+    widget
+        .try_get::<bool>("text_wrap")
+        .map_or(false, |text_wrap| *text_wrap)
+}
+
+fn font_weight(widget: &WidgetContainer) -> FontWeight {
+    widget
+        .try_get::<FontWeight>("font_weight")
+        .map_or(FontWeight::Normal, |font_weight| *font_weight)
+}
+
+fn font_style(widget: &WidgetContainer) -> FontStyle {
+    widget
+        .try_get::<FontStyle>("font_style")
+        .map_or(FontStyle::Normal, |font_style| *font_style)
+}
+
+// Measures the rich text, the text or if the text is empty the water mark of the widget.
+fn measure_widget_text(
+    render_context_2_d: &mut RenderContext2D,
+    widget: &WidgetContainer,
+    max_width: Option<f64>,
+) -> Option<Size> {
+    let font_weight = font_weight(widget);
+    let font_style = font_style(widget);
+    let line_height = widget
+        .try_get::<f64>("line_height")
+        .map_or(DEFAULT_LINE_HEIGHT, |line_height| *line_height);
+
+    if let Some(spans) = widget
+        .try_get::<TextSpans>("spans")
+        .filter(|spans| !spans.is_empty())
+    {
+        let layout = spans.layout(
+            render_context_2_d,
+            widget.get::<String>("font"),
+            *widget.get::<f64>("font_size"),
+            font_weight,
+            font_style,
+            max_width,
+            line_height,
+        );
+
+        return Some(Size::new(layout.width(), layout.height()));
+    }
+
+    // weight and style select the face of the font family
+    render_context_2_d.set_font_weight(font_weight);
+    render_context_2_d.set_font_style(font_style);
+
+    text(widget).and_then(|text| {
+        let font = widget.get::<String>("font");
+        let font_size = widget.get::<f64>("font_size");
+
+        if text.is_empty() {
+            widget
+                .try_get::<String>("water_mark")
+                .filter(|water_mark| !water_mark.is_empty())
+                .map(|water_mark| {
+                    measure_text(
+                        render_context_2_d,
+                        &water_mark,
+                        font.as_str(),
+                        *font_size,
+                        max_width,
+                        line_height,
+                    )
+                })
+        } else {
+            Some(measure_text(
+                render_context_2_d,
+                &text,
+                font.as_str(),
+                *font_size,
+                max_width,
+                line_height,
+            ))
+        }
+    })
+}
+
 fn measure_text(
     render_context_2_d: &mut RenderContext2D,
     text: &str,
     font_family: &str,
     font_size: f64,
+    max_width: Option<f64>,
+    line_height: f64,
 ) -> Size {
-    let mut text_metrics = render_context_2_d.measure(text, font_size, font_family);
-    let mut lines = SmallVec::<[&str; 2]>::new();
-    let mut last_hit = 0;
-    for hit in memchr_iter(b'\n', text.as_bytes()).chain(iter::once(text.len())) {
-        let line = &text[last_hit..hit];
-        lines.push(line);
-        last_hit = hit;
-    }
-    text_metrics.width = 0.0;
-    for line in lines.iter() {
-        let line_text_metrics = render_context_2_d.measure(line, font_size, font_family);
-        text_metrics.width = text_metrics.width.max(line_text_metrics.width);
-    }
-    Size::new(
-        text_metrics.width,
-        text_metrics.height * (((lines.len() - 1) as f64) * 1.15 + 1.0),
-    )
+    render_context_2_d.set_font_family(font_family);
+    render_context_2_d.set_font_size(font_size);
+    let layout = render_context_2_d.layout_text(text, max_width, font_size * line_height);
+
+    Size::new(layout.width(), layout.height())
 }
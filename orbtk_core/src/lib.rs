@@ -10,6 +10,8 @@ pub(crate) use orbtk_proc_macros as proc_macros;
 pub(crate) use orbtk_tinyskia::prelude as render;
 pub(crate) use orbtk_utils::prelude as utils;
 
+pub mod accessibility;
+pub mod animation;
 pub mod application;
 pub mod localization;
 pub mod theming;
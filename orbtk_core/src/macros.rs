@@ -108,6 +108,18 @@ macro_rules! widget {
             enabled: bool,
             #[property(bool)]
             clip: bool,
+            #[property(bool)]
+            cache: bool,
+            #[property(bool)]
+            focusable: bool,
+            #[property(i32)]
+            tab_index: i32,
+            #[property(Role)]
+            role: Role,
+            #[property(String)]
+            accessible_name: String,
+            #[property(String)]
+            accessible_description: String,
             #[property(f32)]
             opacity: f32,
             #[property(Visibility)]
@@ -222,6 +234,43 @@ macro_rules! widget {
                 self.set_property("clip", clip)
             }
 
+            /// Sets or shares the cache property. If set to `true` the widget and its children
+            /// are drawn once into a layer that is reused until one of them is dirty.
+            pub fn cache(self, cache: impl IntoPropertySource<bool>) -> Self {
+                self.set_property("cache", cache)
+            }
+
+            /// Sets or shares the focusable property. If set to `true` the widget can be focused
+            /// with the `Tab` key.
+            pub fn focusable(self, focusable: impl IntoPropertySource<bool>) -> Self {
+                self.set_property("focusable", focusable)
+            }
+
+            /// Sets or shares the tab index property. Widgets with a positive tab index are
+            /// focused first in ascending order, followed by the widgets with `0` in document
+            /// order. Widgets with a negative tab index are skipped by the `Tab` key.
+            pub fn tab_index(self, tab_index: impl IntoPropertySource<i32>) -> Self {
+                self.set_property("tab_index", tab_index)
+            }
+
+            /// Sets or shares the role property. It describes the kind of the widget to
+            /// assistive technologies like screen readers.
+            pub fn role(self, role: impl IntoPropertySource<Role>) -> Self {
+                self.set_property("role", role)
+            }
+
+            /// Sets or shares the accessible name property. It is read by screen readers, if it is
+            /// empty the text of the widget is read.
+            pub fn accessible_name(self, accessible_name: impl IntoPropertySource<String>) -> Self {
+                self.set_property("accessible_name", accessible_name)
+            }
+
+            /// Sets or shares the accessible description property. It describes the widget in
+            /// addition to its name.
+            pub fn accessible_description(self, accessible_description: impl IntoPropertySource<String>) -> Self {
+                self.set_property("accessible_description", accessible_description)
+            }
+
             /// Sets or shares the opacity property.
             pub fn opacity(self, opacity: impl IntoPropertySource<f32>) -> Self {
                 self.set_property("opacity", opacity)
@@ -387,6 +436,9 @@ macro_rules! widget {
                     enabled: true,
                     opacity: 1.,
                     clip: false,
+                    cache: false,
+                    focusable: false,
+                    tab_index: 0,
                     $(
                         $(
                             $property: None,
@@ -457,6 +509,12 @@ macro_rules! widget {
                 ctx.register_property("margin", entity, this.margin);
                 ctx.register_property("enabled", entity, this.enabled);
                 ctx.register_property("clip", entity, this.clip);
+                ctx.register_property("cache", entity, this.cache);
+                ctx.register_property("focusable", entity, this.focusable);
+                ctx.register_property("tab_index", entity, this.tab_index);
+                ctx.register_property("role", entity, this.role);
+                ctx.register_property("accessible_name", entity, this.accessible_name);
+                ctx.register_property("accessible_description", entity, this.accessible_description);
                 ctx.register_property("opacity", entity, this.opacity);
                 ctx.register_property("type_id", entity, TypeId::of::<$widget>());
                 ctx.register_property("type_name", entity, std::any::type_name::<$widget>().to_string());
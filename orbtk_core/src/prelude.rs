@@ -2,6 +2,8 @@
 pub use std::rc::Rc;
 
 // crates modules
+pub use crate::accessibility::*;
+pub use crate::animation::*;
 pub use crate::application::*;
 pub use crate::event::*;
 pub use crate::layout::*;
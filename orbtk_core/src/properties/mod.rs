@@ -6,7 +6,7 @@ use dces::prelude::{Component, ComponentStore, Entity};
 
 pub use self::layout::*;
 pub use self::widget::*;
-use crate::{into_property_source, render, theming, utils};
+use crate::{accessibility, into_property_source, render, theming, utils};
 
 mod layout;
 mod widget;
@@ -86,6 +86,7 @@ into_property_source!(PathBuf);
 
 // Implementation of PropertySource for utils types
 into_property_source!(utils::Alignment: &str);
+into_property_source!(utils::BorderStyle: &str, String, utils::Value);
 into_property_source!(utils::Brush: &str, utils::Color, utils::Value);
 into_property_source!(utils::Orientation: &str);
 into_property_source!(utils::Point: f64, i32, (i32, i32), (f64, f64));
@@ -104,7 +105,11 @@ into_property_source!(
     (f64, f64, f64, f64),
     utils::Value
 );
+into_property_source!(utils::FontStyle: &str, String, utils::Value);
+into_property_source!(utils::FontWeight: &str, String, u16, utils::Value);
 into_property_source!(utils::SelectionMode: &str);
+into_property_source!(utils::Shadow: &str, String, utils::Value);
+into_property_source!(utils::TextAlignment: &str, String, utils::Value);
 into_property_source!(utils::Visibility: &str);
 into_property_source!(Vec<String>);
 into_property_source!(utils::Filter: &str, String, Vec<String>, Vec<&str>);
@@ -124,5 +129,7 @@ into_property_source!(ScrollViewerMode: (&str, &str));
 into_property_source!(SelectedEntities: HashSet<Entity>);
 into_property_source!(SelectedIndices: HashSet<usize>);
 into_property_source!(TextSelection: (usize, usize));
+into_property_source!(TextSpans: Vec<TextSpan>, TextSpan);
 into_property_source!(FocusState);
 into_property_source!(KeyboardState);
+into_property_source!(accessibility::Role: &str);
@@ -1,6 +1,6 @@
-use crate::{theming::Selector, widget_base::Context};
+use crate::{theming::Selector, tree::Tree, utils::Visibility, widget_base::Context};
 
-use dces::prelude::Entity;
+use dces::prelude::{Entity, EntityComponentManager};
 
 /// Contains the state information of the current focused element.
 ///
@@ -16,7 +16,7 @@ impl FocusState {
         let entity = entity.into();
 
         if (self.focused_entity.is_some() && self.focused_entity.unwrap() == entity)
-            || !*ctx.get_widget(entity).get::<bool>("enabled")
+            || !ctx.get_widget(entity).is_enabled()
         {
             return;
         }
@@ -63,6 +63,41 @@ impl FocusState {
         self.focused_entity = None;
     }
 
+    /// Moves the focus to the next focusable widget in tab order. After the last widget the
+    /// focus wraps around to the first one. If a modal widget of the overlay is open, only its
+    /// children are focused.
+    pub fn focus_next(&mut self, ctx: &mut Context) {
+        self.move_focus(false, ctx);
+    }
+
+    /// Moves the focus to the previous focusable widget in tab order. Before the first widget
+    /// the focus wraps around to the last one.
+    pub fn focus_previous(&mut self, ctx: &mut Context) {
+        self.move_focus(true, ctx);
+    }
+
+    fn move_focus(&mut self, reverse: bool, ctx: &mut Context) {
+        let chain = focus_chain(ctx.ecm);
+        let len = chain.len();
+
+        if len == 0 {
+            return;
+        }
+
+        let index = self
+            .focused_entity
+            .and_then(|focused| chain.iter().position(|entity| *entity == focused));
+
+        let next = match (index, reverse) {
+            (Some(index), false) => chain[(index + 1) % len],
+            (Some(index), true) => chain[(index + len - 1) % len],
+            (None, false) => chain[0],
+            (None, true) => chain[len - 1],
+        };
+
+        self.request_focus(next, ctx);
+    }
+
     /// Returns `true` if the given entity is focused.
     pub fn has_focus(&self, entity: impl Into<Entity>) -> bool {
         self.focused_entity.is_some() && self.focused_entity.unwrap() == entity.into()
@@ -73,3 +108,114 @@ impl FocusState {
         &self.focused_entity
     }
 }
+
+// -- Helpers --
+
+// Returns the focusable widgets of the window or of the open modal widget in tab order.
+fn focus_chain(ecm: &EntityComponentManager<Tree>) -> Vec<Entity> {
+    let mut chain = vec![];
+
+    if let Some(scope) = focus_scope(ecm) {
+        collect_focusable(ecm, scope, &mut chain);
+    }
+
+    tab_order(chain)
+}
+
+// The last open modal widget of the overlay is the scope of the focus, otherwise the root.
+fn focus_scope(ecm: &EntityComponentManager<Tree>) -> Option<Entity> {
+    let tree = ecm.entity_store();
+
+    if let Some(children) = tree.overlay.and_then(|overlay| tree.children.get(&overlay)) {
+        if let Some(modal) = children.iter().rev().find(|child| {
+            *ecm.component_store()
+                .get::<bool>("modal", **child)
+                .unwrap_or(&false)
+                && is_visible(ecm, **child)
+        }) {
+            return Some(*modal);
+        }
+    }
+
+    tree.root
+}
+
+// Collects the focusable widgets with their tab index in document order. Disabled and invisible
+// widgets are skipped together with their children.
+fn collect_focusable(
+    ecm: &EntityComponentManager<Tree>,
+    entity: Entity,
+    chain: &mut Vec<(Entity, i32)>,
+) {
+    let store = ecm.component_store();
+
+    if !store
+        .get::<bool>("enabled", entity)
+        .map_or(true, |enabled| *enabled)
+        || !store
+            .get::<bool>("command_enabled", entity)
+            .map_or(true, |command_enabled| *command_enabled)
+        || !is_visible(ecm, entity)
+    {
+        return;
+    }
+
+    if *store.get::<bool>("focusable", entity).unwrap_or(&false) {
+        let tab_index = store
+            .get::<i32>("tab_index", entity)
+            .map_or(0, |tab_index| *tab_index);
+
+        if tab_index >= 0 {
+            chain.push((entity, tab_index));
+        }
+    }
+
+    if let Some(children) = ecm.entity_store().children.get(&entity) {
+        for child in children {
+            collect_focusable(ecm, *child, chain);
+        }
+    }
+}
+
+fn is_visible(ecm: &EntityComponentManager<Tree>, entity: Entity) -> bool {
+    ecm.component_store()
+        .get::<Visibility>("visibility", entity)
+        .map_or(true, |visibility| *visibility == Visibility::Visible)
+}
+
+// Widgets with a positive tab index come first in ascending order, followed by the widgets with
+// tab index `0`. The sort is stable, so equal tab indices keep the document order.
+fn tab_order(mut chain: Vec<(Entity, i32)>) -> Vec<Entity> {
+    chain.sort_by_key(
+        |(_, tab_index)| {
+            if *tab_index > 0 {
+                *tab_index
+            } else {
+                i32::MAX
+            }
+        },
+    );
+
+    chain.into_iter().map(|(entity, _)| entity).collect()
+}
+
+// -- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tab_order() {
+        let chain = vec![
+            (1.into(), 0),
+            (2.into(), 2),
+            (3.into(), 0),
+            (4.into(), 1),
+            (5.into(), 2),
+        ];
+
+        let expected: Vec<Entity> = vec![4.into(), 2.into(), 5.into(), 1.into(), 3.into()];
+        assert_eq!(tab_order(chain), expected);
+    }
+}
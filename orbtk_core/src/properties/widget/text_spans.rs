@@ -0,0 +1,242 @@
+use crate::{
+    render::{RenderContext2D, RunLayout, TextRun},
+    utils::{Brush, FontStyle, FontWeight},
+};
+
+/// A `TextSpan` is a part of a rich text with its own style. Properties that are not set are
+/// taken from the widget that draws the text.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct TextSpan {
+    /// The text of the span.
+    pub text: String,
+
+    /// The font family of the span.
+    pub font: Option<String>,
+
+    /// The font size of the span.
+    pub font_size: Option<f64>,
+
+    /// The font weight of the span.
+    pub font_weight: Option<FontWeight>,
+
+    /// The font style of the span.
+    pub font_style: Option<FontStyle>,
+
+    /// The foreground of the span.
+    pub foreground: Option<Brush>,
+
+    /// If set to `true` the span is underlined.
+    pub underline: bool,
+
+    /// If set to `true` the span is struck through.
+    pub strikethrough: bool,
+
+    /// The target of the span if it is a link.
+    pub link: Option<String>,
+}
+
+impl TextSpan {
+    /// Creates a new span with the given text.
+    pub fn new(text: impl Into<String>) -> Self {
+        TextSpan {
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the font family.
+    pub fn font(mut self, font: impl Into<String>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the font size.
+    pub fn font_size(mut self, font_size: f64) -> Self {
+        self.font_size = Some(font_size);
+        self
+    }
+
+    /// Sets the font weight.
+    pub fn font_weight(mut self, font_weight: impl Into<FontWeight>) -> Self {
+        self.font_weight = Some(font_weight.into());
+        self
+    }
+
+    /// Sets the font style.
+    pub fn font_style(mut self, font_style: impl Into<FontStyle>) -> Self {
+        self.font_style = Some(font_style.into());
+        self
+    }
+
+    /// Sets the foreground.
+    pub fn foreground(mut self, foreground: impl Into<Brush>) -> Self {
+        self.foreground = Some(foreground.into());
+        self
+    }
+
+    /// Sets underline.
+    pub fn underline(mut self, underline: bool) -> Self {
+        self.underline = underline;
+        self
+    }
+
+    /// Sets strikethrough.
+    pub fn strikethrough(mut self, strikethrough: bool) -> Self {
+        self.strikethrough = strikethrough;
+        self
+    }
+
+    /// Sets the link target.
+    pub fn link(mut self, link: impl Into<String>) -> Self {
+        self.link = Some(link.into());
+        self
+    }
+}
+
+impl From<&str> for TextSpan {
+    fn from(text: &str) -> Self {
+        TextSpan::new(text)
+    }
+}
+
+impl From<String> for TextSpan {
+    fn from(text: String) -> Self {
+        TextSpan::new(text)
+    }
+}
+
+/// `TextSpans` describes a rich text as list of spans.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct TextSpans(pub Vec<TextSpan>);
+
+impl TextSpans {
+    /// Creates an empty list of spans.
+    pub fn new() -> Self {
+        TextSpans::default()
+    }
+
+    /// Adds a span.
+    pub fn span(mut self, span: impl Into<TextSpan>) -> Self {
+        self.0.push(span.into());
+        self
+    }
+
+    /// Returns `true` if there are no spans.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Gets the span with the given index.
+    pub fn get(&self, index: usize) -> Option<&TextSpan> {
+        self.0.get(index)
+    }
+
+    /// Returns the text of all spans.
+    pub fn text(&self) -> String {
+        self.0.iter().map(|span| span.text.as_str()).collect()
+    }
+
+    /// Returns `true` if one of the spans is a link.
+    pub fn has_links(&self) -> bool {
+        self.0.iter().any(|span| span.link.is_some())
+    }
+
+    /// Lays out the spans. `font`, `font_size`, `font_weight` and `font_style` are used for spans
+    /// that do not set them. `line_height` is a factor of the largest font size.
+    #[allow(clippy::too_many_arguments)]
+    pub fn layout(
+        &self,
+        render_context_2_d: &RenderContext2D,
+        font: &str,
+        font_size: f64,
+        font_weight: FontWeight,
+        font_style: FontStyle,
+        max_width: Option<f64>,
+        line_height: f64,
+    ) -> RunLayout {
+        let runs = self.runs(font, font_size, font_weight, font_style);
+        let max_font_size = runs.iter().map(|run| run.font_size).fold(0.0, f64::max);
+
+        render_context_2_d.layout_runs(&runs, max_width, max_font_size * line_height)
+    }
+
+    // Converts the spans to runs of the text layout, with the given style for unset properties.
+    fn runs<'a>(
+        &'a self,
+        font: &'a str,
+        font_size: f64,
+        font_weight: FontWeight,
+        font_style: FontStyle,
+    ) -> Vec<TextRun<'a>> {
+        self.0
+            .iter()
+            .map(|span| TextRun {
+                text: span.text.as_str(),
+                family: span.font.as_deref().unwrap_or(font),
+                font_size: span.font_size.unwrap_or(font_size),
+                font_weight: span.font_weight.unwrap_or(font_weight),
+                font_style: span.font_style.unwrap_or(font_style),
+            })
+            .collect()
+    }
+}
+
+impl From<Vec<TextSpan>> for TextSpans {
+    fn from(spans: Vec<TextSpan>) -> Self {
+        TextSpans(spans)
+    }
+}
+
+impl From<TextSpan> for TextSpans {
+    fn from(span: TextSpan) -> Self {
+        TextSpans(vec![span])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder() {
+        let spans = TextSpans::new()
+            .span("Read the ")
+            .span(
+                TextSpan::new("docs")
+                    .link("https://www.redox-os.org")
+                    .underline(true),
+            )
+            .span(".");
+
+        assert_eq!(spans.text(), "Read the docs.");
+        assert!(spans.has_links());
+        assert_eq!(
+            spans.get(1).and_then(|span| span.link.as_deref()),
+            Some("https://www.redox-os.org")
+        );
+        assert!(spans.get(1).unwrap().underline);
+        assert_eq!(
+            TextSpan::new("bold").font_weight("bold").font_weight,
+            Some(FontWeight::Bold)
+        );
+        assert!(!TextSpans::from(TextSpan::new("text")).has_links());
+    }
+
+    #[test]
+    fn test_runs() {
+        let spans = TextSpans::new()
+            .span("normal ")
+            .span(TextSpan::new("bold").font_weight(FontWeight::Bold))
+            .span(TextSpan::new(" italic").font_style(FontStyle::Italic));
+
+        let runs = spans.runs("Roboto", 12.0, FontWeight::Light, FontStyle::Normal);
+
+        assert_eq!(runs.len(), 3);
+        assert_eq!(runs[0].font_weight, FontWeight::Light);
+        assert_eq!(runs[1].font_weight, FontWeight::Bold);
+        assert_eq!(runs[1].font_style, FontStyle::Normal);
+        assert_eq!(runs[2].font_weight, FontWeight::Light);
+        assert_eq!(runs[2].font_style, FontStyle::Italic);
+        assert_eq!(runs[2].family, "Roboto");
+    }
+}
@@ -22,6 +22,11 @@ impl RenderObject for CursorRenderObject {
             selection_width,
             selection_x,
             offset,
+            cursor_y,
+            selection_y,
+            selection_end_x,
+            selection_end_y,
+            line_height,
         ) = {
             let widget = ctx.widget();
             (
@@ -34,29 +39,70 @@ impl RenderObject for CursorRenderObject {
                 *widget.get::<f64>("selection_width"),
                 *widget.get::<f64>("selection_x"),
                 *widget.get::<f64>("offset"),
+                widget.try_get::<f64>("cursor_y").map_or(0.0, |y| *y),
+                widget.try_get::<f64>("selection_y").map_or(0.0, |y| *y),
+                widget.try_get::<f64>("selection_end_x").map_or(0.0, |x| *x),
+                widget.try_get::<f64>("selection_end_y").map_or(0.0, |y| *y),
+                widget.try_get::<f64>("line_height").map_or(0.0, |h| *h),
             )
         };
 
         let border_width = border_width.right();
+        let x = global_position.x() + bounds.x() + offset - border_width / 2.;
+        let y = global_position.y() + bounds.y();
 
         // background
         ctx.render_context_2_d().set_alpha(background_opacity);
         ctx.render_context_2_d().set_fill_style(background);
-        ctx.render_context_2_d().fill_rect(
-            global_position.x() + bounds.x() + offset + selection_x - border_width / 2.,
-            global_position.y() + bounds.y(),
-            selection_width,
-            bounds.height(),
-        );
+
+        if line_height <= 0. {
+            ctx.render_context_2_d().fill_rect(
+                x + selection_x,
+                y,
+                selection_width,
+                bounds.height(),
+            );
+        } else if selection_end_y <= selection_y {
+            ctx.render_context_2_d().fill_rect(
+                x + selection_x,
+                y + selection_y,
+                selection_width,
+                line_height,
+            );
+        } else {
+            // the selection spans multiple lines: the first line is filled from the start of
+            // the selection, the last line up to its end and all lines in between completely.
+            ctx.render_context_2_d().fill_rect(
+                x + selection_x,
+                y + selection_y,
+                (bounds.width() - offset - selection_x).max(0.),
+                line_height,
+            );
+            ctx.render_context_2_d().fill_rect(
+                x - offset,
+                y + selection_y + line_height,
+                bounds.width(),
+                selection_end_y - selection_y - line_height,
+            );
+            ctx.render_context_2_d().fill_rect(
+                x,
+                y + selection_end_y,
+                selection_end_x,
+                line_height,
+            );
+        }
+
         ctx.render_context_2_d().set_alpha(1.);
 
         // border
+        let (cursor_y, cursor_height) = if line_height <= 0. {
+            (0., bounds.height())
+        } else {
+            (cursor_y, line_height)
+        };
+
         ctx.render_context_2_d().set_fill_style(border_brush);
-        ctx.render_context_2_d().fill_rect(
-            global_position.x() + bounds.x() + offset + cursor_x - border_width / 2.,
-            global_position.y() + bounds.y(),
-            border_width,
-            bounds.height(),
-        );
+        ctx.render_context_2_d()
+            .fill_rect(x + cursor_x, y + cursor_y, border_width, cursor_height);
     }
 }
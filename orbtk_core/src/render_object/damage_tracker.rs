@@ -0,0 +1,201 @@
+use std::collections::BTreeMap;
+
+use dces::prelude::*;
+
+use crate::{
+    prelude::*,
+    tree::Tree,
+    utils::{Rectangle, Shadow},
+};
+
+/// The `DamageTracker` remembers the area each widget and its children covered when they were
+/// drawn the last time. The `RenderSystem` uses it to repaint only the damaged region of the
+/// window instead of the whole tree.
+#[derive(Debug, Default, Clone)]
+pub struct DamageTracker {
+    extents: BTreeMap<Entity, Rectangle>,
+    region: Option<Rectangle>,
+}
+
+impl DamageTracker {
+    /// Creates a new damage tracker.
+    pub fn new() -> Self {
+        DamageTracker::default()
+    }
+
+    /// Returns the area the widget and its children covered when they were drawn the last time.
+    pub fn extent(&self, entity: Entity) -> Option<Rectangle> {
+        self.extents.get(&entity).copied()
+    }
+
+    /// Sets the area the widget and its children cover.
+    pub fn set_extent(&mut self, entity: Entity, extent: Rectangle) {
+        self.extents.insert(entity, extent);
+    }
+
+    /// Removes the extent of the given widget and returns it.
+    pub fn remove(&mut self, entity: Entity) -> Option<Rectangle> {
+        self.extents.remove(&entity)
+    }
+
+    /// Removes the extents of all widgets `f` returns `false` for and returns the area they
+    /// covered.
+    pub fn retain(&mut self, mut f: impl FnMut(Entity) -> bool) -> Option<Rectangle> {
+        let mut removed = None;
+
+        self.extents.retain(|entity, extent| {
+            let keep = f(*entity);
+            if !keep {
+                join_damage(&mut removed, extent);
+            }
+            keep
+        });
+
+        removed
+    }
+
+    /// Returns the region that is repainted in the current frame. `None` means the whole window.
+    pub fn region(&self) -> Option<Rectangle> {
+        self.region
+    }
+
+    /// Sets the region that is repainted in the current frame.
+    pub fn set_region(&mut self, region: Option<Rectangle>) {
+        self.region = region;
+    }
+
+    /// Returns `true` if the given area intersects the region that is repainted.
+    pub fn is_damaged(&self, rect: &Rectangle) -> bool {
+        self.region.map_or(true, |region| region.intersects(rect))
+    }
+
+    /// Removes all extents.
+    pub fn clear(&mut self) {
+        self.extents.clear();
+        self.region = None;
+    }
+}
+
+// -- Helpers --
+
+// Joins `rect` into the optional rectangle `target`.
+pub(crate) fn join_damage(target: &mut Option<Rectangle>, rect: &Rectangle) {
+    match target {
+        Some(target) => target.join_with_rectangle(rect),
+        None => *target = Some(*rect),
+    }
+}
+
+// Returns the area the widget paints to. `position` is the global position of its parent. The
+// bounds are extended by the drop shadow and a small margin for anti aliased edges.
+pub(crate) fn paint_rect(
+    ecm: &EntityComponentManager<Tree>,
+    entity: Entity,
+    position: (f64, f64),
+) -> Option<Rectangle> {
+    let bounds = ecm
+        .component_store()
+        .get::<Rectangle>("bounds", entity)
+        .ok()?;
+
+    let mut rect = Rectangle::new(
+        (position.0 + bounds.x() - 2.0, position.1 + bounds.y() - 2.0),
+        (bounds.width() + 4.0, bounds.height() + 4.0),
+    );
+
+    if let Ok(shadow) = ecm.component_store().get::<Shadow>("shadow", entity) {
+        let extend = shadow.spread().max(0.0) + shadow.blur().max(0.0).ceil() + 1.0;
+        rect.join_with_rectangle(&Rectangle::new(
+            (
+                rect.x() + shadow.offset().x() - extend,
+                rect.y() + shadow.offset().y() - extend,
+            ),
+            (rect.width() + 2.0 * extend, rect.height() + 2.0 * extend),
+        ));
+    }
+
+    Some(rect)
+}
+
+// Returns the global position of the parent of the widget.
+pub(crate) fn parent_position(ecm: &EntityComponentManager<Tree>, entity: Entity) -> (f64, f64) {
+    let mut position = (0.0, 0.0);
+    let mut current = ecm.entity_store().parent.get(&entity).copied().flatten();
+
+    while let Some(parent) = current {
+        if let Ok(bounds) = ecm.component_store().get::<Rectangle>("bounds", parent) {
+            position.0 += bounds.x();
+            position.1 += bounds.y();
+        }
+        current = ecm.entity_store().parent.get(&parent).copied().flatten();
+    }
+
+    position
+}
+
+// Returns the area the visible widget and its visible children will cover. `position` is the
+// global position of the parent of the widget.
+pub(crate) fn subtree_rect(
+    ecm: &EntityComponentManager<Tree>,
+    entity: Entity,
+    position: (f64, f64),
+) -> Option<Rectangle> {
+    match ecm
+        .component_store()
+        .get::<Visibility>("visibility", entity)
+    {
+        Ok(Visibility::Visible) => {}
+        _ => return None,
+    }
+
+    let mut rect = paint_rect(ecm, entity, position);
+
+    let position = match ecm.component_store().get::<Rectangle>("bounds", entity) {
+        Ok(bounds) => (position.0 + bounds.x(), position.1 + bounds.y()),
+        Err(_) => position,
+    };
+
+    if let Some(children) = ecm.entity_store().children.get(&entity) {
+        for child in children {
+            if let Some(child_rect) = subtree_rect(ecm, *child, position) {
+                join_damage(&mut rect, &child_rect);
+            }
+        }
+    }
+
+    rect
+}
+
+// -- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retain() {
+        let mut tracker = DamageTracker::new();
+        tracker.set_extent(1.into(), Rectangle::new((0.0, 0.0), (10.0, 10.0)));
+        tracker.set_extent(2.into(), Rectangle::new((20.0, 20.0), (10.0, 10.0)));
+        tracker.set_extent(3.into(), Rectangle::new((40.0, 0.0), (10.0, 10.0)));
+
+        assert_eq!(
+            tracker.retain(|entity| entity == 1.into()),
+            Some(Rectangle::new((20.0, 0.0), (30.0, 30.0)))
+        );
+        assert!(tracker.extent(1.into()).is_some());
+        assert!(tracker.extent(2.into()).is_none());
+        assert_eq!(tracker.retain(|_| true), None);
+    }
+
+    #[test]
+    fn test_is_damaged() {
+        let mut tracker = DamageTracker::new();
+        let rect = Rectangle::new((0.0, 0.0), (10.0, 10.0));
+        assert!(tracker.is_damaged(&rect));
+
+        tracker.set_region(Some(Rectangle::new((20.0, 20.0), (10.0, 10.0))));
+        assert!(!tracker.is_damaged(&rect));
+        assert!(tracker.is_damaged(&Rectangle::new((15.0, 15.0), (10.0, 10.0))));
+    }
+}
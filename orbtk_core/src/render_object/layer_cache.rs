@@ -0,0 +1,151 @@
+use std::collections::BTreeMap;
+
+use dces::prelude::*;
+
+use crate::{
+    render::RenderTarget,
+    render_object::subtree_rect,
+    tree::Tree,
+    utils::{Point, Rectangle, Size},
+};
+
+/// A `Layer` is the drawn subtree of a widget with the `cache` property.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layer {
+    /// The drawn widget and its children.
+    pub render_target: RenderTarget,
+
+    /// The area of the window the layer covers.
+    pub rect: Rectangle,
+
+    /// The global position of the widget when the layer was drawn.
+    pub position: Point,
+}
+
+impl Layer {
+    /// Creates a new layer.
+    pub fn new(render_target: RenderTarget, rect: Rectangle, position: Point) -> Self {
+        Layer {
+            render_target,
+            rect,
+            position,
+        }
+    }
+}
+
+/// The `LayerCache` stores the layers of widgets with the `cache` property. A layer is drawn
+/// instead of the widget and its children until one of them is dirty.
+#[derive(Debug, Default, Clone)]
+pub struct LayerCache {
+    layers: BTreeMap<Entity, Layer>,
+    size: Size,
+}
+
+impl LayerCache {
+    /// Creates a new layer cache.
+    pub fn new() -> Self {
+        LayerCache::default()
+    }
+
+    /// Returns the size of the window.
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Sets the size of the window. Layers are limited to the window, so all layers are removed
+    /// if the size changes.
+    pub fn set_size(&mut self, size: Size) {
+        if self.size != size {
+            self.size = size;
+            self.layers.clear();
+        }
+    }
+
+    /// Returns the layer of the given widget.
+    pub fn get(&self, entity: Entity) -> Option<&Layer> {
+        self.layers.get(&entity)
+    }
+
+    /// Stores the layer of the given widget.
+    pub fn insert(&mut self, entity: Entity, layer: Layer) {
+        self.layers.insert(entity, layer);
+    }
+
+    /// Removes the layer of the given widget.
+    pub fn remove(&mut self, entity: Entity) -> Option<Layer> {
+        self.layers.remove(&entity)
+    }
+
+    /// Removes all layers that intersect the given area.
+    pub fn remove_intersecting(&mut self, rect: &Rectangle) {
+        self.layers.retain(|_, layer| !layer.rect.intersects(rect));
+    }
+
+    /// Returns the number of layers.
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Returns `true` if there are no layers.
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// Removes all layers.
+    pub fn clear(&mut self) {
+        self.layers.clear();
+    }
+}
+
+// -- Helpers --
+
+// Returns the pixel aligned area of the window the layer of the widget and its children covers.
+// `position` is the global position of the parent of the widget.
+pub(crate) fn layer_rect(
+    ecm: &EntityComponentManager<Tree>,
+    entity: Entity,
+    position: (f64, f64),
+    size: Size,
+) -> Option<Rectangle> {
+    let rect = subtree_rect(ecm, entity, position)?;
+
+    let left = rect.x().floor().max(0.0);
+    let top = rect.y().floor().max(0.0);
+    let right = (rect.x() + rect.width()).ceil().min(size.width());
+    let bottom = (rect.y() + rect.height()).ceil().min(size.height());
+
+    if right <= left || bottom <= top {
+        return None;
+    }
+
+    Some(Rectangle::new((left, top), (right - left, bottom - top)))
+}
+
+// -- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layer(x: f64, y: f64) -> Layer {
+        Layer::new(
+            RenderTarget::new(10, 10),
+            Rectangle::new((x, y), (10.0, 10.0)),
+            Point::new(x, y),
+        )
+    }
+
+    #[test]
+    fn test_remove_intersecting() {
+        let mut cache = LayerCache::new();
+        cache.insert(1.into(), layer(0.0, 0.0));
+        cache.insert(2.into(), layer(50.0, 50.0));
+
+        cache.remove_intersecting(&Rectangle::new((5.0, 5.0), (2.0, 2.0)));
+        assert!(cache.get(1.into()).is_none());
+        assert!(cache.get(2.into()).is_some());
+
+        cache.set_size(Size::new(100.0, 100.0));
+        assert!(cache.is_empty());
+    }
+}
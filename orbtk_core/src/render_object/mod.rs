@@ -7,19 +7,25 @@ use dces::prelude::*;
 use crate::{application::ContextProvider, prelude::*, render::RenderContext2D, utils::*};
 
 pub use self::cursor::*;
+pub use self::damage_tracker::*;
 pub use self::default::*;
 pub use self::font_icon::*;
 pub use self::image::*;
+pub use self::layer_cache::*;
 pub use self::pipeline::*;
 pub use self::rectangle::*;
+pub use self::rich_text::*;
 pub use self::text::*;
 
 mod cursor;
+mod damage_tracker;
 mod default;
 mod font_icon;
 mod image;
+mod layer_cache;
 mod pipeline;
 mod rectangle;
+mod rich_text;
 mod text;
 
 pub trait RenderObject: Any {
@@ -46,12 +52,80 @@ pub trait RenderObject: Any {
             .get::<Visibility>("visibility", entity)
         {
             if *visibility != Visibility::Visible {
+                context_provider.damage_tracker.borrow_mut().remove(entity);
                 return;
             }
         } else {
             return;
         }
 
+        // skips widgets outside of the damaged region
+        let paint_rect = paint_rect(ecm, entity, (global_position.x(), global_position.y()));
+        {
+            let damage_tracker = context_provider.damage_tracker.borrow();
+            let mut extent = damage_tracker.extent(entity);
+            if let Some(paint_rect) = &paint_rect {
+                join_damage(&mut extent, paint_rect);
+            }
+
+            if let Some(extent) = extent {
+                if !damage_tracker.is_damaged(&extent) {
+                    return;
+                }
+            }
+        }
+
+        let position = ecm
+            .component_store()
+            .get::<Rectangle>("bounds", entity)
+            .map_or(global_position, |bounds| {
+                Point::new(
+                    global_position.x() + bounds.x(),
+                    global_position.y() + bounds.y(),
+                )
+            });
+
+        // draws the cached layer instead of the widget and its children
+        let cache = *ecm.component_store().get::<bool>("cache", entity).unwrap();
+        if cache {
+            if let Some(layer) = context_provider.layer_cache.borrow().get(entity) {
+                if layer.position == position {
+                    render_context.draw_render_target(
+                        &layer.render_target,
+                        layer.rect.x(),
+                        layer.rect.y(),
+                    );
+                    return;
+                }
+            }
+        }
+
+        let layer_rect = if cache {
+            layer_rect(
+                ecm,
+                entity,
+                (global_position.x(), global_position.y()),
+                context_provider.layer_cache.borrow().size(),
+            )
+        } else {
+            None
+        };
+
+        // the layer contains the whole widget, not only the damaged part
+        let region = context_provider.damage_tracker.borrow().region();
+        if let Some(layer_rect) = &layer_rect {
+            render_context.begin_layer(
+                layer_rect.x(),
+                layer_rect.y(),
+                layer_rect.width(),
+                layer_rect.height(),
+            );
+            context_provider
+                .damage_tracker
+                .borrow_mut()
+                .set_region(None);
+        }
+
         render_context.begin_path();
         render_context.set_alpha(
             *ecm.component_store()
@@ -113,6 +187,34 @@ pub trait RenderObject: Any {
             render_context.restore();
         }
 
+        if let Some(layer_rect) = layer_rect {
+            let render_target = render_context.end_layer();
+            context_provider
+                .damage_tracker
+                .borrow_mut()
+                .set_region(region);
+            render_context.draw_render_target(&render_target, layer_rect.x(), layer_rect.y());
+            context_provider
+                .layer_cache
+                .borrow_mut()
+                .insert(entity, Layer::new(render_target, layer_rect, position));
+        }
+
+        // remembers the area of the widget and its children for the next frame
+        {
+            let mut damage_tracker = context_provider.damage_tracker.borrow_mut();
+            let mut extent = paint_rect;
+            for child in &ecm.entity_store().children[&entity] {
+                if let Some(child_extent) = damage_tracker.extent(*child) {
+                    join_damage(&mut extent, &child_extent);
+                }
+            }
+
+            if let Some(extent) = extent {
+                damage_tracker.set_extent(entity, extent);
+            }
+        }
+
         // render debug border for each widget
         if debug {
             if let Ok(bounds) = ecm.component_store().get::<Rectangle>("bounds", entity) {
@@ -5,7 +5,7 @@ use crate::{
     render::RenderContext2D,
     render_object::*,
     utils,
-    utils::{Brush, Point, Rectangle, Thickness},
+    utils::{BorderStyle, Brush, Point, Rectangle, Shadow, Thickness},
 };
 
 #[derive(Debug, IntoRenderObject)]
@@ -130,9 +130,58 @@ impl RectangleRenderObject {
     }
 }
 
+impl RectangleRenderObject {
+    // Renders the blurred drop shadow of the (rounded) rectangle.
+    fn render_shadow(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        rect: Rectangle,
+        radius: f64,
+        shadow: Shadow,
+    ) {
+        let spread = shadow.spread();
+        let width = rect.width() + 2.0 * spread;
+        let height = rect.height() + 2.0 * spread;
+
+        if width <= 0.0 || height <= 0.0 {
+            return;
+        }
+
+        render_context_2_d.begin_path();
+
+        if radius > 0.0 {
+            self.render_rounded_rect_path(
+                render_context_2_d,
+                rect.x() - spread,
+                rect.y() - spread,
+                width,
+                height,
+                (radius + spread).max(0.0).min(width.min(height) / 2.0),
+            );
+        } else {
+            render_context_2_d.rect(rect.x() - spread, rect.y() - spread, width, height);
+        }
+
+        render_context_2_d.fill_shadow(
+            shadow.offset().x(),
+            shadow.offset().y(),
+            shadow.blur(),
+            shadow.color(),
+        );
+    }
+}
+
 impl RenderObject for RectangleRenderObject {
     fn render_self(&self, ctx: &mut Context, global_position: &Point) {
-        let (bounds, background, border_radius, border_thickness, border_brush) = {
+        let (
+            bounds,
+            background,
+            border_radius,
+            border_thickness,
+            border_brush,
+            border_style,
+            shadow,
+        ) = {
             let widget = ctx.widget();
             (
                 widget.clone::<Rectangle>("bounds"),
@@ -140,6 +189,8 @@ impl RenderObject for RectangleRenderObject {
                 widget.clone_or_default::<f64>("border_radius"),
                 widget.clone_or_default::<Thickness>("border_width"),
                 widget.clone_or_default::<Brush>("border_brush"),
+                widget.clone_or_default::<BorderStyle>("border_style"),
+                widget.clone_or_default::<Shadow>("shadow"),
             )
         };
 
@@ -159,7 +210,18 @@ impl RenderObject for RectangleRenderObject {
             || border_thickness.right > 0.0
             || border_thickness.bottom > 0.0;
 
+        if !shadow.is_none() {
+            self.render_shadow(
+                ctx.render_context_2_d(),
+                Rectangle::new(*global_position + bounds.position(), bounds.size()),
+                border_radius,
+                shadow,
+            );
+        }
+
         ctx.render_context_2_d().begin_path();
+        ctx.render_context_2_d()
+            .set_line_dash(border_style.line_dash(border_thickness.left()));
 
         if (bounds.width() - bounds.height()).abs() < f64::EPSILON
             && border_radius >= bounds.width() / 2.0
@@ -226,5 +288,7 @@ impl RenderObject for RectangleRenderObject {
                 bounds.height(),
             );
         }
+
+        ctx.render_context_2_d().set_line_dash(vec![]);
     }
 }
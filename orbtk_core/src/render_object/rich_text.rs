@@ -0,0 +1,111 @@
+use crate::{
+    layout::DEFAULT_LINE_HEIGHT,
+    proc_macros::IntoRenderObject,
+    render_object::*,
+    utils::{Brush, FontStyle, FontWeight, Point, Rectangle, TextAlignment},
+};
+
+/// Used to render rich text of the `spans` property. Each span is drawn with its own font, size
+/// and foreground. The spans are wrapped like a text and placed on a common base line.
+#[derive(Debug, IntoRenderObject)]
+pub struct RichTextRenderObject;
+
+impl RenderObject for RichTextRenderObject {
+    fn render_self(&self, ctx: &mut Context, global_position: &Point) {
+        let (bounds, spans, foreground, font, font_size, offset) = {
+            let widget = ctx.widget();
+            (
+                *widget.get::<Rectangle>("bounds"),
+                widget.clone::<TextSpans>("spans"),
+                widget.get::<Brush>("foreground").clone(),
+                widget.get::<String>("font").clone(),
+                *widget.get::<f64>("font_size"),
+                widget.try_get::<f64>("offset").map_or(0.0, |o| *o),
+            )
+        };
+
+        let (text_wrap, line_height, text_align, font_weight, font_style) = {
+            let widget = ctx.widget();
+            (
+                widget.try_get::<bool>("text_wrap").map_or(false, |w| *w),
+                widget
+                    .try_get::<f64>("line_height")
+                    .map_or(DEFAULT_LINE_HEIGHT, |h| *h),
+                widget
+                    .try_get::<TextAlignment>("text_align")
+                    .map_or(TextAlignment::Left, |a| *a),
+                widget
+                    .try_get::<FontWeight>("font_weight")
+                    .map_or(FontWeight::Normal, |w| *w),
+                widget
+                    .try_get::<FontStyle>("font_style")
+                    .map_or(FontStyle::Normal, |s| *s),
+            )
+        };
+
+        if bounds.width() == 0.0 || bounds.height() == 0.0 || spans.is_empty() {
+            return;
+        }
+
+        let max_width = if text_wrap {
+            Some(bounds.width())
+        } else {
+            None
+        };
+        let layout = spans.layout(
+            ctx.render_context_2_d(),
+            &font,
+            font_size,
+            font_weight,
+            font_style,
+            max_width,
+            line_height,
+        );
+
+        let x = global_position.x() + bounds.x() + offset;
+        let y = global_position.y() + bounds.y();
+
+        ctx.render_context_2_d().begin_path();
+
+        for fragment in layout.fragments() {
+            let span = match spans.get(fragment.run) {
+                Some(span) => span,
+                None => continue,
+            };
+
+            let span_foreground = span.foreground.as_ref().unwrap_or(&foreground);
+            let span_font_size = span.font_size.unwrap_or(font_size);
+
+            if span_foreground.is_transparent() || span_font_size == 0.0 {
+                continue;
+            }
+
+            let fragment_x = x
+                + layout
+                    .layout()
+                    .line_x(fragment.line, bounds.width(), text_align)
+                + fragment.x;
+            let line_y = y + layout.layout().line_y(fragment.line);
+
+            let render_context_2_d = ctx.render_context_2_d();
+            render_context_2_d.set_font_family(span.font.as_deref().unwrap_or(&font));
+            render_context_2_d.set_font_size(span_font_size);
+            render_context_2_d.set_font_weight(span.font_weight.unwrap_or(font_weight));
+            render_context_2_d.set_font_style(span.font_style.unwrap_or(font_style));
+            render_context_2_d.set_fill_style(span_foreground.clone());
+
+            let fragment_y = line_y + fragment.y;
+            render_context_2_d.fill_text(&fragment.text, fragment_x, fragment_y);
+
+            if span.underline {
+                render_context_2_d.fill_underline(fragment_x, fragment_y, fragment.width);
+            }
+
+            if span.strikethrough {
+                render_context_2_d.fill_strikethrough(fragment_x, fragment_y, fragment.width);
+            }
+        }
+
+        ctx.render_context_2_d().close_path();
+    }
+}
@@ -1,17 +1,26 @@
 use crate::{
+    layout::DEFAULT_LINE_HEIGHT,
     proc_macros::IntoRenderObject,
     render_object::*,
-    utils::{Brush, Point, Rectangle},
+    utils::{Brush, FontStyle, FontWeight, Point, Rectangle, TextAlignment},
 };
-use memchr::memchr_iter;
-use std::iter;
 
-/// Used to render a text.
+/// Used to render a text. If the widget has `spans`, they are drawn as rich text instead.
 #[derive(Debug, IntoRenderObject)]
 pub struct TextRenderObject;
 
 impl RenderObject for TextRenderObject {
     fn render_self(&self, ctx: &mut Context, global_position: &Point) {
+        let has_spans = ctx
+            .widget()
+            .try_get::<TextSpans>("spans")
+            .map_or(false, |spans| !spans.is_empty());
+
+        if has_spans {
+            RichTextRenderObject.render_self(ctx, global_position);
+            return;
+        }
+
         let (bounds, text, foreground, font, font_size, offset) = {
             let widget = ctx.widget();
             let text = text(&widget);
@@ -34,6 +43,35 @@ impl RenderObject for TextRenderObject {
             )
         };
 
+        let (text_wrap, line_height, text_align) = {
+            let widget = ctx.widget();
+            (
+                widget.try_get::<bool>("text_wrap").map_or(false, |w| *w),
+                widget
+                    .try_get::<f64>("line_height")
+                    .map_or(DEFAULT_LINE_HEIGHT, |h| *h),
+                widget
+                    .try_get::<TextAlignment>("text_align")
+                    .map_or(TextAlignment::Left, |a| *a),
+            )
+        };
+
+        let (font_weight, font_style, underline, strikethrough) = {
+            let widget = ctx.widget();
+            (
+                widget
+                    .try_get::<FontWeight>("font_weight")
+                    .map_or(FontWeight::Normal, |w| *w),
+                widget
+                    .try_get::<FontStyle>("font_style")
+                    .map_or(FontStyle::Normal, |s| *s),
+                widget.try_get::<bool>("underline").map_or(false, |u| *u),
+                widget
+                    .try_get::<bool>("strikethrough")
+                    .map_or(false, |s| *s),
+            )
+        };
+
         if bounds.width() == 0.0
             || bounds.height() == 0.0
             || foreground.is_transparent()
@@ -49,18 +87,40 @@ impl RenderObject for TextRenderObject {
         ctx.render_context_2_d().begin_path();
         ctx.render_context_2_d().set_font_family(font);
         ctx.render_context_2_d().set_font_size(font_size);
+        ctx.render_context_2_d().set_font_weight(font_weight);
+        ctx.render_context_2_d().set_font_style(font_style);
         ctx.render_context_2_d().set_fill_style(foreground);
 
-        let mut y_disp = 0.0;
-        let mut last_ofs = 0;
-        for i in memchr_iter(b'\n', text.as_bytes()).chain(iter::once(text.len())) {
-            ctx.render_context_2_d().fill_text(
-                &text[last_ofs..i],
-                global_position.x() + bounds.x() + offset,
-                global_position.y() + bounds.y() + y_disp,
-            );
-            y_disp += font_size * 1.15; // TODO: Make the space between lines customizable
-            last_ofs = i + 1; // + 1 to skip the end of line character
+        let max_width = if text_wrap {
+            Some(bounds.width())
+        } else {
+            None
+        };
+        let layout =
+            ctx.render_context_2_d()
+                .layout_text(&text, max_width, font_size * line_height);
+
+        let x = global_position.x() + bounds.x() + offset;
+        let y = global_position.y() + bounds.y();
+
+        ctx.render_context_2_d()
+            .fill_text_layout(&layout, x, y, bounds.width(), text_align);
+
+        if underline || strikethrough {
+            for (i, line) in layout.lines().iter().enumerate() {
+                let line_x = x + layout.line_x(i, bounds.width(), text_align);
+                let line_y = y + layout.line_y(i);
+
+                if underline {
+                    ctx.render_context_2_d()
+                        .fill_underline(line_x, line_y, line.width);
+                }
+
+                if strikethrough {
+                    ctx.render_context_2_d()
+                        .fill_strikethrough(line_x, line_y, line.width);
+                }
+            }
         }
 
         ctx.render_context_2_d().close_path();
@@ -0,0 +1,145 @@
+use std::fmt;
+
+use crate::accessibility::{AccessibilityEvent, AccessibilityTree};
+
+/// Connects the accessibility tree of a window with a platform accessibility API, e.g. AT-SPI on
+/// Linux.
+///
+/// On Linux the application installs an `AccessKitAdapter` that publishes the tree over AT-SPI
+/// if the bus is available. An application can replace it with its own adapter.
+pub trait AccessibilityAdapter {
+    /// Is called with the new tree and its changes after the widgets of the window are updated.
+    fn update(&mut self, tree: &AccessibilityTree, events: &[AccessibilityEvent]);
+}
+
+/// The `Accessibility` service keeps the accessibility tree of a window up to date and notifies
+/// the accessibility adapter about changes. The tree is only created if an adapter is set or the
+/// service is enabled.
+///
+/// # Examples
+/// ```
+/// struct Logger;
+///
+/// impl AccessibilityAdapter for Logger {
+///     fn update(&mut self, tree: &AccessibilityTree, events: &[AccessibilityEvent]) {
+///         for event in events {
+///             println!("{:?}", event);
+///         }
+///     }
+/// }
+///
+/// impl State for MyState {
+///     fn init(&mut self, registry: &mut Registry, _: &mut Context) {
+///         registry
+///             .get_mut::<Accessibility>("accessibility")
+///             .set_adapter(Logger);
+///     }
+/// }
+/// ```
+#[derive(Default)]
+pub struct Accessibility {
+    enabled: bool,
+    tree: AccessibilityTree,
+    adapter: Option<Box<dyn AccessibilityAdapter>>,
+}
+
+impl Accessibility {
+    /// Creates a new disabled `Accessibility` service.
+    pub fn new() -> Self {
+        Accessibility::default()
+    }
+
+    /// Sets the adapter that is notified about changes of the tree and enables the service.
+    pub fn set_adapter(&mut self, adapter: impl AccessibilityAdapter + 'static) {
+        self.adapter = Some(Box::new(adapter));
+        self.enabled = true;
+    }
+
+    /// Removes the adapter.
+    pub fn remove_adapter(&mut self) {
+        self.adapter = None;
+    }
+
+    /// Enables or disables the creation of the tree.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+
+        if !enabled {
+            self.tree = AccessibilityTree::new();
+        }
+    }
+
+    /// Returns `true` if the tree is created on each update.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the current accessibility tree.
+    pub fn tree(&self) -> &AccessibilityTree {
+        &self.tree
+    }
+
+    /// Replaces the tree, notifies the adapter about the changes and returns them. It is called
+    /// by the window after the widgets are updated.
+    pub fn update(&mut self, tree: AccessibilityTree) -> Vec<AccessibilityEvent> {
+        let events = tree.changes(&self.tree);
+        self.tree = tree;
+
+        if let Some(adapter) = &mut self.adapter {
+            if !events.is_empty() {
+                adapter.update(&self.tree, &events);
+            }
+        }
+
+        events
+    }
+}
+
+impl fmt::Debug for Accessibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Accessibility")
+            .field("enabled", &self.enabled)
+            .field("tree", &self.tree)
+            .field("adapter", &self.adapter.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::*;
+    use crate::accessibility::{AccessibleNode, Role};
+
+    struct Recorder(Rc<RefCell<Vec<AccessibilityEvent>>>);
+
+    impl AccessibilityAdapter for Recorder {
+        fn update(&mut self, _: &AccessibilityTree, events: &[AccessibilityEvent]) {
+            self.0.borrow_mut().extend_from_slice(events);
+        }
+    }
+
+    #[test]
+    fn test_update() {
+        let events = Rc::new(RefCell::new(vec![]));
+        let mut accessibility = Accessibility::new();
+        assert!(!accessibility.is_enabled());
+
+        accessibility.set_adapter(Recorder(events.clone()));
+        assert!(accessibility.is_enabled());
+
+        let mut tree = AccessibilityTree::new();
+        tree.insert(
+            1.into(),
+            AccessibleNode {
+                role: Role::Window,
+                ..Default::default()
+            },
+        );
+
+        accessibility.update(tree.clone());
+        assert!(accessibility.update(tree).is_empty());
+        assert_eq!(*events.borrow(), vec![AccessibilityEvent::Added(1.into())]);
+    }
+}
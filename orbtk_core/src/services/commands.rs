@@ -0,0 +1,227 @@
+use std::{collections::HashMap, fmt, rc::Rc};
+
+use dces::entity::Entity;
+
+use crate::widget_base::{Context, Registry};
+
+// Runs a command.
+type Execute = Rc<dyn Fn(&mut Registry, &mut Context)>;
+
+/// A `Command` describes an action of the application like "Save" or "Undo" that can be used by
+/// several widgets. It can only be executed if its `can_execute` predicate returns `true`.
+#[derive(Clone)]
+pub struct Command {
+    label: String,
+    execute: Execute,
+    can_execute: Rc<dyn Fn() -> bool>,
+}
+
+impl Command {
+    /// Creates a new command with the given label that is always executable.
+    pub fn new(
+        label: impl Into<String>,
+        execute: impl Fn(&mut Registry, &mut Context) + 'static,
+    ) -> Self {
+        Command {
+            label: label.into(),
+            execute: Rc::new(execute),
+            can_execute: Rc::new(|| true),
+        }
+    }
+
+    /// Sets the predicate that decides if the command can be executed.
+    pub fn can_execute(mut self, can_execute: impl Fn() -> bool + 'static) -> Self {
+        self.can_execute = Rc::new(can_execute);
+        self
+    }
+
+    /// Returns the label of the command, e.g. to display it in a menu.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns `true` if the command can be executed.
+    pub fn is_executable(&self) -> bool {
+        (self.can_execute)()
+    }
+
+    /// Executes the command if it is executable. Returns `false` otherwise.
+    pub fn execute(&self, registry: &mut Registry, ctx: &mut Context) -> bool {
+        if !self.is_executable() {
+            return false;
+        }
+
+        (self.execute)(registry, ctx);
+        true
+    }
+}
+
+impl fmt::Debug for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Command")
+            .field("label", &self.label)
+            .finish()
+    }
+}
+
+#[derive(Debug)]
+struct CommandBinding {
+    entity: Entity,
+    key: String,
+    executable: Option<bool>,
+}
+
+/// The `Commands` service stores the commands of the application. Widgets like `Button` and
+/// `ToggleButton` execute the command of their `command` property and are only enabled if the
+/// command can be executed. The executable state is checked after the events of the window are
+/// handled and stored in the `command_enabled` property of the widgets, their `enabled`
+/// property is not changed. Commands can be bound to shortcuts by
+/// `Shortcuts::register_command`.
+///
+/// # Examples
+/// ```
+/// impl State for MainViewState {
+///     fn init(&mut self, registry: &mut Registry, ctx: &mut Context) {
+///         let document = self.document.clone();
+///
+///         registry.get_mut::<Commands>("commands").register(
+///             "save",
+///             Command::new("Save", move |registry, ctx| {
+///                 // ...
+///             })
+///             .can_execute(move || document.borrow().is_modified()),
+///         );
+///
+///         registry.get_mut::<Shortcuts>("shortcuts").register_command(
+///             ShortcutScope::Window,
+///             "Ctrl+S",
+///             "save",
+///         );
+///     }
+/// }
+///
+/// Button::new().text("Save").command("save").build(ctx)
+/// ```
+#[derive(Debug, Default)]
+pub struct Commands {
+    commands: HashMap<String, Command>,
+    bindings: Vec<CommandBinding>,
+}
+
+impl Commands {
+    /// Creates a new `Commands` service.
+    pub fn new() -> Self {
+        Commands::default()
+    }
+
+    /// Registers the command with the given key. Returns the replaced command with the same key.
+    pub fn register(&mut self, key: impl Into<String>, command: Command) -> Option<Command> {
+        self.commands.insert(key.into(), command)
+    }
+
+    /// Removes the command with the given key. Widgets that are bound to it are disabled.
+    pub fn unregister(&mut self, key: &str) -> Option<Command> {
+        self.commands.remove(key)
+    }
+
+    /// Returns the command with the given key.
+    pub fn get(&self, key: &str) -> Option<&Command> {
+        self.commands.get(key)
+    }
+
+    /// Returns an iterator over all commands and their keys.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Command)> {
+        self.commands.iter()
+    }
+
+    /// Returns `true` if the command with the given key exists and can be executed.
+    pub fn is_executable(&self, key: &str) -> bool {
+        self.commands
+            .get(key)
+            .map_or(false, |command| command.is_executable())
+    }
+
+    /// Binds the widget to the command with the given key. The widget is enabled if the command
+    /// can be executed. A previous binding of the widget is replaced.
+    pub fn bind(&mut self, entity: Entity, key: impl Into<String>) {
+        self.remove_entity(entity);
+        self.bindings.push(CommandBinding {
+            entity,
+            key: key.into(),
+            executable: None,
+        });
+    }
+
+    /// Removes the binding of the given widget.
+    pub fn remove_entity(&mut self, entity: Entity) {
+        self.bindings.retain(|binding| binding.entity != entity);
+    }
+
+    /// Returns the number of registered commands.
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    /// Returns `true` if no command is registered.
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Checks the executable state of the bound commands. Returns the widgets whose command
+    /// has changed its state since the last call together with the new state.
+    pub fn refresh(&mut self) -> Vec<(Entity, bool)> {
+        let mut changes = vec![];
+
+        for binding in &mut self.bindings {
+            let executable = self
+                .commands
+                .get(&binding.key)
+                .map_or(false, |command| command.is_executable());
+
+            if binding.executable != Some(executable) {
+                binding.executable = Some(executable);
+                changes.push((binding.entity, executable));
+            }
+        }
+
+        changes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    #[test]
+    fn test_refresh() {
+        let modified = Rc::new(Cell::new(false));
+        let mut commands = Commands::new();
+
+        let can_save = modified.clone();
+        commands.register(
+            "save",
+            Command::new("Save", |_, _| {}).can_execute(move || can_save.get()),
+        );
+        commands.bind(1.into(), "save");
+        commands.bind(2.into(), "undo");
+
+        let expected: Vec<(Entity, bool)> = vec![(1.into(), false), (2.into(), false)];
+        assert_eq!(commands.refresh(), expected);
+        assert!(commands.refresh().is_empty());
+
+        modified.set(true);
+        assert!(commands.is_executable("save"));
+        let expected: Vec<(Entity, bool)> = vec![(1.into(), true)];
+        assert_eq!(commands.refresh(), expected);
+        assert_eq!(
+            commands.get("save").map(|command| command.label()),
+            Some("Save")
+        );
+
+        commands.remove_entity(1.into());
+        modified.set(false);
+        assert!(commands.refresh().is_empty());
+    }
+}
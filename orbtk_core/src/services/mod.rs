@@ -1,7 +1,17 @@
 //! This module contains global services.
 //!
+pub use self::accessibility::*;
 pub use self::clipboard::*;
+pub use self::commands::*;
 pub use self::settings::*;
+pub use self::shortcuts::*;
+pub use self::tasks::*;
+pub use self::timer::*;
 
+mod accessibility;
 mod clipboard;
+mod commands;
 mod settings;
+mod shortcuts;
+mod tasks;
+mod timer;
@@ -4,9 +4,6 @@ use std::{
     io::Write,
 };
 
-#[cfg(not(target_arch = "wasm32"))]
-use threadpool::*;
-
 #[cfg(target_arch = "wasm32")]
 use stdweb::web::window;
 
@@ -22,7 +19,7 @@ use serde::{de::DeserializeOwned, Serialize};
 
 use dces::entity::Entity;
 
-use crate::widget_base::MessageAdapter;
+use crate::services::Tasks;
 
 #[derive(Debug)]
 pub enum SettingsError {
@@ -34,36 +31,24 @@ pub type SettingsResult<T> = Result<T, SettingsError>;
 
 /// `Settings` represents a global settings service that could be use to serialize and deserialize
 /// data in the `ron` file format. Settings are stored in the user settings directory (depending on the operating system)
-/// under the a folder with the given application name.
+/// under the a folder with the given application name. The async methods run on the given `Tasks` service.
 #[derive(Debug, Clone)]
 pub struct Settings {
     app_name: String,
-    message_adapter: MessageAdapter,
-
-    #[cfg(not(target_arch = "wasm32"))]
-    pool: ThreadPool,
+    tasks: Tasks,
 }
 
 impl Settings {
     /// Creates a new `Settings` service with an default name.
-    pub fn new(message_adapter: MessageAdapter) -> Self {
+    pub fn new(tasks: Tasks) -> Self {
         Settings {
             app_name: "orbtk_app".into(),
-            message_adapter,
-
-            #[cfg(not(target_arch = "wasm32"))]
-            pool: ThreadPool::new(4),
+            tasks,
         }
     }
     /// Creates a new `Settings` service with the given app name.
-    pub fn from_name(app_name: String, message_adapter: MessageAdapter) -> Self {
-        Settings {
-            app_name,
-            message_adapter,
-
-            #[cfg(not(target_arch = "wasm32"))]
-            pool: ThreadPool::new(4),
-        }
+    pub fn from_name(app_name: String, tasks: Tasks) -> Self {
+        Settings { app_name, tasks }
     }
 
     /// Gets the app name of the setting service.
@@ -72,7 +57,6 @@ impl Settings {
     }
 
     /// Serialize the given data object from user's config dir. Sends the result `Result<(), String>` as message to the given entity.
-    #[cfg(not(target_arch = "wasm32"))]
     pub fn save_async<S: Serialize + Send + Sync + 'static>(
         &self,
         key: String,
@@ -80,48 +64,21 @@ impl Settings {
         entity: Entity,
     ) {
         let app_name = self.app_name.clone();
-        let message_adapter = self.message_adapter.clone();
 
-        self.pool.execute(move || {
-            message_adapter.send_message(save(app_name.as_str(), key.as_str(), &data), entity);
-        })
+        self.tasks
+            .spawn(entity, move || save(app_name.as_str(), key.as_str(), &data));
     }
 
     /// Loads and deserialize data from user's config dir. Send the result `Result<D, String>` as message to the given entity.
-    #[cfg(not(target_arch = "wasm32"))]
     pub fn load_async<D: DeserializeOwned + Send + Sync + 'static>(
         &self,
         key: String,
         entity: Entity,
     ) {
         let app_name = self.app_name.clone();
-        let message_adapter = self.message_adapter.clone();
-
-        self.pool.execute(move || {
-            message_adapter.send_message(load::<D>(app_name.as_str(), key.as_str()), entity);
-        })
-    }
 
-    #[cfg(target_arch = "wasm32")]
-    pub fn save_async<S: Serialize + Send + Sync + 'static>(
-        &self,
-        key: String,
-        data: S,
-        entity: Entity,
-    ) {
-        self.message_adapter
-            .send_message(save(self.app_name.as_str(), key.as_str(), &data), entity);
-    }
-
-    /// Loads and deserialize data from user's config dir. Send the result `Result<D, String>` as message to the given entity.
-    #[cfg(target_arch = "wasm32")]
-    pub fn load_async<D: DeserializeOwned + Send + Sync + 'static>(
-        &self,
-        key: String,
-        entity: Entity,
-    ) {
-        self.message_adapter
-            .send_message(load::<D>(self.app_name.as_str(), key.as_str()), entity);
+        self.tasks
+            .spawn(entity, move || load::<D>(app_name.as_str(), key.as_str()));
     }
 
     /// Serialize the given data object from user's config dir.
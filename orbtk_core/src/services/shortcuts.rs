@@ -0,0 +1,565 @@
+use std::{any::Any, fmt};
+
+use dces::entity::Entity;
+
+use crate::{properties::KeyboardState, shell::Key, tree::Tree, widget_base::MessageAdapter};
+
+// Names of the keys that are not described by a single character. Aliases that are only parsed
+// come after the displayed name.
+const KEY_NAMES: [(Key, &str); 29] = [
+    (Key::Backspace, "Backspace"),
+    (Key::Delete, "Delete"),
+    (Key::Down, "Down"),
+    (Key::End, "End"),
+    (Key::Enter, "Enter"),
+    (Key::Escape, "Esc"),
+    (Key::F1, "F1"),
+    (Key::F2, "F2"),
+    (Key::F3, "F3"),
+    (Key::F4, "F4"),
+    (Key::F5, "F5"),
+    (Key::F6, "F6"),
+    (Key::F7, "F7"),
+    (Key::F8, "F8"),
+    (Key::F9, "F9"),
+    (Key::F10, "F10"),
+    (Key::F11, "F11"),
+    (Key::F12, "F12"),
+    (Key::Home, "Home"),
+    (Key::Left, "Left"),
+    (Key::NumpadAdd, "Plus"),
+    (Key::NumpadSubtract, "Minus"),
+    (Key::PageDown, "PageDown"),
+    (Key::PageUp, "PageUp"),
+    (Key::Right, "Right"),
+    (Key::Space, "Space"),
+    (Key::Tab, "Tab"),
+    (Key::Up, "Up"),
+    (Key::Escape, "Escape"),
+];
+
+/// Describes a key combination with modifiers, e.g. `Ctrl+Shift+Z`. Letters are matched
+/// regardless of the case, the shift modifier is described by `shift`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Shortcut {
+    key: Key,
+    ctrl: bool,
+    shift: bool,
+    alt: bool,
+}
+
+impl Shortcut {
+    /// Creates a new shortcut of the given key without modifiers.
+    pub fn new(key: Key) -> Self {
+        Shortcut {
+            key: normalize(key),
+            ctrl: false,
+            shift: false,
+            alt: false,
+        }
+    }
+
+    /// Adds the control modifier to the shortcut.
+    pub fn ctrl(mut self) -> Self {
+        self.ctrl = true;
+        self
+    }
+
+    /// Adds the shift modifier to the shortcut.
+    pub fn shift(mut self) -> Self {
+        self.shift = true;
+        self
+    }
+
+    /// Adds the alt modifier to the shortcut.
+    pub fn alt(mut self) -> Self {
+        self.alt = true;
+        self
+    }
+
+    /// Returns the key of the shortcut.
+    pub fn key(&self) -> Key {
+        self.key
+    }
+
+    /// Returns `true` if the shortcut needs the control key.
+    pub fn is_ctrl(&self) -> bool {
+        self.ctrl
+    }
+
+    /// Returns `true` if the shortcut needs the shift key.
+    pub fn is_shift(&self) -> bool {
+        self.shift
+    }
+
+    /// Returns `true` if the shortcut needs the alt key.
+    pub fn is_alt(&self) -> bool {
+        self.alt
+    }
+
+    /// Creates the shortcut of a pressed `key` with the modifiers of the `keyboard_state`.
+    pub fn from_key(key: Key, keyboard_state: &KeyboardState) -> Self {
+        Shortcut {
+            key: normalize(key),
+            ctrl: keyboard_state.is_ctrl_down(),
+            shift: keyboard_state.is_shift_down(),
+            alt: keyboard_state.is_alt_down(),
+        }
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.ctrl {
+            write!(f, "Ctrl+")?;
+        }
+        if self.shift {
+            write!(f, "Shift+")?;
+        }
+        if self.alt {
+            write!(f, "Alt+")?;
+        }
+
+        if let Some((_, name)) = KEY_NAMES.iter().find(|(key, _)| *key == self.key) {
+            return write!(f, "{}", name);
+        }
+
+        match <&'static str>::from(self.key) {
+            "" => write!(f, "{:?}", self.key),
+            name => write!(f, "{}", name.to_uppercase()),
+        }
+    }
+}
+
+impl From<&str> for Shortcut {
+    fn from(s: &str) -> Self {
+        let mut parts: Vec<&str> = s.split('+').map(|part| part.trim()).collect();
+        let mut shortcut = Shortcut::new(parse_key(parts.pop().unwrap_or_default()));
+
+        for modifier in parts {
+            match modifier.to_lowercase().as_str() {
+                "ctrl" | "control" => shortcut.ctrl = true,
+                "shift" => shortcut.shift = true,
+                "alt" => shortcut.alt = true,
+                _ => shortcut.key = Key::Unknown,
+            }
+        }
+
+        shortcut
+    }
+}
+
+impl From<String> for Shortcut {
+    fn from(s: String) -> Self {
+        Self::from(&s[..])
+    }
+}
+
+impl From<Key> for Shortcut {
+    fn from(key: Key) -> Self {
+        Shortcut::new(key)
+    }
+}
+
+/// Describes where a shortcut is active.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ShortcutScope {
+    /// The shortcut is active in the whole window.
+    Window,
+
+    /// The shortcut is active if the focused widget is the given widget or one of its children.
+    Widget(Entity),
+}
+
+// Sends the message of a binding to its target.
+type SendMessage = Box<dyn Fn(&MessageAdapter, Entity)>;
+
+// Describes what happens if the shortcut of a binding is pressed.
+enum ShortcutAction {
+    // Sends a message to the target widget.
+    Message(Entity, SendMessage),
+
+    // Executes the command with the given key of the `Commands` service.
+    Command(String),
+}
+
+/// A shortcut registered in the `Shortcuts` service.
+pub struct ShortcutBinding {
+    shortcut: Shortcut,
+    scope: ShortcutScope,
+    label: String,
+    action: ShortcutAction,
+}
+
+impl ShortcutBinding {
+    /// Returns the key combination of the binding.
+    pub fn shortcut(&self) -> Shortcut {
+        self.shortcut
+    }
+
+    /// Returns the scope of the binding.
+    pub fn scope(&self) -> ShortcutScope {
+        self.scope
+    }
+
+    /// Returns the label of the command, e.g. to display it in a menu.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the widget that receives the message of the binding. Bindings of a command don't
+    /// have a target.
+    pub fn target(&self) -> Option<Entity> {
+        match self.action {
+            ShortcutAction::Message(target, _) => Some(target),
+            ShortcutAction::Command(_) => None,
+        }
+    }
+
+    /// Returns the key of the command that is executed by the binding.
+    pub fn command(&self) -> Option<&str> {
+        match &self.action {
+            ShortcutAction::Message(..) => None,
+            ShortcutAction::Command(command) => Some(command),
+        }
+    }
+}
+
+impl fmt::Debug for ShortcutBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShortcutBinding")
+            .field("shortcut", &self.shortcut)
+            .field("scope", &self.scope)
+            .field("label", &self.label)
+            .field("target", &self.target())
+            .field("command", &self.command())
+            .finish()
+    }
+}
+
+/// The `Shortcuts` service sends messages to widgets if a key combination is pressed. Shortcuts
+/// are handled before the focused widget gets the key, so they also work if a `TextBox` has the
+/// focus.
+///
+/// If the same key combination is registered for several scopes, the binding of the innermost
+/// widget that contains the focused widget wins, bindings of the window scope come last.
+///
+/// # Examples
+/// ```
+/// impl State for MyState {
+///     fn init(&mut self, registry: &mut Registry, ctx: &mut Context) {
+///         registry.get_mut::<Shortcuts>("shortcuts").register(
+///             ShortcutScope::Window,
+///             "Ctrl+S",
+///             "Save",
+///             ctx.entity(),
+///             Action::Save,
+///         );
+///     }
+///
+///     fn messages(&mut self, mut messages: MessageReader, _: &mut Registry, ctx: &mut Context) {
+///         for action in messages.read::<Action>() {
+///             // ...
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Shortcuts {
+    message_adapter: MessageAdapter,
+    bindings: Vec<ShortcutBinding>,
+}
+
+impl Shortcuts {
+    /// Creates a new `Shortcuts` service that sends the messages with the given message adapter.
+    pub fn new(message_adapter: MessageAdapter) -> Self {
+        Shortcuts {
+            message_adapter,
+            bindings: vec![],
+        }
+    }
+
+    /// Sends `message` to the widget `target` if the `shortcut` is pressed inside of the `scope`.
+    /// A binding of the same shortcut and scope is replaced, its label is returned.
+    pub fn register<M: Any + Send + Clone>(
+        &mut self,
+        scope: ShortcutScope,
+        shortcut: impl Into<Shortcut>,
+        label: impl Into<String>,
+        target: Entity,
+        message: M,
+    ) -> Option<String> {
+        let shortcut = shortcut.into();
+        let replaced = self.unregister(scope, shortcut);
+
+        self.bindings.push(ShortcutBinding {
+            shortcut,
+            scope,
+            label: label.into(),
+            action: ShortcutAction::Message(
+                target,
+                Box::new(move |message_adapter, target| {
+                    message_adapter.send_message(message.clone(), target);
+                }),
+            ),
+        });
+
+        replaced
+    }
+
+    /// Executes the command with the key `command` of the `Commands` service if the `shortcut`
+    /// is pressed inside of the `scope`. The key of the command is used as label of the binding.
+    /// A binding of the same shortcut and scope is replaced, its label is returned.
+    pub fn register_command(
+        &mut self,
+        scope: ShortcutScope,
+        shortcut: impl Into<Shortcut>,
+        command: impl Into<String>,
+    ) -> Option<String> {
+        let shortcut = shortcut.into();
+        let replaced = self.unregister(scope, shortcut);
+        let command = command.into();
+
+        self.bindings.push(ShortcutBinding {
+            shortcut,
+            scope,
+            label: command.clone(),
+            action: ShortcutAction::Command(command),
+        });
+
+        replaced
+    }
+
+    /// Removes the binding of the shortcut in the given scope and returns its label.
+    pub fn unregister(
+        &mut self,
+        scope: ShortcutScope,
+        shortcut: impl Into<Shortcut>,
+    ) -> Option<String> {
+        let shortcut = shortcut.into();
+
+        self.bindings
+            .iter()
+            .position(|binding| binding.scope == scope && binding.shortcut == shortcut)
+            .map(|index| self.bindings.remove(index).label)
+    }
+
+    /// Removes all bindings that are scoped to or sent to the given widget.
+    pub fn remove_entity(&mut self, entity: Entity) {
+        self.bindings.retain(|binding| {
+            binding.target() != Some(entity) && binding.scope != ShortcutScope::Widget(entity)
+        });
+    }
+
+    /// Returns all registered bindings.
+    pub fn bindings(&self) -> &[ShortcutBinding] {
+        &self.bindings
+    }
+
+    /// Returns the shortcut of the command with the given label, e.g. to display it in a menu.
+    /// Bindings of the window scope are preferred.
+    pub fn shortcut(&self, label: &str) -> Option<Shortcut> {
+        self.bindings
+            .iter()
+            .filter(|binding| binding.label == label)
+            .min_by_key(|binding| binding.scope != ShortcutScope::Window)
+            .map(|binding| binding.shortcut)
+    }
+
+    /// Returns the number of registered bindings.
+    pub fn len(&self) -> usize {
+        self.bindings.len()
+    }
+
+    /// Returns `true` if no shortcut is registered.
+    pub fn is_empty(&self) -> bool {
+        self.bindings.is_empty()
+    }
+
+    /// Sends the message of the binding that matches the pressed `key` and the modifiers of the
+    /// `keyboard_state`. The scopes are resolved from the `focused` widget up to the window.
+    /// Returns the matching binding, the command of a command binding is executed by the caller.
+    pub fn dispatch(
+        &self,
+        key: Key,
+        keyboard_state: &KeyboardState,
+        focused: Option<Entity>,
+        tree: &Tree,
+    ) -> Option<&ShortcutBinding> {
+        if key == Key::Unknown {
+            return None;
+        }
+
+        let shortcut = Shortcut::from_key(key, keyboard_state);
+        let candidates: Vec<&ShortcutBinding> = self
+            .bindings
+            .iter()
+            .filter(|binding| binding.shortcut == shortcut)
+            .collect();
+
+        if candidates.is_empty() {
+            return None;
+        }
+
+        let mut current = focused;
+        let mut binding = None;
+
+        while let Some(entity) = current {
+            binding = candidates
+                .iter()
+                .find(|binding| binding.scope == ShortcutScope::Widget(entity));
+
+            if binding.is_some() {
+                break;
+            }
+
+            current = tree.parent.get(&entity).cloned().flatten();
+        }
+
+        let binding = binding.or_else(|| {
+            candidates
+                .iter()
+                .find(|binding| binding.scope == ShortcutScope::Window)
+        })?;
+
+        if let ShortcutAction::Message(target, send) = &binding.action {
+            send(&self.message_adapter, *target);
+        }
+
+        Some(binding)
+    }
+}
+
+// -- Helpers --
+
+// Removes the case of letters, the shift key is a modifier of the shortcut.
+fn normalize(key: Key) -> Key {
+    let name: &str = key.into();
+
+    match name.chars().next() {
+        Some(c) if name.len() == 1 && c.is_ascii_uppercase() => Key::from(c.to_ascii_lowercase()),
+        _ => key,
+    }
+}
+
+fn parse_key(name: &str) -> Key {
+    if let Some((key, _)) = KEY_NAMES
+        .iter()
+        .find(|(_, key_name)| key_name.eq_ignore_ascii_case(name))
+    {
+        return *key;
+    }
+
+    let mut chars = name.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Key::from(c.to_ascii_lowercase()),
+        _ => Key::Unknown,
+    }
+}
+
+// -- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+
+    use super::*;
+
+    #[derive(Clone)]
+    struct Save;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            Shortcut::from("Ctrl+Shift+Z"),
+            Shortcut::new(Key::Z(true)).ctrl().shift()
+        );
+        assert_eq!(Shortcut::from("alt + f4"), Shortcut::new(Key::F4).alt());
+        assert_eq!(Shortcut::from("Ctrl+S").to_string(), "Ctrl+S");
+        assert_eq!(Shortcut::from("Shift+Escape").to_string(), "Shift+Esc");
+        assert_eq!(Shortcut::from("Hyper+S").key(), Key::Unknown);
+    }
+
+    #[test]
+    fn test_dispatch() {
+        let (sender, _receiver) = mpsc::channel();
+        let message_adapter = MessageAdapter::new(sender);
+        let mut shortcuts = Shortcuts::new(message_adapter.clone());
+
+        let mut tree = Tree::new();
+        tree.register_node(0);
+        tree.register_node(1);
+        tree.register_node(2);
+        tree.append_child(0, 1).unwrap();
+        tree.append_child(1, 2).unwrap();
+
+        shortcuts.register(ShortcutScope::Window, "Ctrl+S", "Save", 0.into(), Save);
+        assert_eq!(
+            shortcuts.register(
+                ShortcutScope::Widget(1.into()),
+                "Ctrl+S",
+                "Save",
+                1.into(),
+                Save
+            ),
+            None
+        );
+        assert_eq!(
+            shortcuts.register(
+                ShortcutScope::Widget(1.into()),
+                "Ctrl+S",
+                "Save all",
+                1.into(),
+                Save
+            ),
+            Some(String::from("Save"))
+        );
+        assert_eq!(shortcuts.len(), 2);
+        assert_eq!(shortcuts.shortcut("Save"), Some(Shortcut::from("Ctrl+S")));
+
+        let mut keyboard_state = KeyboardState::default();
+        assert!(shortcuts
+            .dispatch(Key::S(false), &keyboard_state, None, &tree)
+            .is_none());
+
+        keyboard_state.set_key_state(Key::Control, true);
+        assert!(shortcuts
+            .dispatch(Key::S(false), &keyboard_state, Some(2.into()), &tree)
+            .is_some());
+        assert!(message_adapter
+            .message_reader(1.into())
+            .contains_type::<Save>());
+
+        assert!(shortcuts
+            .dispatch(Key::S(false), &keyboard_state, None, &tree)
+            .is_some());
+        assert!(message_adapter
+            .message_reader(0.into())
+            .contains_type::<Save>());
+
+        shortcuts.remove_entity(1.into());
+        assert_eq!(shortcuts.len(), 1);
+    }
+
+    #[test]
+    fn test_dispatch_command() {
+        let (sender, _receiver) = mpsc::channel();
+        let mut shortcuts = Shortcuts::new(MessageAdapter::new(sender));
+
+        let mut tree = Tree::new();
+        tree.register_node(0);
+
+        shortcuts.register_command(ShortcutScope::Window, "Ctrl+S", "save");
+        assert_eq!(shortcuts.shortcut("save"), Some(Shortcut::from("Ctrl+S")));
+
+        let mut keyboard_state = KeyboardState::default();
+        keyboard_state.set_key_state(Key::Control, true);
+
+        let binding = shortcuts
+            .dispatch(Key::S(false), &keyboard_state, None, &tree)
+            .unwrap();
+        assert_eq!(binding.command(), Some("save"));
+        assert_eq!(binding.target(), None);
+    }
+}
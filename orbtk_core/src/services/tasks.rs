@@ -0,0 +1,364 @@
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    task,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::{
+    task::{Wake, Waker},
+    thread,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use threadpool::*;
+
+#[cfg(target_arch = "wasm32")]
+use std::{
+    sync::Mutex,
+    task::{Wake, Waker},
+};
+
+use dces::entity::Entity;
+
+use crate::widget_base::MessageAdapter;
+
+/// Identifies a task of the `Tasks` service. It is used to cancel the task.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TaskHandle(u64);
+
+// Shared state of a running task.
+#[derive(Debug, Default)]
+struct TaskState {
+    cancelled: AtomicBool,
+    finished: AtomicBool,
+}
+
+#[derive(Debug)]
+struct TaskEntry {
+    handle: TaskHandle,
+    entity: Entity,
+    state: Arc<TaskState>,
+}
+
+/// The `Tasks` service runs closures and futures in the background and sends their results as
+/// message to a widget. If the widget is removed, its tasks are cancelled and their results are
+/// dropped. Clones of the service share their tasks and threads, e.g. the `Settings` service runs
+/// on a clone of the `Tasks` of its window.
+///
+/// # Examples
+/// ```
+/// impl State for MyState {
+///     fn init(&mut self, registry: &mut Registry, ctx: &mut Context) {
+///         registry
+///             .get_mut::<Tasks>("tasks")
+///             .spawn(ctx.entity(), || load_items("items.ron"));
+///     }
+///
+///     fn messages(&mut self, mut messages: MessageReader, _: &mut Registry, ctx: &mut Context) {
+///         for items in messages.read::<Vec<Item>>() {
+///             // ...
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Tasks {
+    message_adapter: MessageAdapter,
+    entries: Rc<RefCell<Vec<TaskEntry>>>,
+    next_handle: Rc<Cell<u64>>,
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pool: ThreadPool,
+}
+
+impl Tasks {
+    /// Creates a new `Tasks` service that sends the results with the given message adapter.
+    pub fn new(message_adapter: MessageAdapter) -> Self {
+        Tasks {
+            message_adapter,
+            entries: Rc::new(RefCell::new(vec![])),
+            next_handle: Rc::new(Cell::new(0)),
+
+            #[cfg(not(target_arch = "wasm32"))]
+            pool: ThreadPool::new(4),
+        }
+    }
+
+    /// Runs the closure in the background and sends its result as message to the widget
+    /// `entity`.
+    pub fn spawn<F, R>(&self, entity: Entity, f: F) -> TaskHandle
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Any + Send,
+    {
+        let (handle, state) = self.add(entity);
+        let message_adapter = self.message_adapter.clone();
+
+        self.execute(move || {
+            if !state.cancelled.load(Ordering::Acquire) {
+                let result = f();
+                send_result(&message_adapter, &state, result, entity);
+            }
+
+            state.finished.store(true, Ordering::Release);
+        });
+
+        handle
+    }
+
+    /// Runs the future in the background and sends its output as message to the widget
+    /// `entity`.
+    pub fn spawn_future<F>(&self, entity: Entity, future: F) -> TaskHandle
+    where
+        F: Future + Send + 'static,
+        F::Output: Any + Send,
+    {
+        let (handle, state) = self.add(entity);
+        let message_adapter = self.message_adapter.clone();
+
+        let future = Box::pin(async move {
+            if !state.cancelled.load(Ordering::Acquire) {
+                let result = future.await;
+                send_result(&message_adapter, &state, result, entity);
+            }
+
+            state.finished.store(true, Ordering::Release);
+        });
+
+        self.execute_future(future);
+
+        handle
+    }
+
+    /// Cancels the task of the given handle. The result of the task is dropped. Returns `false`
+    /// if the task is already finished or cancelled.
+    pub fn cancel(&mut self, handle: TaskHandle) -> bool {
+        self.remove_finished();
+
+        let mut entries = self.entries.borrow_mut();
+
+        if let Some(index) = entries.iter().position(|entry| entry.handle == handle) {
+            let entry = entries.remove(index);
+            entry.state.cancelled.store(true, Ordering::Release);
+            return true;
+        }
+
+        false
+    }
+
+    /// Cancels all tasks of the given widget.
+    pub fn cancel_entity(&mut self, entity: Entity) {
+        let mut entries = self.entries.borrow_mut();
+
+        for entry in entries.iter().filter(|entry| entry.entity == entity) {
+            entry.state.cancelled.store(true, Ordering::Release);
+        }
+
+        entries.retain(|entry| entry.entity != entity);
+    }
+
+    /// Cancels all tasks.
+    pub fn cancel_all(&mut self) {
+        let mut entries = self.entries.borrow_mut();
+
+        for entry in entries.iter() {
+            entry.state.cancelled.store(true, Ordering::Release);
+        }
+
+        entries.clear();
+    }
+
+    /// Returns `true` if the task of the given handle is still running.
+    pub fn is_running(&self, handle: TaskHandle) -> bool {
+        self.entries
+            .borrow()
+            .iter()
+            .any(|entry| entry.handle == handle && !entry.state.finished.load(Ordering::Acquire))
+    }
+
+    /// Returns the number of running tasks.
+    pub fn len(&self) -> usize {
+        self.entries
+            .borrow()
+            .iter()
+            .filter(|entry| !entry.state.finished.load(Ordering::Acquire))
+            .count()
+    }
+
+    /// Returns `true` if no task is running.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn add(&self, entity: Entity) -> (TaskHandle, Arc<TaskState>) {
+        self.remove_finished();
+
+        let handle = TaskHandle(self.next_handle.get());
+        self.next_handle.set(handle.0 + 1);
+
+        let state = Arc::new(TaskState::default());
+
+        self.entries.borrow_mut().push(TaskEntry {
+            handle,
+            entity,
+            state: state.clone(),
+        });
+
+        (handle, state)
+    }
+
+    fn remove_finished(&self) {
+        self.entries
+            .borrow_mut()
+            .retain(|entry| !entry.state.finished.load(Ordering::Acquire));
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn execute(&self, f: impl FnOnce() + Send + 'static) {
+        self.pool.execute(f);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn execute_future(&self, future: Pin<Box<dyn Future<Output = ()> + Send>>) {
+        self.pool.execute(move || block_on(future));
+    }
+
+    // There are no threads on the web, the closure runs after the current frame.
+    #[cfg(target_arch = "wasm32")]
+    fn execute(&self, f: impl FnOnce() + Send + 'static) {
+        stdweb::web::set_timeout(f, 0);
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn execute_future(&self, future: Pin<Box<dyn Future<Output = ()> + Send>>) {
+        Arc::new(WebTask {
+            future: Mutex::new(Some(future)),
+        })
+        .wake();
+    }
+}
+
+// -- Helpers --
+
+// Sends the result of the task if it is not cancelled.
+fn send_result<R: Any + Send>(
+    message_adapter: &MessageAdapter,
+    state: &TaskState,
+    result: R,
+    entity: Entity,
+) {
+    if !state.cancelled.load(Ordering::Acquire) {
+        message_adapter.send_message(result, entity);
+    }
+}
+
+// Wakes the thread that polls the future.
+#[cfg(not(target_arch = "wasm32"))]
+struct ThreadWaker(thread::Thread);
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+// Polls the future on the current thread until it is ready.
+#[cfg(not(target_arch = "wasm32"))]
+fn block_on(mut future: Pin<Box<dyn Future<Output = ()> + Send>>) {
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut context = task::Context::from_waker(&waker);
+
+    while future.as_mut().poll(&mut context).is_pending() {
+        thread::park();
+    }
+}
+
+// A future that is polled by the browser event loop. Each wake schedules the next poll.
+#[cfg(target_arch = "wasm32")]
+struct WebTask {
+    future: Mutex<Option<Pin<Box<dyn Future<Output = ()> + Send>>>>,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Wake for WebTask {
+    fn wake(self: Arc<Self>) {
+        stdweb::web::set_timeout(move || poll_web_task(self), 0);
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn poll_web_task(web_task: Arc<WebTask>) {
+    let waker = Waker::from(web_task.clone());
+    let mut slot = web_task.future.lock().unwrap();
+
+    if let Some(mut future) = slot.take() {
+        if future
+            .as_mut()
+            .poll(&mut task::Context::from_waker(&waker))
+            .is_pending()
+        {
+            *slot = Some(future);
+        }
+    }
+}
+
+// -- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+
+    use super::*;
+
+    #[test]
+    fn test_spawn() {
+        let (sender, _receiver) = mpsc::channel();
+        let message_adapter = MessageAdapter::new(sender);
+        let tasks = Tasks::new(message_adapter.clone());
+
+        tasks.spawn(1.into(), || 42);
+        tasks.spawn_future(2.into(), async { String::from("done") });
+        tasks.pool.join();
+
+        assert_eq!(message_adapter.len(), 2);
+        assert_eq!(
+            message_adapter
+                .message_reader(1.into())
+                .read::<i32>()
+                .next(),
+            Some(42)
+        );
+        assert!(tasks.is_empty());
+    }
+
+    #[test]
+    fn test_cancel_entity() {
+        let (sender, _receiver) = mpsc::channel();
+        let message_adapter = MessageAdapter::new(sender);
+        let mut tasks = Tasks::new(message_adapter.clone());
+
+        // the task is spawned by a clone that shares the tasks
+        let (start_sender, start_receiver) = mpsc::channel::<()>();
+        let handle = tasks.clone().spawn(1.into(), move || {
+            start_receiver.recv().unwrap();
+            42
+        });
+        assert!(tasks.is_running(handle));
+
+        tasks.cancel_entity(1.into());
+        assert!(!tasks.is_running(handle));
+        start_sender.send(()).unwrap();
+
+        tasks.pool.join();
+        assert!(message_adapter.is_empty());
+    }
+}
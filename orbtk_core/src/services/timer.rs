@@ -0,0 +1,323 @@
+use std::{any::Any, sync::mpsc, time::Duration};
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::{sync::mpsc::RecvTimeoutError, thread, time::Instant};
+
+use dces::entity::Entity;
+
+use crate::{animation::FrameClock, shell::WindowRequest, widget_base::MessageAdapter};
+
+/// Identifies a timeout or an interval of the `Timer`. It is used to cancel it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TimerHandle(u64);
+
+// Sends the message of a timer to its widget.
+type SendMessage = Box<dyn FnMut(&MessageAdapter, Entity)>;
+
+struct TimerEntry {
+    handle: TimerHandle,
+    entity: Entity,
+    deadline: Duration,
+    interval: Option<Duration>,
+    send: SendMessage,
+}
+
+/// The `Timer` service sends messages to widgets after a timeout or repeatedly in an interval.
+/// The window is woken up at the deadline of each timer, it is not necessary to redraw the
+/// window in the meantime.
+///
+/// # Examples
+/// ```
+/// impl State for MyState {
+///     fn init(&mut self, registry: &mut Registry, ctx: &mut Context) {
+///         self.blink = Some(registry.get_mut::<Timer>("timer").set_interval(
+///             ctx.entity(),
+///             Duration::from_millis(500),
+///             CursorAction::Blink,
+///         ));
+///     }
+///
+///     fn cleanup(&mut self, registry: &mut Registry, _: &mut Context) {
+///         if let Some(blink) = self.blink {
+///             registry.get_mut::<Timer>("timer").cancel(blink);
+///         }
+///     }
+/// }
+/// ```
+pub struct Timer {
+    message_adapter: MessageAdapter,
+    window_sender: mpsc::Sender<WindowRequest>,
+    clock: FrameClock,
+    entries: Vec<TimerEntry>,
+    next_handle: u64,
+
+    #[cfg(not(target_arch = "wasm32"))]
+    waker: Option<mpsc::Sender<Instant>>,
+}
+
+impl Timer {
+    /// Creates a new timer that sends its messages with the given message adapter.
+    pub fn new(
+        message_adapter: MessageAdapter,
+        window_sender: mpsc::Sender<WindowRequest>,
+    ) -> Self {
+        Timer {
+            message_adapter,
+            window_sender,
+            clock: FrameClock::new(),
+            entries: vec![],
+            next_handle: 0,
+
+            #[cfg(not(target_arch = "wasm32"))]
+            waker: None,
+        }
+    }
+
+    /// Sends `message` to the widget `entity` once after `timeout`.
+    pub fn set_timeout<M: Any + Send>(
+        &mut self,
+        entity: Entity,
+        timeout: Duration,
+        message: M,
+    ) -> TimerHandle {
+        let mut message = Some(message);
+
+        self.add(
+            entity,
+            timeout,
+            None,
+            Box::new(move |message_adapter, entity| {
+                if let Some(message) = message.take() {
+                    message_adapter.send_message(message, entity);
+                }
+            }),
+        )
+    }
+
+    /// Sends `message` to the widget `entity` repeatedly after each `interval` until the
+    /// interval is cancelled.
+    pub fn set_interval<M: Any + Send + Clone>(
+        &mut self,
+        entity: Entity,
+        interval: Duration,
+        message: M,
+    ) -> TimerHandle {
+        self.add(
+            entity,
+            interval,
+            Some(interval),
+            Box::new(move |message_adapter, entity| {
+                message_adapter.send_message(message.clone(), entity);
+            }),
+        )
+    }
+
+    /// Cancels the timeout or interval of the given handle. Returns `false` if it is already
+    /// finished or cancelled.
+    pub fn cancel(&mut self, handle: TimerHandle) -> bool {
+        let len = self.entries.len();
+        self.entries.retain(|entry| entry.handle != handle);
+        len != self.entries.len()
+    }
+
+    /// Cancels all timeouts and intervals of the given widget.
+    pub fn cancel_entity(&mut self, entity: Entity) {
+        self.entries.retain(|entry| entry.entity != entity);
+    }
+
+    /// Returns `true` if the timeout or interval of the given handle is pending.
+    pub fn is_active(&self, handle: TimerHandle) -> bool {
+        self.entries.iter().any(|entry| entry.handle == handle)
+    }
+
+    /// Returns the number of pending timeouts and intervals.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if there are no pending timeouts and intervals.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Wakes up the window after `delay` without sending a message, e.g. to draw the next frame
+    /// of running animations.
+    pub fn request_redraw(&mut self, delay: Duration) {
+        self.wake_after(delay);
+    }
+
+    /// Sends the messages of all timeouts and intervals whose deadline is reached. It is called
+    /// by the window on each update.
+    pub fn run(&mut self) {
+        let now = self.clock.now();
+        self.run_until(now);
+    }
+
+    // Sends the messages of all timers with a deadline before `now`.
+    fn run_until(&mut self, now: Duration) {
+        let mut i = 0;
+
+        while i < self.entries.len() {
+            if self.entries[i].deadline > now {
+                i += 1;
+                continue;
+            }
+
+            let entry = &mut self.entries[i];
+            (entry.send)(&self.message_adapter, entry.entity);
+
+            if let Some(interval) = entry.interval {
+                entry.deadline += interval;
+
+                // skips missed intervals instead of sending them all at once
+                if entry.deadline <= now {
+                    entry.deadline = now + interval;
+                }
+
+                let delay = entry.deadline - now;
+                self.wake_after(delay);
+                i += 1;
+            } else {
+                self.entries.remove(i);
+            }
+        }
+    }
+
+    fn add(
+        &mut self,
+        entity: Entity,
+        delay: Duration,
+        interval: Option<Duration>,
+        send: SendMessage,
+    ) -> TimerHandle {
+        let handle = TimerHandle(self.next_handle);
+        self.next_handle += 1;
+
+        self.entries.push(TimerEntry {
+            handle,
+            entity,
+            deadline: self.clock.now() + delay,
+            interval,
+            send,
+        });
+
+        self.wake_after(delay);
+
+        handle
+    }
+
+    // Requests a redraw of the window after `delay` to run the timers. A background thread
+    // waits for the deadlines.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn wake_after(&mut self, delay: Duration) {
+        let deadline = Instant::now() + delay;
+
+        if let Some(waker) = &self.waker {
+            if waker.send(deadline).is_ok() {
+                return;
+            }
+        }
+
+        let (sender, receiver) = mpsc::channel();
+        let window_sender = self.window_sender.clone();
+
+        thread::spawn(move || wait_for_deadlines(receiver, window_sender));
+
+        let _ = sender.send(deadline);
+        self.waker = Some(sender);
+    }
+
+    // Requests a redraw of the window after `delay` to run the timers.
+    #[cfg(target_arch = "wasm32")]
+    fn wake_after(&mut self, delay: Duration) {
+        let window_sender = self.window_sender.clone();
+
+        stdweb::web::set_timeout(
+            move || {
+                let _ = window_sender.send(WindowRequest::Redraw);
+            },
+            delay.as_millis() as u32,
+        );
+    }
+}
+
+// -- Helpers --
+
+// Sends a redraw request to the window at each received deadline. Stops if the timer or the
+// window is dropped.
+#[cfg(not(target_arch = "wasm32"))]
+fn wait_for_deadlines(
+    receiver: mpsc::Receiver<Instant>,
+    window_sender: mpsc::Sender<WindowRequest>,
+) {
+    let mut deadlines: Vec<Instant> = vec![];
+
+    loop {
+        let result = match deadlines.iter().min() {
+            Some(next) => receiver.recv_timeout(next.saturating_duration_since(Instant::now())),
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+
+        match result {
+            Ok(deadline) => deadlines.push(deadline),
+            Err(RecvTimeoutError::Timeout) => {
+                let now = Instant::now();
+                deadlines.retain(|deadline| *deadline > now);
+
+                if window_sender.send(WindowRequest::Redraw).is_err() {
+                    return;
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => return,
+        }
+    }
+}
+
+// -- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone)]
+    struct Tick;
+
+    // Reads the ticks that are sent to the given entity.
+    fn ticks(message_adapter: &MessageAdapter, entity: u32) -> usize {
+        message_adapter
+            .message_reader(entity.into())
+            .read::<Tick>()
+            .count()
+    }
+
+    #[test]
+    fn test_timeout_and_interval() {
+        let (sender, _receiver) = mpsc::channel();
+        let message_adapter = MessageAdapter::new(sender.clone());
+        let mut timer = Timer::new(message_adapter.clone(), sender);
+
+        let timeout = timer.set_timeout(1.into(), Duration::from_secs(10), Tick);
+        let interval = timer.set_interval(2.into(), Duration::from_secs(5), Tick);
+        assert_eq!(timer.len(), 2);
+
+        timer.run_until(Duration::from_secs(6));
+        assert_eq!(ticks(&message_adapter, 1), 0);
+        assert_eq!(ticks(&message_adapter, 2), 1);
+        assert!(timer.is_active(interval));
+
+        timer.run_until(Duration::from_secs(11));
+        assert!(!timer.is_active(timeout));
+        assert!(timer.is_active(interval));
+        assert_eq!(ticks(&message_adapter, 1), 1);
+        assert_eq!(ticks(&message_adapter, 2), 1);
+
+        // missed intervals are skipped
+        timer.run_until(Duration::from_secs(30));
+        assert_eq!(ticks(&message_adapter, 1), 0);
+        assert_eq!(ticks(&message_adapter, 2), 1);
+
+        assert!(timer.cancel(interval));
+        assert!(!timer.cancel(interval));
+        assert!(timer.is_empty());
+    }
+}
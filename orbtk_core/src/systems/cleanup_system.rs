@@ -68,5 +68,10 @@ impl System<Tree, RenderContext2D> for CleanupSystem {
 
             dirty_index += 1;
         }
+
+        // results of running tasks are dropped with the window
+        if let Some(tasks) = self.registry.borrow_mut().try_get_mut::<Tasks>("tasks") {
+            tasks.cancel_all();
+        }
     }
 }
@@ -2,7 +2,9 @@ use std::{cell::RefCell, rc::Rc};
 
 use dces::prelude::*;
 
-use crate::{prelude::*, render::RenderContext2D, theming::Theme, tree::Tree, utils::*};
+use crate::{
+    prelude::*, render::RenderContext2D, shell::Key, theming::Theme, tree::Tree, utils::*,
+};
 
 /// The `EventStateSystem` pops events from the event queue and delegates the events to the corresponding event handlers of the widgets and updates the states.
 #[derive(Constructor)]
@@ -10,6 +12,7 @@ pub struct EventStateSystem {
     context_provider: ContextProvider,
     registry: Rc<RefCell<Registry>>,
     hovered_widgets: RefCell<Vec<Entity>>,
+    pending_commands: RefCell<Vec<String>>,
 }
 
 impl EventStateSystem {
@@ -68,7 +71,8 @@ impl EventStateSystem {
                     ecm,
                     &theme,
                     Some(&self.context_provider.event_adapter),
-                ),
+                )
+                .with_animations(&self.context_provider.animations),
             );
         }
     }
@@ -95,6 +99,22 @@ impl EventStateSystem {
                 state.cleanup(registry, &mut ctx);
             }
 
+            if let Some(timer) = registry.try_get_mut::<Timer>("timer") {
+                timer.cancel_entity(entity);
+            }
+
+            if let Some(tasks) = registry.try_get_mut::<Tasks>("tasks") {
+                tasks.cancel_entity(entity);
+            }
+
+            if let Some(shortcuts) = registry.try_get_mut::<Shortcuts>("shortcuts") {
+                shortcuts.remove_entity(entity);
+            }
+
+            if let Some(commands) = registry.try_get_mut::<Commands>("commands") {
+                commands.remove_entity(entity);
+            }
+
             drop(ctx);
         }
         self.context_provider.states.borrow_mut().remove(&entity);
@@ -111,6 +131,100 @@ impl EventStateSystem {
             .remove(&entity);
     }
 
+    // Sends the message of the shortcut that matches the pressed key. The command of a command
+    // binding is executed after the messages are handled.
+    fn dispatch_shortcut(&self, key: Key, ecm: &EntityComponentManager<Tree>) -> bool {
+        let root = ecm.entity_store().root();
+
+        let keyboard_state = match ecm
+            .component_store()
+            .get::<KeyboardState>("keyboard_state", root)
+        {
+            Ok(keyboard_state) => keyboard_state,
+            Err(_) => return false,
+        };
+
+        let focused = ecm
+            .component_store()
+            .get::<FocusState>("focus_state", root)
+            .ok()
+            .and_then(|focus_state| *focus_state.focused_entity());
+
+        let registry = self.registry.borrow();
+
+        let binding = match registry
+            .try_get::<Shortcuts>("shortcuts")
+            .and_then(|shortcuts| {
+                shortcuts.dispatch(key, keyboard_state, focused, ecm.entity_store())
+            }) {
+            Some(binding) => binding,
+            None => return false,
+        };
+
+        if let Some(command) = binding.command() {
+            self.pending_commands.borrow_mut().push(command.to_string());
+        }
+
+        true
+    }
+
+    // Executes the commands of the pressed shortcuts in the context of the window.
+    fn execute_commands(
+        &self,
+        theme: &Theme,
+        ecm: &mut EntityComponentManager<Tree>,
+        render_context: &mut RenderContext2D,
+    ) {
+        let pending_commands: Vec<String> = self.pending_commands.borrow_mut().drain(..).collect();
+        let root = ecm.entity_store().root();
+
+        for key in pending_commands {
+            let command = match self
+                .registry
+                .borrow()
+                .try_get::<Commands>("commands")
+                .and_then(|commands| commands.get(&key).cloned())
+            {
+                Some(command) => command,
+                None => continue,
+            };
+
+            let mut ctx = Context::new((root, ecm), theme, &self.context_provider, render_context);
+            command.execute(&mut self.registry.borrow_mut(), &mut ctx);
+        }
+    }
+
+    // Sets the `command_enabled` flag of the widgets that are bound to a command if its
+    // executable state has changed. The `enabled` property of the widgets is left to the user.
+    fn update_commands(&self, theme: &Theme, ecm: &mut EntityComponentManager<Tree>) {
+        let changes = match self
+            .registry
+            .borrow_mut()
+            .try_get_mut::<Commands>("commands")
+        {
+            Some(commands) => commands.refresh(),
+            None => return,
+        };
+
+        for (entity, executable) in changes {
+            if ecm
+                .component_store()
+                .get::<bool>("command_enabled", entity)
+                .is_err()
+            {
+                continue;
+            }
+
+            WidgetContainer::new(
+                entity,
+                ecm,
+                theme,
+                Some(&self.context_provider.event_adapter),
+            )
+            .set("command_enabled", executable);
+        }
+    }
+
     fn process_direct(&self, event: &EventBox, ecm: &mut EntityComponentManager<Tree>) -> bool {
         // skip all direct events on first run
         if self.context_provider.first_run.get() {
@@ -172,6 +286,11 @@ impl EventStateSystem {
                 // Set this value on the keyboard state
                 keyboard_state.set_key_state(event.event.key, true);
             }
+
+            // shortcuts are handled before the focused widget gets the key
+            if self.dispatch_shortcut(event.event.key, ecm) {
+                return true;
+            }
         }
 
         if let Ok(event) = event.downcast_ref::<KeyUpEvent>() {
@@ -198,7 +317,12 @@ impl EventStateSystem {
                 }
             }
             if let Ok(enabled) = ecm.component_store().get::<bool>("enabled", current_node) {
-                if !enabled {
+                let command_enabled = ecm
+                    .component_store()
+                    .get::<bool>("command_enabled", current_node)
+                    .map_or(true, |command_enabled| *command_enabled);
+
+                if !enabled || !command_enabled {
                     disabled_parents.push(current_node);
                 }
             }
@@ -341,7 +465,8 @@ impl EventStateSystem {
                                         ecm,
                                         &theme,
                                         Some(&self.context_provider.event_adapter),
-                                    ),
+                                    )
+                                    .with_animations(&self.context_provider.animations),
                                 );
 
                                 self.remove_hover(last, ecm, &theme);
@@ -360,7 +485,8 @@ impl EventStateSystem {
                                         ecm,
                                         &theme,
                                         Some(&self.context_provider.event_adapter),
-                                    ),
+                                    )
+                                    .with_animations(&self.context_provider.animations),
                                 );
 
                                 self.hovered_widgets.borrow_mut().push(current_node);
@@ -399,13 +525,13 @@ impl EventStateSystem {
                 }
 
                 if unknown_event
-                    && *WidgetContainer::new(
+                    && WidgetContainer::new(
                         current_node,
                         ecm,
                         &theme,
                         Some(&self.context_provider.event_adapter),
                     )
-                    .get::<bool>("enabled")
+                    .is_enabled()
                     && has_handler
                 {
                     matching_nodes.push(current_node);
@@ -451,6 +577,28 @@ impl EventStateSystem {
             }
         }
 
+        // moves the focus with tab if no widget has handled the key
+        if !handled {
+            if let Ok(event) = event.downcast_ref::<KeyDownEvent>() {
+                if event.event.key == Key::Tab {
+                    let reverse = ecm
+                        .component_store()
+                        .get::<KeyboardState>("keyboard_state", root)
+                        .map_or(false, |keyboard_state| keyboard_state.is_shift_down());
+
+                    self.context_provider.event_adapter.push_event_direct(
+                        root,
+                        if reverse {
+                            FocusEvent::FocusPrevious
+                        } else {
+                            FocusEvent::FocusNext
+                        },
+                    );
+                    update = true;
+                }
+            }
+        }
+
         update
     }
 }
@@ -536,6 +684,9 @@ impl System<Tree, RenderContext2D> for EventStateSystem {
                 }
             }
 
+            self.execute_commands(&theme, ecm, render_context);
+            self.update_commands(&theme, ecm);
+
             let mut remove_widget_list: Vec<Entity> = vec![];
 
             let mut dirty_index = 0;
@@ -31,6 +31,30 @@ impl PostLayoutStateSystem {
                 state.cleanup(&mut self.registry.borrow_mut(), &mut ctx);
             }
 
+            if let Some(timer) = self.registry.borrow_mut().try_get_mut::<Timer>("timer") {
+                timer.cancel_entity(entity);
+            }
+
+            if let Some(tasks) = self.registry.borrow_mut().try_get_mut::<Tasks>("tasks") {
+                tasks.cancel_entity(entity);
+            }
+
+            if let Some(shortcuts) = self
+                .registry
+                .borrow_mut()
+                .try_get_mut::<Shortcuts>("shortcuts")
+            {
+                shortcuts.remove_entity(entity);
+            }
+
+            if let Some(commands) = self
+                .registry
+                .borrow_mut()
+                .try_get_mut::<Commands>("commands")
+            {
+                commands.remove_entity(entity);
+            }
+
             drop(ctx);
         }
         self.context_provider.states.borrow_mut().remove(&entity);
@@ -107,5 +131,16 @@ impl System<Tree, RenderContext2D> for PostLayoutStateSystem {
                 }
             }
         }
+
+        // describes the updated widgets to assistive technologies
+        if let Some(accessibility) = self
+            .registry
+            .borrow_mut()
+            .try_get_mut::<Accessibility>("accessibility")
+        {
+            if accessibility.is_enabled() {
+                accessibility.update(AccessibilityTree::from_ecm(ecm));
+            }
+        }
     }
 }
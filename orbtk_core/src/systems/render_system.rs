@@ -10,6 +10,90 @@ pub struct RenderSystem {
     context_provider: ContextProvider,
 }
 
+impl RenderSystem {
+    // Returns the region of the window that must be repainted. It covers the areas the dirty
+    // widgets were drawn to in the last frame and the areas they will be drawn to now. `None`
+    // means the whole window is repainted. Cached layers of dirty widgets and their ancestors
+    // are removed.
+    fn damaged_region(
+        &self,
+        ecm: &EntityComponentManager<Tree>,
+        render_context: &RenderContext2D,
+        dirty_widgets: &[Entity],
+    ) -> Option<Rectangle> {
+        let mut damage_tracker = self.context_provider.damage_tracker.borrow_mut();
+        let mut layer_cache = self.context_provider.layer_cache.borrow_mut();
+        let root = ecm.entity_store().root();
+
+        layer_cache.set_size(Size::new(render_context.width(), render_context.height()));
+
+        if self.context_provider.first_run.get() {
+            damage_tracker.clear();
+            layer_cache.clear();
+            return None;
+        }
+
+        // widgets that are removed from the tree
+        let mut region =
+            damage_tracker.retain(|entity| ecm.entity_store().parent.contains_key(&entity));
+        if let Some(region) = &region {
+            layer_cache.remove_intersecting(region);
+        }
+
+        // layers of dirty widgets and their ancestors are drawn again
+        for widget in dirty_widgets {
+            let mut current = Some(*widget);
+            while let Some(entity) = current {
+                layer_cache.remove(entity);
+                current = ecm.entity_store().parent.get(&entity).copied().flatten();
+            }
+        }
+
+        // a resized window is repainted completely
+        let window = Rectangle::new(
+            (0.0, 0.0),
+            (render_context.width(), render_context.height()),
+        );
+        if damage_tracker.extent(root).map_or(true, |extent| {
+            !extent.contains_rect(&window)
+                || ecm
+                    .component_store()
+                    .get::<Rectangle>("bounds", root)
+                    .map_or(true, |bounds| bounds.size() != window.size())
+        }) {
+            return None;
+        }
+
+        for widget in dirty_widgets {
+            if !ecm.entity_store().parent.contains_key(widget) {
+                continue;
+            }
+
+            let position = parent_position(ecm, *widget);
+
+            if let Some(extent) = damage_tracker.extent(*widget) {
+                join_damage(&mut region, &extent);
+            }
+
+            if let Some(rect) = subtree_rect(ecm, *widget, position) {
+                join_damage(&mut region, &rect);
+
+                // ancestors must not be skipped if the widget grows out of them
+                let mut current = ecm.entity_store().parent[widget];
+                while let Some(parent) = current {
+                    if let Some(mut extent) = damage_tracker.extent(parent) {
+                        extent.join_with_rectangle(&rect);
+                        damage_tracker.set_extent(parent, extent);
+                    }
+                    current = ecm.entity_store().parent.get(&parent).copied().flatten();
+                }
+            }
+        }
+
+        Some(region.unwrap_or_default())
+    }
+}
+
 impl System<Tree, RenderContext2D> for RenderSystem {
     fn run_with_context(
         &self,
@@ -28,6 +112,8 @@ impl System<Tree, RenderContext2D> for RenderSystem {
             return;
         }
 
+        let region = self.damaged_region(ecm, render_context, &dirty_widgets);
+
         // reset the dirty flag of all dirty widgets to `false`
         for widget in dirty_widgets {
             if let Ok(dirty) = ecm.component_store_mut().get_mut::<bool>("dirty", widget) {
@@ -40,6 +126,13 @@ impl System<Tree, RenderContext2D> for RenderSystem {
             .unwrap()
             .clear();
 
+        // nothing visible has changed
+        if region.map_or(false, |region| {
+            region.width() <= 0.0 || region.height() <= 0.0
+        }) {
+            return;
+        }
+
         #[cfg(feature = "debug")]
         let debug = true;
         #[cfg(not(feature = "debug"))]
@@ -57,7 +150,15 @@ impl System<Tree, RenderContext2D> for RenderSystem {
 
         // CONSOLE.time("render");
 
-        render_context.start();
+        match region {
+            Some(region) => render_context.start_region(region),
+            None => render_context.start(),
+        }
+        self.context_provider
+            .damage_tracker
+            .borrow_mut()
+            .set_region(region);
+
         render_context.begin_path();
         self.context_provider.render_objects.borrow()[&root].render(
             render_context,
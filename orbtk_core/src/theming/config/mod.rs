@@ -1,5 +1,7 @@
 pub use self::style_config::*;
 pub use self::theme_config::*;
+pub use self::transition_config::*;
 
 mod style_config;
 mod theme_config;
+mod transition_config;
@@ -3,10 +3,10 @@ use std::collections::HashMap;
 use ron::Value;
 use serde_derive::{Deserialize, Serialize};
 
-use crate::theming::ThemeState;
+use crate::theming::{config::TransitionConfig, ThemeState};
 
 /// Defines a style. A style could be base on other styles and contains a list for properties
-/// and a list of state properties.
+/// and a list of state properties. Transitions animate properties when the state changes.
 #[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct StyleConfig {
     // set default string to base style
@@ -16,4 +16,6 @@ pub struct StyleConfig {
     pub states: Vec<ThemeState>,
     #[serde(default)]
     pub properties: HashMap<String, Value>,
+    #[serde(default)]
+    pub transitions: HashMap<String, TransitionConfig>,
 }
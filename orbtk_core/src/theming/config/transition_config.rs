@@ -0,0 +1,25 @@
+use std::time::Duration;
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{animation::Transition, utils::Easing};
+
+/// Defines the transition of a property in a style, e.g. `(duration: 150, easing: "ease-out")`.
+/// Durations are given in milliseconds.
+#[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TransitionConfig {
+    #[serde(default)]
+    pub duration: u64,
+    #[serde(default)]
+    pub delay: u64,
+    #[serde(default)]
+    pub easing: String,
+}
+
+impl From<&TransitionConfig> for Transition {
+    fn from(config: &TransitionConfig) -> Self {
+        Transition::new(Duration::from_millis(config.duration))
+            .delay(Duration::from_millis(config.delay))
+            .easing(Easing::from(&config.easing[..]))
+    }
+}
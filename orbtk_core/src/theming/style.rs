@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use super::ThemeState;
+use super::{config::TransitionConfig, ThemeState};
 
 use ron::Value;
 
@@ -12,6 +12,9 @@ pub struct Style {
 
     /// Represents the list of states.
     pub states: Vec<ThemeState>,
+
+    /// Represents the map of property transitions.
+    pub transitions: HashMap<String, TransitionConfig>,
 }
 
 impl Style {
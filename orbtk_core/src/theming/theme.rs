@@ -2,16 +2,22 @@ use std::collections::HashMap;
 
 use ron::Value;
 
-use crate::theming::{
-    config::{ThemeConfig, RESOURCE_KEY},
-    Selector, Style, ThemeState,
+use crate::{
+    animation::Transition,
+    render::{FontFace, FontSource},
+    theming::{
+        config::{ThemeConfig, RESOURCE_KEY},
+        Selector, Style, ThemeState,
+    },
+    utils::{FontStyle, FontWeight},
 };
 
 /// Theme is used to read properties for a given selector with a internal state.
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct Theme {
     styles: HashMap<String, Style>,
-    fonts: HashMap<String, &'static [u8]>,
+    fonts: Vec<(String, FontSource)>,
+    font_faces: Vec<FontFace>,
 }
 
 impl Theme {
@@ -25,21 +31,44 @@ impl Theme {
 
         Theme {
             styles,
-            fonts: HashMap::new(),
+            fonts: vec![],
+            font_faces: vec![],
         }
     }
 
-    /// Registers a new font file as binary.
-    pub fn register_font(mut self, key: &str, font: &'static [u8]) -> Self {
-        self.fonts.insert(key.to_string(), font);
+    /// Registers a new font. The font could be a binary font file, a path to a font file or the
+    /// name of a system font. If a font is registered multiple times for the same key, the
+    /// following fonts are used as fallback for chars that are missing in the first font.
+    pub fn register_font(mut self, key: &str, font: impl Into<FontSource>) -> Self {
+        self.fonts.push((key.to_string(), font.into()));
         self
     }
 
-    /// Returns the map of registered fonts.
-    pub fn fonts(&self) -> &HashMap<String, &'static [u8]> {
+    /// Returns the list of registered fonts in registration order.
+    pub fn fonts(&self) -> &[(String, FontSource)] {
         &self.fonts
     }
 
+    /// Registers the font `face` as face of `family` with the given weight and style. Widgets
+    /// with one of the faces of the family as `font` and the given `font_weight` and `font_style`
+    /// are drawn with the face.
+    pub fn register_font_face(
+        mut self,
+        family: &str,
+        weight: FontWeight,
+        style: FontStyle,
+        face: &str,
+    ) -> Self {
+        self.font_faces
+            .push(FontFace::new(family, weight, style, face));
+        self
+    }
+
+    /// Returns the list of registered font faces.
+    pub fn font_faces(&self) -> &[FontFace] {
+        &self.font_faces
+    }
+
     /// Returns a reference to the style corresponding to the key.
     pub fn style(&self, key: &str) -> Option<&Style> {
         self.styles.get(key)
@@ -77,6 +106,24 @@ impl Theme {
         None
     }
 
+    /// Returns the transition of the property `key` for the given selector. The transition of
+    /// the active state has priority over the transition of the style.
+    pub fn transition(&self, selector: &Selector, key: &str) -> Option<Transition> {
+        let style = self.styles.get(selector.style.as_ref()?)?;
+
+        // same order as properties, the last active state has highest priority
+        let state = style
+            .states
+            .iter()
+            .rev()
+            .find(|state| selector.states().contains(&state.key));
+
+        state
+            .and_then(|state| state.transitions.get(key))
+            .or_else(|| style.transitions.get(key))
+            .map(Transition::from)
+    }
+
     // reads the given config and copy it's data in the given styles map
     fn read_config(
         style_key: &str,
@@ -109,9 +156,17 @@ impl Theme {
                     );
                 }
 
+                // reads the transitions
+                for (property_key, transition) in &style_config.transitions {
+                    style
+                        .transitions
+                        .insert(property_key.clone(), transition.clone());
+                }
+
                 // reads the states
                 for state in &style_config.states {
                     let mut new_state = ThemeState::new(state.key.clone());
+                    new_state.transitions = state.transitions.clone();
 
                     for (property_key, property_value) in &state.properties {
                         new_state.properties.insert(
@@ -143,3 +198,58 @@ impl Theme {
         property_value.clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::utils::Easing;
+
+    const THEME: &str = r##"
+        Theme (
+            styles: {
+                "button": (
+                    properties: {
+                        "background": "#000000",
+                    },
+                    transitions: {
+                        "background": (duration: 150, easing: "ease-out"),
+                    },
+                    states: [
+                        (
+                            key: "pressed",
+                            properties: {
+                                "background": "#ffffff",
+                            },
+                            transitions: {
+                                "background": (duration: 50),
+                            },
+                        ),
+                    ],
+                ),
+                "toggle_button": (
+                    base: "button",
+                ),
+            },
+        )
+    "##;
+
+    #[test]
+    fn test_transition() {
+        let theme = Theme::from_config(ThemeConfig::from(THEME));
+        let mut selector = Selector::new("toggle_button");
+
+        assert_eq!(
+            theme.transition(&selector, "background"),
+            Some(Transition::new(Duration::from_millis(150)).easing(Easing::EaseOut))
+        );
+        assert_eq!(theme.transition(&selector, "foreground"), None);
+
+        selector.push_state("pressed");
+        assert_eq!(
+            theme.transition(&selector, "background"),
+            Some(Transition::new(Duration::from_millis(50)))
+        );
+    }
+}
@@ -4,13 +4,18 @@ use serde_derive::{Deserialize, Serialize};
 
 use ron::Value;
 
-/// Contains a list of properties corresponding to the state key.
+use crate::theming::config::TransitionConfig;
+
+/// Contains a list of properties corresponding to the state key. The transitions of the state
+/// are used if the state becomes active.
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct ThemeState {
     #[serde(default)]
     pub key: String,
     #[serde(default)]
     pub properties: HashMap<String, Value>,
+    #[serde(default)]
+    pub transitions: HashMap<String, TransitionConfig>,
 }
 
 impl ThemeState {
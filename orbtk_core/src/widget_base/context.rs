@@ -10,6 +10,7 @@ use crate::{
     render::RenderContext2D,
     shell::{ShellRequest, WindowRequest},
     tree::Tree,
+    utils::Lerp,
 };
 
 use super::WidgetContainer;
@@ -92,6 +93,7 @@ impl<'a> Context<'a> {
             &self.theme,
             Some(&self.provider.event_adapter),
         )
+        .with_animations(&self.provider.animations)
     }
 
     /// Returns the widget of the current state ctx.
@@ -351,6 +353,55 @@ impl<'a> Context<'a> {
 
     // -- Manipulation --
 
+    // -- Animations --
+
+    /// Animates the property `key` of the given widget. A running animation of the property is
+    /// replaced.
+    pub fn animate<P>(&mut self, entity: Entity, key: &str, animation: Animation<P>)
+    where
+        P: Lerp + Component + Clone + PartialEq,
+    {
+        self.provider
+            .animations
+            .borrow_mut()
+            .add(entity, key, animation);
+    }
+
+    /// Animates the property `key` of the given widget from its current value to `to`. If the
+    /// property is already animated to `to` the running animation is kept.
+    pub fn transition<P>(&mut self, entity: Entity, key: &str, to: P, transition: Transition)
+    where
+        P: Lerp + Component + Clone + PartialEq,
+    {
+        if let Some(animation) = self.provider.animations.borrow().get::<P>(entity, key) {
+            if animation.to == to {
+                return;
+            }
+        }
+
+        let from = self.get_widget(entity).clone::<P>(key);
+
+        if from == to {
+            self.stop_animation(entity, key);
+            return;
+        }
+
+        self.animate(entity, key, transition.animation(from, to));
+    }
+
+    /// Stops the animation of the property `key` of the given widget. The property keeps its
+    /// current value.
+    pub fn stop_animation(&mut self, entity: Entity, key: &str) {
+        self.provider.animations.borrow_mut().remove(entity, key);
+    }
+
+    /// Returns `true` if the property `key` of the given widget is animated.
+    pub fn is_animating(&self, entity: Entity, key: &str) -> bool {
+        self.provider.animations.borrow().contains(entity, key)
+    }
+
+    // -- Animations --
+
     /// Returns the entity of a child, identified by its id.
     /// If there is no matching id string, `None` will be returned.
     pub fn entity_of_child<'b>(&mut self, id: impl Into<&'b str>) -> Option<Entity> {
@@ -455,8 +506,18 @@ impl<'a> Context<'a> {
 
         *self.window().get_mut::<Rc<Theme>>("theme") = theme;
 
+        // glyphs of fonts that are no longer used should not stay in the cache
+        self.render_context.clear_glyph_cache();
+
+        // cached layers are drawn with the old theme
+        self.provider.layer_cache.borrow_mut().clear();
+
         for (key, font) in self.theme.fonts() {
-            self.render_context.register_font(key, *font);
+            self.render_context.register_font(key, font.clone());
+        }
+
+        for face in self.theme.font_faces() {
+            self.render_context.register_font_face(face.clone());
         }
 
         // update on window to update all widgets in the tree
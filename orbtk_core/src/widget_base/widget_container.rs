@@ -1,8 +1,15 @@
-use std::any::type_name;
+use std::{any::type_name, cell::RefCell};
 
 use dces::prelude::*;
 
-use crate::{event::ChangedEvent, event::*, theming::*, tree::*, utils::prelude::*};
+use crate::{
+    animation::{Animations, Transition},
+    event::ChangedEvent,
+    event::*,
+    theming::*,
+    tree::*,
+    utils::prelude::*,
+};
 
 /// Mark the widget and shared widgets as dirty.
 pub fn mark_as_dirty(key: &str, entity: Entity, ecm: &mut EntityComponentManager<Tree>) {
@@ -50,6 +57,7 @@ pub struct WidgetContainer<'a> {
     current_node: Entity,
     theme: &'a Theme,
     event_adapter: Option<&'a EventAdapter>,
+    animations: Option<&'a RefCell<Animations>>,
 }
 
 impl<'a> WidgetContainer<'a> {
@@ -65,9 +73,17 @@ impl<'a> WidgetContainer<'a> {
             current_node: root,
             theme,
             event_adapter,
+            animations: None,
         }
     }
 
+    /// Sets the animation store that is used for the transitions of the widget style. Without
+    /// the store theme properties are set without transition.
+    pub fn with_animations(mut self, animations: &'a RefCell<Animations>) -> Self {
+        self.animations = Some(animations);
+        self
+    }
+
     fn mark_as_dirty(&mut self, key: &str, entity: Entity) {
         mark_as_dirty(key, entity, self.ecm);
     }
@@ -219,13 +235,13 @@ impl<'a> WidgetContainer<'a> {
     }
 
     fn toggle_enabled_state(&mut self) {
-        if *self.get::<bool>("enabled") && self.get::<Selector>("selector").has_state("disabled") {
+        let enabled = self.is_enabled();
+
+        if enabled && self.get::<Selector>("selector").has_state("disabled") {
             self.get_mut::<Selector>("selector")
                 .remove_state("disabled");
             self.update(false);
-        } else if !*self.get::<bool>("enabled")
-            && !self.get::<Selector>("selector").has_state("disabled")
-        {
+        } else if !enabled && !self.get::<Selector>("selector").has_state("disabled") {
             self.get_mut::<Selector>("selector").push_state("disabled");
             self.update(false);
         }
@@ -306,7 +322,7 @@ impl<'a> WidgetContainer<'a> {
 
         self.set_non_dirty(key, value);
 
-        if key.eq("enabled") {
+        if key.eq("enabled") || key.eq("command_enabled") {
             self.toggle_enabled_state();
         }
     }
@@ -351,6 +367,12 @@ impl<'a> WidgetContainer<'a> {
             .is_ok()
     }
 
+    /// Returns `true` if the `enabled` property of the widget is set and the command it is bound
+    /// to, if any, can be executed.
+    pub fn is_enabled(&self) -> bool {
+        *self.get::<bool>("enabled") && *self.try_get::<bool>("command_enabled").unwrap_or(&true)
+    }
+
     /// Returns a reference of a property of type `P` from the given widget entity. If the entity does
     /// not exists or it doesn't have a component of type `P` `None` will be returned.
     pub fn try_get<P: Component>(&self, key: &str) -> Option<&P> {
@@ -421,6 +443,41 @@ impl<'a> WidgetContainer<'a> {
         }
     }
 
+    // Updates the property with a transition if the style of the widget declares one for it.
+    fn update_animated_value<T>(&mut self, key: &str, value: Value, transition: Option<Transition>)
+    where
+        T: Lerp + Component + Clone + PartialEq,
+        Value: Into<T>,
+    {
+        if !self.has::<T>(key) {
+            return;
+        }
+
+        let value: T = value.into();
+
+        if let (Some(animations), Some(transition)) = (self.animations, transition) {
+            let mut animations = animations.borrow_mut();
+
+            if animations
+                .get::<T>(self.current_node, key)
+                .map_or(false, |animation| animation.to == value)
+            {
+                return;
+            }
+
+            let from = self.clone::<T>(key);
+
+            if from != value {
+                animations.add(self.current_node, key, transition.animation(from, value));
+                return;
+            }
+
+            animations.remove(self.current_node, key);
+        }
+
+        self.update_value::<T, T>(key, value);
+    }
+
     fn update_value<T, V>(&mut self, key: &str, value: V)
     where
         T: Component + Clone,
@@ -477,19 +534,31 @@ impl<'a> WidgetContainer<'a> {
                     "width" | "height" | "min_width" | "min_height" | "max_width"
                     | "max_height" => self.update_constraint(&key, Value(value)),
                     _ => {
+                        let transition = self.theme.transition(&selector, &key);
+
                         // common mapping
                         if self.is::<Brush>(&key) {
-                            self.update_value::<Brush, Value>(&key, Value(value));
+                            self.update_animated_value::<Brush>(&key, Value(value), transition);
                         } else if self.is::<f32>(&key) {
-                            self.update_value::<f32, Value>(&key, Value(value));
+                            self.update_animated_value::<f32>(&key, Value(value), transition);
                         } else if self.is::<f64>(&key) {
-                            self.update_value::<f64, Value>(&key, Value(value));
+                            self.update_animated_value::<f64>(&key, Value(value), transition);
                         } else if self.is::<Thickness>(&key) {
-                            self.update_value::<Thickness, Value>(&key, Value(value));
+                            self.update_animated_value::<Thickness>(&key, Value(value), transition);
                         } else if self.is::<String>(&key) {
                             self.update_value::<String, Value>(&key, Value(value));
                         } else if self.is::<Alignment>(&key) {
                             self.update_value::<Alignment, Value>(&key, Value(value));
+                        } else if self.is::<BorderStyle>(&key) {
+                            self.update_value::<BorderStyle, Value>(&key, Value(value));
+                        } else if self.is::<Shadow>(&key) {
+                            self.update_value::<Shadow, Value>(&key, Value(value));
+                        } else if self.is::<TextAlignment>(&key) {
+                            self.update_value::<TextAlignment, Value>(&key, Value(value));
+                        } else if self.is::<FontWeight>(&key) {
+                            self.update_value::<FontWeight, Value>(&key, Value(value));
+                        } else if self.is::<FontStyle>(&key) {
+                            self.update_value::<FontStyle, Value>(&key, Value(value));
                         }
                     }
                 }
@@ -560,5 +629,6 @@ impl<'a> From<&'a mut super::Context<'_>> for WidgetContainer<'a> {
             &ctx.theme,
             Some(&ctx.provider.event_adapter),
         )
+        .with_animations(&ctx.provider.animations)
     }
 }
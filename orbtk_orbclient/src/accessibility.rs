@@ -0,0 +1,46 @@
+//! This module contains the bridge that exposes the accessibility tree of a window to assistive
+//! technologies. The tree is described with AccessKit, on Linux it is published over AT-SPI.
+
+pub use accesskit;
+
+#[cfg(target_os = "linux")]
+pub use self::at_spi::*;
+
+#[cfg(target_os = "linux")]
+mod at_spi {
+    use accesskit::{ActionHandler, ActionRequest, TreeUpdate};
+
+    // Actions requested by assistive technologies, e.g. to click a button, are not forwarded to
+    // the window.
+    struct IgnoreActions;
+
+    impl ActionHandler for IgnoreActions {
+        fn do_action(&self, _: ActionRequest) {}
+    }
+
+    /// The `AtSpiAdapter` publishes the accessibility tree of a window over the AT-SPI bus of the
+    /// desktop, where it is read by screen readers like Orca.
+    pub struct AtSpiAdapter {
+        adapter: accesskit_unix::Adapter,
+    }
+
+    impl AtSpiAdapter {
+        /// Creates a new adapter with the initial tree of the window. Returns `None` if the AT-SPI
+        /// bus is not available.
+        pub fn new(app_name: impl Into<String>, initial_state: TreeUpdate) -> Option<Self> {
+            accesskit_unix::Adapter::new(
+                app_name.into(),
+                String::from("OrbTk"),
+                String::from(env!("CARGO_PKG_VERSION")),
+                move || initial_state,
+                Box::new(IgnoreActions),
+            )
+            .map(|adapter| AtSpiAdapter { adapter })
+        }
+
+        /// Publishes the changes of the tree.
+        pub fn update(&self, update: TreeUpdate) {
+            self.adapter.update(update);
+        }
+    }
+}
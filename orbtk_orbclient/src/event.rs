@@ -47,9 +47,22 @@ pub enum Key {
     Down,
     Dot,
     Eight,
+    End,
     Enter,
     Escape,
     ExclamationMark,
+    F1,
+    F2,
+    F3,
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    F10,
+    F11,
+    F12,
     Five,
     Four,
     Hash,
@@ -77,6 +90,8 @@ pub enum Key {
     NumpadMultiply,
     NumpadSubtract,
     One,
+    PageDown,
+    PageUp,
     QuestionMark,
     Quote,
     Six,
@@ -364,6 +379,10 @@ impl From<char> for Key {
             '\u{f701}' => Key::Down,
             '\u{f702}' => Key::Left,
             '\u{f703}' => Key::Right,
+            '\u{f729}' => Key::Home,
+            '\u{f72b}' => Key::End,
+            '\u{f72c}' => Key::PageUp,
+            '\u{f72d}' => Key::PageDown,
             '\u{8}' => Key::Backspace,
             '*' => Key::NumpadMultiply,
             '-' => Key::NumpadSubtract,
@@ -0,0 +1,161 @@
+//! This module contains a headless implementation of the window shell.
+//!
+//! The headless shell renders into an offscreen `RenderContext2D` and does not open a
+//! platform window. Input is not read from the operating system, it is scripted by pushing
+//! events to a `Window`. It could be used to run OrbTk applications in tests or on a CI
+//! server without a display.
+
+use std::sync::mpsc;
+
+use crate::{
+    event::{ButtonState, KeyEvent, MouseButton},
+    utils::Point,
+    window_adapter::WindowAdapter,
+    ShellRequest, WindowSettings,
+};
+
+pub use self::window::*;
+pub use self::window_builder::*;
+
+mod window;
+mod window_builder;
+
+/// Describes an input event that is scripted on a headless `Window`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ScriptedEvent {
+    /// Moves the mouse cursor to the given position.
+    MouseMove(Point),
+
+    /// Changes the state of a mouse button at the current mouse position.
+    MouseButton(MouseButton, ButtonState),
+
+    /// Scrolls by the given delta.
+    Scroll(Point),
+
+    /// Changes the state of a keyboard key.
+    Key(KeyEvent),
+
+    /// Emits a text input.
+    TextInput(String),
+
+    /// Resizes the window to the given width and height.
+    Resize(f64, f64),
+
+    /// Changes the active state of the window.
+    Active(bool),
+
+    /// Drops a file with the given name on the window.
+    FileDrop(String),
+
+    /// Drops a text on the window.
+    TextDrop(String),
+
+    /// Requests to quit the window.
+    Quit,
+}
+
+/// Represents a headless application shell that could handle multiple windows.
+pub struct Shell<A: 'static>
+where
+    A: WindowAdapter,
+{
+    window_shells: Vec<Window<A>>,
+    requests: mpsc::Receiver<ShellRequest<A>>,
+}
+
+impl<A> Shell<A>
+where
+    A: WindowAdapter,
+{
+    /// Creates a new headless application shell.
+    pub fn new(requests: mpsc::Receiver<ShellRequest<A>>) -> Self {
+        Shell {
+            window_shells: vec![],
+            requests,
+        }
+    }
+
+    /// Creates a window builder, that could be used to create a window and add it to the application shell.
+    pub fn create_window(&mut self, adapter: A) -> WindowBuilder<A> {
+        WindowBuilder::new(self, adapter)
+    }
+
+    /// Creates a window builder from a settings object.
+    pub fn create_window_from_settings(
+        &mut self,
+        settings: WindowSettings,
+        adapter: A,
+    ) -> WindowBuilder<A> {
+        WindowBuilder::from_settings(settings, self, adapter)
+    }
+
+    /// Gets the number of open windows.
+    pub fn len(&self) -> usize {
+        self.window_shells.len()
+    }
+
+    /// Returns `true` if the shell has no open windows.
+    pub fn is_empty(&self) -> bool {
+        self.window_shells.is_empty()
+    }
+
+    /// Gets a reference of the window with the given index.
+    pub fn window(&self, index: usize) -> Option<&Window<A>> {
+        self.window_shells.get(index)
+    }
+
+    /// Gets a mutable reference of the window with the given index.
+    pub fn window_mut(&mut self, index: usize) -> Option<&mut Window<A>> {
+        self.window_shells.get_mut(index)
+    }
+
+    /// Receives window request from the application and handles them.
+    pub fn receive_requests(&mut self) {
+        let mut requests = vec![];
+        for request in self.requests.try_iter() {
+            requests.push(request);
+        }
+
+        for request in requests {
+            if let ShellRequest::CreateWindow(adapter, settings, window_requests) = request {
+                self.create_window_from_settings(settings, adapter)
+                    .request_receiver(window_requests)
+                    .build();
+            }
+        }
+    }
+
+    /// Runs one frame on each window. Closed windows are removed from the shell.
+    ///
+    /// Returns `true` if at least one window has rendered a new frame.
+    pub fn run_frame(&mut self) -> bool {
+        let mut rendered = false;
+
+        for window_shell in &mut self.window_shells {
+            rendered |= window_shell.run_frame();
+        }
+
+        self.window_shells.retain(|w| w.is_open());
+        self.receive_requests();
+
+        rendered
+    }
+
+    /// Runs the shell until no window requests a new frame or `max_frames` frames are rendered.
+    ///
+    /// Returns the number of rendered frames.
+    pub fn run_until_idle(&mut self, max_frames: usize) -> usize {
+        let mut frames = 0;
+
+        while frames < max_frames && self.run_frame() {
+            frames += 1;
+        }
+
+        frames
+    }
+
+    /// Runs (starts) the application shell until all windows are idle.
+    pub fn run(&mut self) {
+        while !self.window_shells.is_empty() && self.run_frame() {}
+    }
+}
@@ -0,0 +1,451 @@
+use std::{collections::VecDeque, sync::mpsc};
+
+use super::ScriptedEvent;
+use crate::{
+    event::{ButtonState, Key, KeyEvent, MouseButton, MouseEvent},
+    render::RenderContext2D,
+    utils::{Color, Point},
+    window_adapter::WindowAdapter,
+    WindowRequest,
+};
+
+/// Represents a headless window. It renders into an offscreen render context, propagates
+/// scripted events to the window adapter and handles the update pipeline.
+pub struct Window<A>
+where
+    A: WindowAdapter,
+{
+    adapter: A,
+    render_context: RenderContext2D,
+    request_receiver: Option<mpsc::Receiver<WindowRequest>>,
+    events: VecDeque<ScriptedEvent>,
+    title: String,
+    size: (f64, f64),
+    mouse_position: Point,
+    clipboard: String,
+    has_clipboard_update: bool,
+    frame_count: usize,
+    update: bool,
+    close: bool,
+}
+
+impl<A> Window<A>
+where
+    A: WindowAdapter,
+{
+    /// Creates a new headless window.
+    pub fn new(
+        adapter: A,
+        render_context: RenderContext2D,
+        title: impl Into<String>,
+        size: (f64, f64),
+        request_receiver: Option<mpsc::Receiver<WindowRequest>>,
+    ) -> Self {
+        Window {
+            adapter,
+            render_context,
+            request_receiver,
+            events: VecDeque::new(),
+            title: title.into(),
+            size,
+            mouse_position: Point::default(),
+            clipboard: String::default(),
+            has_clipboard_update: true,
+            frame_count: 0,
+            update: true,
+            close: false,
+        }
+    }
+
+    /// Check if the window is open.
+    pub fn is_open(&self) -> bool {
+        !self.close
+    }
+
+    /// Gets the current title of the window.
+    pub fn title(&self) -> &str {
+        self.title.as_str()
+    }
+
+    /// Gets the current width of the window.
+    pub fn width(&self) -> f64 {
+        self.size.0
+    }
+
+    /// Gets the current height of the window.
+    pub fn height(&self) -> f64 {
+        self.size.1
+    }
+
+    /// Gets the number of frames rendered by the window.
+    pub fn frame_count(&self) -> usize {
+        self.frame_count
+    }
+
+    /// Gets the current mouse position of the window.
+    pub fn mouse_position(&self) -> Point {
+        self.mouse_position
+    }
+
+    /// Gets a reference of the window adapter.
+    pub fn adapter(&self) -> &A {
+        &self.adapter
+    }
+
+    /// Gets a mutable reference of the window adapter.
+    pub fn adapter_mut(&mut self) -> &mut A {
+        &mut self.adapter
+    }
+
+    /// Gets a reference of the offscreen render context.
+    pub fn render_context(&self) -> &RenderContext2D {
+        &self.render_context
+    }
+
+    /// Returns the pixel data of the last rendered frame.
+    ///
+    /// Byteorder: BGRA
+    pub fn data(&self) -> &[u8] {
+        self.render_context.data()
+    }
+
+    /// Returns the color of the pixel at the given position of the last rendered frame.
+    /// Returns `None` if the position is outside of the window.
+    pub fn pixel(&self, x: u32, y: u32) -> Option<Color> {
+        let width = self.width() as u32;
+
+        if x >= width || y >= self.height() as u32 {
+            return None;
+        }
+
+        let index = (y * width + x) as usize * 4;
+
+        self.data()
+            .get(index..index + 4)
+            .map(|p| Color::rgba(p[2], p[1], p[0], p[3]))
+    }
+
+    /// Gets the current value of the simulated system clipboard.
+    pub fn clipboard(&self) -> &str {
+        self.clipboard.as_str()
+    }
+
+    /// Sets the value of the simulated system clipboard.
+    pub fn set_clipboard(&mut self, value: impl Into<String>) {
+        self.clipboard = value.into();
+        self.has_clipboard_update = true;
+    }
+
+    /// Pushes a scripted event. The event is propagated to the adapter on the next frame.
+    pub fn push_event(&mut self, event: ScriptedEvent) {
+        self.events.push_back(event);
+    }
+
+    /// Moves the mouse cursor to the given position.
+    pub fn mouse_move(&mut self, x: f64, y: f64) {
+        self.push_event(ScriptedEvent::MouseMove(Point::new(x, y)));
+    }
+
+    /// Presses the given mouse button at the current mouse position.
+    pub fn mouse_down(&mut self, button: MouseButton) {
+        self.push_event(ScriptedEvent::MouseButton(button, ButtonState::Down));
+    }
+
+    /// Releases the given mouse button at the current mouse position.
+    pub fn mouse_up(&mut self, button: MouseButton) {
+        self.push_event(ScriptedEvent::MouseButton(button, ButtonState::Up));
+    }
+
+    /// Moves the mouse cursor to the given position and clicks the left mouse button.
+    pub fn click(&mut self, x: f64, y: f64) {
+        self.mouse_move(x, y);
+        self.mouse_down(MouseButton::Left);
+        self.mouse_up(MouseButton::Left);
+    }
+
+    /// Scrolls by the given delta.
+    pub fn scroll(&mut self, delta_x: f64, delta_y: f64) {
+        self.push_event(ScriptedEvent::Scroll(Point::new(delta_x, delta_y)));
+    }
+
+    /// Presses the given key.
+    pub fn key_down(&mut self, key: Key, text: impl Into<String>) {
+        self.push_event(ScriptedEvent::Key(KeyEvent {
+            state: ButtonState::Down,
+            key,
+            text: text.into(),
+        }));
+    }
+
+    /// Releases the given key.
+    pub fn key_up(&mut self, key: Key) {
+        self.push_event(ScriptedEvent::Key(KeyEvent {
+            state: ButtonState::Up,
+            key,
+            text: String::default(),
+        }));
+    }
+
+    /// Presses and releases the given key.
+    pub fn press_key(&mut self, key: Key, text: impl Into<String>) {
+        self.key_down(key, text);
+        self.key_up(key);
+    }
+
+    /// Emits a text input.
+    pub fn text_input(&mut self, text: impl Into<String>) {
+        self.push_event(ScriptedEvent::TextInput(text.into()));
+    }
+
+    /// Resizes the window.
+    pub fn resize(&mut self, width: f64, height: f64) {
+        self.push_event(ScriptedEvent::Resize(width, height));
+    }
+
+    /// Requests to quit the window.
+    pub fn quit(&mut self) {
+        self.push_event(ScriptedEvent::Quit);
+    }
+
+    /// Updates the clipboard.
+    pub fn update_clipboard(&mut self) {
+        let mut clipboard_value = if self.has_clipboard_update {
+            self.has_clipboard_update = false;
+            Some(self.clipboard.clone())
+        } else {
+            None
+        };
+
+        self.adapter.clipboard_update(&mut clipboard_value);
+
+        if let Some(value) = clipboard_value {
+            self.clipboard = value;
+        }
+    }
+
+    /// Drain the scripted events and propagate them to the adapter.
+    pub fn drain_events(&mut self) {
+        while let Some(event) = self.events.pop_front() {
+            match event {
+                ScriptedEvent::MouseMove(position) => {
+                    self.mouse_position = position;
+                    self.adapter.mouse(position.x(), position.y());
+                }
+                ScriptedEvent::MouseButton(button, state) => {
+                    self.adapter.mouse_event(MouseEvent {
+                        position: self.mouse_position,
+                        button,
+                        state,
+                    });
+                }
+                ScriptedEvent::Scroll(delta) => {
+                    self.adapter.scroll(delta.x(), delta.y());
+                }
+                ScriptedEvent::Key(event) => {
+                    self.adapter.key_event(event);
+                }
+                ScriptedEvent::TextInput(text) => {
+                    self.adapter.text_input(text);
+                }
+                ScriptedEvent::Resize(width, height) => {
+                    self.size = (width, height);
+                    self.adapter.resize(width, height);
+                    self.render_context.resize(width, height);
+                }
+                ScriptedEvent::Active(active) => {
+                    self.adapter.active(active);
+                }
+                ScriptedEvent::FileDrop(file_name) => {
+                    self.adapter.file_drop_event(file_name);
+                }
+                ScriptedEvent::TextDrop(text) => {
+                    self.adapter.text_drop_event(text);
+                }
+                ScriptedEvent::Quit => {
+                    self.adapter.quit_event();
+                    self.close = true;
+                }
+            }
+
+            self.update = true;
+        }
+    }
+
+    /// Receives window request from the application and handles them.
+    pub fn receive_requests(&mut self) {
+        if let Some(request_receiver) = &self.request_receiver {
+            for request in request_receiver.try_iter() {
+                match request {
+                    WindowRequest::Redraw => {
+                        self.update = true;
+                    }
+                    WindowRequest::ChangeTitle(title) => {
+                        self.title = title;
+                        self.update = true;
+                    }
+                    WindowRequest::Close => {
+                        self.close = true;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Runs update on the adapter and renders a new frame into the offscreen render context.
+    ///
+    /// Returns `true` if a new frame was rendered.
+    pub fn update(&mut self) -> bool {
+        if !self.update {
+            return false;
+        }
+
+        self.adapter.run(&mut self.render_context);
+        self.update = false;
+        self.frame_count += 1;
+
+        true
+    }
+
+    /// Propagates the pending events and requests and renders a new frame if needed.
+    ///
+    /// Returns `true` if a new frame was rendered.
+    pub fn run_frame(&mut self) -> bool {
+        self.drain_events();
+        self.update_clipboard();
+        self.receive_requests();
+
+        if !self.is_open() {
+            return false;
+        }
+
+        self.update()
+    }
+
+    /// Renders frames until the window is idle or `max_frames` frames are rendered.
+    ///
+    /// Returns the number of rendered frames.
+    pub fn run_until_idle(&mut self, max_frames: usize) -> usize {
+        let mut frames = 0;
+
+        while frames < max_frames && self.run_frame() {
+            frames += 1;
+        }
+
+        frames
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct TestAdapter {
+        runs: usize,
+        mouse_events: Vec<MouseEvent>,
+        key_events: Vec<KeyEvent>,
+        text: String,
+        size: (f64, f64),
+    }
+
+    impl WindowAdapter for TestAdapter {
+        fn set_raw_window_handle(&mut self, _: raw_window_handle::RawWindowHandle) {}
+
+        fn clipboard_update(&mut self, _: &mut Option<String>) {}
+
+        fn resize(&mut self, width: f64, height: f64) {
+            self.size = (width, height);
+        }
+
+        fn mouse_event(&mut self, event: MouseEvent) {
+            self.mouse_events.push(event);
+        }
+
+        fn key_event(&mut self, event: KeyEvent) {
+            self.key_events.push(event);
+        }
+
+        fn text_input(&mut self, text: String) {
+            self.text.push_str(text.as_str());
+        }
+
+        fn mouse_position(&self) -> Point {
+            Point::default()
+        }
+
+        fn active(&mut self, _: bool) {}
+
+        fn file_drop_event(&mut self, _: String) {}
+
+        fn text_drop_event(&mut self, _: String) {}
+
+        fn run(&mut self, render_context: &mut RenderContext2D) {
+            self.runs += 1;
+            render_context.set_fill_style(Color::rgb(255, 0, 0));
+            render_context.fill_rect(0.0, 0.0, 2.0, 2.0);
+        }
+    }
+
+    fn window() -> Window<TestAdapter> {
+        Window::new(
+            TestAdapter::default(),
+            RenderContext2D::new(4.0, 4.0),
+            "Test",
+            (4.0, 4.0),
+            None,
+        )
+    }
+
+    #[test]
+    fn test_run_until_idle() {
+        let mut window = window();
+
+        assert_eq!(window.run_until_idle(10), 1);
+        assert_eq!(window.adapter().runs, 1);
+        assert_eq!(window.run_until_idle(10), 0);
+
+        window.text_input("a");
+        assert_eq!(window.run_until_idle(10), 1);
+        assert_eq!(window.frame_count(), 2);
+    }
+
+    #[test]
+    fn test_scripted_events() {
+        let mut window = window();
+
+        window.click(2.0, 3.0);
+        window.press_key(Key::A(false), "a");
+        window.text_input("a");
+        window.resize(8.0, 6.0);
+        window.run_frame();
+
+        let adapter = window.adapter();
+        assert_eq!(adapter.mouse_events.len(), 2);
+        assert_eq!(adapter.mouse_events[0].position, Point::new(2.0, 3.0));
+        assert_eq!(adapter.mouse_events[0].state, ButtonState::Down);
+        assert_eq!(adapter.mouse_events[1].state, ButtonState::Up);
+        assert_eq!(adapter.key_events.len(), 2);
+        assert_eq!(adapter.text, "a");
+        assert_eq!(adapter.size, (8.0, 6.0));
+        assert_eq!(window.data().len(), 8 * 6 * 4);
+    }
+
+    #[test]
+    fn test_pixel() {
+        let mut window = window();
+        window.run_frame();
+
+        assert_eq!(window.pixel(0, 0), Some(Color::rgb(255, 0, 0)));
+        assert_eq!(window.pixel(3, 3), Some(Color::rgba(0, 0, 0, 0)));
+        assert_eq!(window.pixel(4, 0), None);
+    }
+
+    #[test]
+    fn test_quit() {
+        let mut window = window();
+        window.quit();
+
+        assert!(!window.run_frame());
+        assert!(!window.is_open());
+    }
+}
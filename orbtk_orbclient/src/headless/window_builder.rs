@@ -0,0 +1,113 @@
+use std::sync::mpsc;
+
+use super::{Shell, Window};
+use crate::{
+    render::{FontFace, FontSource, RenderContext2D},
+    utils::{FontStyle, FontWeight, Rectangle},
+    window_adapter::WindowAdapter,
+    WindowRequest, WindowSettings,
+};
+
+/// The `WindowBuilder` is used to construct a window shell for the headless backend.
+pub struct WindowBuilder<'a, A: 'static>
+where
+    A: WindowAdapter,
+{
+    shell: &'a mut Shell<A>,
+    adapter: A,
+    title: String,
+    fonts: Vec<(String, FontSource)>,
+    font_faces: Vec<FontFace>,
+    bounds: Rectangle,
+    request_receiver: Option<mpsc::Receiver<WindowRequest>>,
+}
+
+impl<'a, A> WindowBuilder<'a, A>
+where
+    A: WindowAdapter,
+{
+    /// Creates a new window builder.
+    pub fn new(shell: &'a mut Shell<A>, adapter: A) -> Self {
+        WindowBuilder {
+            shell,
+            adapter,
+            title: String::default(),
+            fonts: vec![],
+            font_faces: vec![],
+            bounds: Rectangle::new((0.0, 0.0), (100.0, 75.0)),
+            request_receiver: None,
+        }
+    }
+
+    /// Creates the window builder from a settings object.
+    pub fn from_settings(settings: WindowSettings, shell: &'a mut Shell<A>, adapter: A) -> Self {
+        WindowBuilder {
+            shell,
+            adapter,
+            title: settings.title,
+            fonts: settings.fonts,
+            font_faces: settings.font_faces,
+            bounds: Rectangle::new(settings.position, (settings.size.0, settings.size.1)),
+            request_receiver: None,
+        }
+    }
+
+    /// Sets the title.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Sets the bounds.
+    pub fn bounds(mut self, bounds: impl Into<Rectangle>) -> Self {
+        self.bounds = bounds.into();
+        self
+    }
+
+    /// Registers a new font with family key. Fonts registered for the same family are used as
+    /// fallback for chars that are missing in the first font.
+    pub fn font(mut self, family: impl Into<String>, font: impl Into<FontSource>) -> Self {
+        self.fonts.push((family.into(), font.into()));
+        self
+    }
+
+    /// Registers the font `face` as face of `family` with the given weight and style.
+    pub fn font_face(
+        mut self,
+        family: impl Into<String>,
+        weight: FontWeight,
+        style: FontStyle,
+        face: impl Into<String>,
+    ) -> Self {
+        self.font_faces
+            .push(FontFace::new(family, weight, style, face));
+        self
+    }
+
+    /// Register a window request receiver to communicate with the window shell from outside.
+    pub fn request_receiver(mut self, request_receiver: mpsc::Receiver<WindowRequest>) -> Self {
+        self.request_receiver = Some(request_receiver);
+        self
+    }
+
+    /// Builds the window shell and add it to the application `Shell`.
+    pub fn build(self) {
+        let mut render_context = RenderContext2D::new(self.bounds.width(), self.bounds.height());
+
+        for (family, font) in self.fonts {
+            render_context.register_font(&family, font);
+        }
+
+        for face in self.font_faces {
+            render_context.register_font_face(face);
+        }
+
+        self.shell.window_shells.push(Window::new(
+            self.adapter,
+            render_context,
+            self.title,
+            (self.bounds.width(), self.bounds.height()),
+            self.request_receiver,
+        ));
+    }
+}
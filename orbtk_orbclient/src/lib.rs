@@ -27,12 +27,14 @@ runner.run()
 #[macro_use]
 extern crate lazy_static;
 
+pub mod accessibility;
 pub mod event;
 pub mod prelude;
 pub mod window_adapter;
 
 pub use orbtk_utils::prelude as utils;
 
+pub mod headless;
 pub mod orbclient;
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -40,7 +42,7 @@ pub mod native;
 
 pub use orbtk_tinyskia::prelude as render;
 
-use std::{collections::HashMap, sync::mpsc};
+use std::sync::mpsc;
 
 /// Used to send a request to the window.
 #[derive(Clone, Debug, PartialEq)]
@@ -97,5 +99,8 @@ pub struct WindowSettings {
     pub size: (f64, f64),
 
     /// List of fonts to register.
-    pub fonts: HashMap<String, &'static [u8]>,
+    pub fonts: Vec<(String, render::FontSource)>,
+
+    /// List of font faces to register.
+    pub font_faces: Vec<render::FontFace>,
 }
@@ -201,13 +201,28 @@ where
                     orbclient::K_CTRL => key = Key::Control,
                     orbclient::K_DEL => key = Key::Delete,
                     orbclient::K_DOWN => key = Key::Down,
+                    orbclient::K_END => key = Key::End,
                     orbclient::K_ENTER => key = Key::Enter,
                     orbclient::K_ESC => key = Key::Escape,
+                    orbclient::K_F1 => key = Key::F1,
+                    orbclient::K_F2 => key = Key::F2,
+                    orbclient::K_F3 => key = Key::F3,
+                    orbclient::K_F4 => key = Key::F4,
+                    orbclient::K_F5 => key = Key::F5,
+                    orbclient::K_F6 => key = Key::F6,
+                    orbclient::K_F7 => key = Key::F7,
+                    orbclient::K_F8 => key = Key::F8,
+                    orbclient::K_F9 => key = Key::F9,
+                    orbclient::K_F10 => key = Key::F10,
+                    orbclient::K_F11 => key = Key::F11,
+                    orbclient::K_F12 => key = Key::F12,
                     orbclient::K_HOME => {
                         key = Key::Home;
                     }
                     orbclient::K_LEFT => key = Key::Left,
                     orbclient::K_LEFT_SHIFT => key = Key::ShiftL,
+                    orbclient::K_PGDN => key = Key::PageDown,
+                    orbclient::K_PGUP => key = Key::PageUp,
                     orbclient::K_RIGHT => key = Key::Right,
                     orbclient::K_RIGHT_SHIFT => key = Key::ShiftR,
                     orbclient::K_TAB => key = Key::Tab,
@@ -364,14 +379,30 @@ where
     /// Swaps the current frame buffer.
     pub fn render(&mut self) {
         if self.redraw.load(Ordering::Relaxed) {
-            let bytes = self.render_context.data_u8_mut();
-            let len = bytes.len() / std::mem::size_of::<orbclient::Color>();
-            let color_data = unsafe {
-                std::slice::from_raw_parts_mut(bytes.as_mut_ptr() as *mut orbclient::Color, len)
-            };
-
-            if color_data.len() == self.window.data().len() {
-                self.window.data_mut().clone_from_slice(color_data);
+            let width = self.render_context.width() as usize;
+            let len = self.render_context.data().len() / std::mem::size_of::<orbclient::Color>();
+
+            if len == self.window.data().len() {
+                let damage = self.render_context.take_damage();
+                let bytes = self.render_context.data_u8_mut();
+                let color_data = unsafe {
+                    std::slice::from_raw_parts_mut(bytes.as_mut_ptr() as *mut orbclient::Color, len)
+                };
+
+                // only the damaged area of the frame buffer is updated
+                if let Some(damage) = damage {
+                    let left = damage.x() as usize;
+                    let right = (left + damage.width() as usize).min(width);
+                    let top = damage.y() as usize;
+                    let bottom = top + damage.height() as usize;
+                    let data = self.window.data_mut();
+
+                    for row in (top..bottom).take_while(|row| (row + 1) * width <= len) {
+                        let start = row * width;
+                        data[start + left..start + right]
+                            .clone_from_slice(&color_data[start + left..start + right]);
+                    }
+                }
 
                 // CONSOLE.time_end("render");
                 self.redraw.store(false, Ordering::Relaxed)
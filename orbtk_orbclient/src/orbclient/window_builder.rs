@@ -1,9 +1,11 @@
-use std::{collections::HashMap, sync::mpsc};
+use std::sync::mpsc;
 
 use super::{Shell, Window};
 use crate::{
-    render::RenderContext2D, utils::Rectangle, window_adapter::WindowAdapter, WindowRequest,
-    WindowSettings,
+    render::{FontFace, FontSource, RenderContext2D},
+    utils::{FontStyle, FontWeight, Rectangle},
+    window_adapter::WindowAdapter,
+    WindowRequest, WindowSettings,
 };
 
 /// The `WindowBuilder` is used to construct a window shell for the minifb backend.
@@ -17,7 +19,8 @@ where
     resizeable: bool,
     always_on_top: bool,
     borderless: bool,
-    fonts: HashMap<String, &'static [u8]>,
+    fonts: Vec<(String, FontSource)>,
+    font_faces: Vec<FontFace>,
     bounds: Rectangle,
     request_receiver: Option<mpsc::Receiver<WindowRequest>>,
 }
@@ -35,7 +38,8 @@ where
             resizeable: false,
             always_on_top: false,
             borderless: false,
-            fonts: HashMap::new(),
+            fonts: vec![],
+            font_faces: vec![],
             bounds: Rectangle::new((0.0, 0.0), (100.0, 75.0)),
             request_receiver: None,
         }
@@ -51,6 +55,7 @@ where
             always_on_top: settings.always_on_top,
             borderless: settings.borderless,
             fonts: settings.fonts,
+            font_faces: settings.font_faces,
             bounds: Rectangle::new(settings.position, (settings.size.0, settings.size.1)),
             request_receiver: None,
         }
@@ -86,9 +91,23 @@ where
         self
     }
 
-    /// Registers a new font with family key.
-    pub fn font(mut self, family: impl Into<String>, font_file: &'static [u8]) -> Self {
-        self.fonts.insert(family.into(), font_file);
+    /// Registers a new font with family key. Fonts registered for the same family are used as
+    /// fallback for chars that are missing in the first font.
+    pub fn font(mut self, family: impl Into<String>, font: impl Into<FontSource>) -> Self {
+        self.fonts.push((family.into(), font.into()));
+        self
+    }
+
+    /// Registers the font `face` as face of `family` with the given weight and style.
+    pub fn font_face(
+        mut self,
+        family: impl Into<String>,
+        weight: FontWeight,
+        style: FontStyle,
+        face: impl Into<String>,
+    ) -> Self {
+        self.font_faces
+            .push(FontFace::new(family, weight, style, face));
         self
     }
 
@@ -130,6 +149,10 @@ where
             render_context.register_font(&family, font);
         }
 
+        for face in self.font_faces {
+            render_context.register_font_face(face);
+        }
+
         self.shell.window_shells.push(Window::new(
             window,
             self.adapter,
@@ -1,3 +1,4 @@
 pub use crate::{
-    event::*, orbclient::*, window_adapter::*, ShellRequest, WindowRequest, WindowSettings,
+    accessibility::*, event::*, orbclient::*, window_adapter::*, ShellRequest, WindowRequest,
+    WindowSettings,
 };
@@ -324,9 +324,24 @@ fn get_key(code: &str, key: String) -> (Key, String) {
         }
         "ControlLeft" | "ControlRight" => Key::Control,
         "Delete" => Key::Delete,
+        "End" => Key::End,
         "Enter" => Key::Enter,
         "Escape" => Key::Escape,
+        "F1" => Key::F1,
+        "F2" => Key::F2,
+        "F3" => Key::F3,
+        "F4" => Key::F4,
+        "F5" => Key::F5,
+        "F6" => Key::F6,
+        "F7" => Key::F7,
+        "F8" => Key::F8,
+        "F9" => Key::F9,
+        "F10" => Key::F10,
+        "F11" => Key::F11,
+        "F12" => Key::F12,
         "OSLeft" | "OSRight" => Key::Home,
+        "PageDown" => Key::PageDown,
+        "PageUp" => Key::PageUp,
         "ShiftLeft" => Key::ShiftL,
         "ShiftRight" => Key::ShiftR,
         "Tab" => Key::Tab,
@@ -341,6 +341,94 @@ pub fn linear_gradient_ends_from_angle(angle: Angle, size: Size) -> Point {
     z
 }
 
+// Returns the radius of a radial gradient in pixels, percentages refer to the distance from the center to the farthest corner.
+pub fn radial_gradient_radius(radius: OnLinePos, center: Point, size: Size) -> f64 {
+    let farthest_corner = [
+        Point::new(0.0, 0.0),
+        Point::new(size.width(), 0.0),
+        Point::new(0.0, size.height()),
+        Point::new(size.width(), size.height()),
+    ]
+    .iter()
+    .map(|corner| corner.distance(center))
+    .fold(0.0, f64::max);
+    radius.pixels(farthest_corner)
+}
+
+// Returns the color of a conic gradient at the given clockwise `angle` in radians. The stops must be
+// sorted by their position in a range from 0.0 to 1.0 of a full turn.
+pub fn conic_gradient_color(stops: &[(f64, Color)], angle: f64, repeat: bool) -> Color {
+    let (first, last) = match (stops.first(), stops.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return Color::default(),
+    };
+    let mut pos = (angle / TAU).rem_euclid(1.0);
+    if repeat && last.0 > first.0 {
+        pos = first.0 + (pos - first.0).rem_euclid(last.0 - first.0);
+    }
+    if pos <= first.0 {
+        return first.1;
+    }
+    for window in stops.windows(2) {
+        let (start, end) = (window[0], window[1]);
+        if pos <= end.0 {
+            if end.0 - start.0 <= f64::EPSILON {
+                return end.1;
+            }
+            return Color::interpolate(start.1, end.1, (pos - start.0) / (end.0 - start.0));
+        }
+    }
+    last.1
+}
+
+// Blurs the given pixel `data` in place with three passes of a box blur, that approximates a
+// gaussian blur with the given standard deviation.
+pub fn blur_pixels(data: &mut [u8], width: usize, height: usize, sigma: f64) {
+    let radius = (((4.0 * sigma * sigma + 1.0).sqrt() - 1.0) / 2.0).round() as usize;
+    if radius == 0 || width == 0 || height == 0 {
+        return;
+    }
+
+    let mut buffer = vec![0; data.len()];
+    for _ in 0..3 {
+        box_blur(data, &mut buffer, width, height, radius, (4, width * 4));
+        box_blur(&buffer, data, height, width, radius, (width * 4, 4));
+    }
+}
+
+// Blurs the lines of `source` into `target` with a box of `2 * radius + 1` pixels. The `stride` describes the
+// byte offset between two pixels of a line and between two lines.
+fn box_blur(
+    source: &[u8],
+    target: &mut [u8],
+    length: usize,
+    lines: usize,
+    radius: usize,
+    stride: (usize, usize),
+) {
+    let size = (2 * radius + 1) as u32;
+    let (pixel_stride, line_stride) = stride;
+
+    for line in 0..lines {
+        let start = line * line_stride;
+        for channel in 0..4 {
+            let value = |i: usize| source[start + i * pixel_stride + channel] as u32;
+            let mut sum: u32 = (0..=radius.min(length - 1)).map(value).sum();
+
+            for i in 0..length {
+                target[start + i * pixel_stride + channel] = ((sum + size / 2) / size) as u8;
+
+                if i + radius + 1 < length {
+                    sum += value(i + radius + 1);
+                }
+                if i >= radius {
+                    sum -= value(i - radius);
+                }
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::PathRect;
@@ -366,6 +454,52 @@ mod tests {
         assert!(orbtk_utils::f64_cmp(100.0, urect.height()));
     }
 
+    #[test]
+    fn test_conic_gradient_color() {
+        use super::conic_gradient_color;
+        use orbtk_utils::Color;
+        use std::f64::consts::{PI, TAU};
+
+        let red = Color::rgb(255, 0, 0);
+        let blue = Color::rgb(0, 0, 255);
+        let stops = [(0.0, red), (0.5, blue)];
+
+        assert_eq!(conic_gradient_color(&stops, 0.0, false), red);
+        assert_eq!(
+            conic_gradient_color(&stops, PI / 2.0, false),
+            Color::rgb(127, 0, 127)
+        );
+        assert_eq!(conic_gradient_color(&stops, 1.5 * PI, false), blue);
+        assert_eq!(conic_gradient_color(&stops, TAU + PI, false), blue);
+        assert_eq!(
+            conic_gradient_color(&stops, 1.5 * PI, true),
+            Color::rgb(127, 0, 127)
+        );
+        assert_eq!(conic_gradient_color(&[], 0.0, false), Color::default());
+    }
+
+    #[test]
+    fn test_radial_gradient_radius() {
+        use super::radial_gradient_radius;
+        use orbtk_utils::{OnLinePos, OnLinePosKind, Point, Size};
+
+        let size = Size::new(40.0, 30.0);
+        let percent = OnLinePos::new(100.0, OnLinePosKind::Percentage);
+        assert!(orbtk_utils::f64_cmp(
+            radial_gradient_radius(percent, Point::new(20.0, 15.0), size),
+            25.0
+        ));
+        assert!(orbtk_utils::f64_cmp(
+            radial_gradient_radius(percent, Point::new(0.0, 0.0), size),
+            50.0
+        ));
+        let pixels = OnLinePos::new(12.0, OnLinePosKind::Pixels);
+        assert!(orbtk_utils::f64_cmp(
+            radial_gradient_radius(pixels, Point::new(0.0, 0.0), size),
+            12.0
+        ));
+    }
+
     #[test]
     fn test_pathrect_arcs() {
         let mut rect = PathRect::new(None);
@@ -420,4 +554,21 @@ mod tests {
         assert!(orbtk_utils::f64_cmp(98.0, urect.width()));
         assert!(orbtk_utils::f64_cmp(185.57550765359252, urect.height()));
     }
+
+    #[test]
+    fn test_blur() {
+        use super::blur_pixels;
+
+        let mut data = vec![0; 9 * 4];
+        data[4 * 4..5 * 4].copy_from_slice(&[255, 255, 255, 255]);
+        blur_pixels(&mut data, 3, 3, 0.2);
+        assert_eq!(data[4 * 4 + 3], 255);
+
+        blur_pixels(&mut data, 3, 3, 1.0);
+        let alpha: Vec<u8> = data.chunks_exact(4).map(|p| p[3]).collect();
+        assert!(alpha[4] < 255);
+        assert!(alpha[0] > 0);
+        assert!(alpha[0].abs_diff(alpha[8]) <= 1);
+        assert!(alpha[1].abs_diff(alpha[3]) <= 1);
+    }
 }
@@ -16,12 +16,58 @@ pub use self::render_target::*;
 
 mod render_target;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub mod snapshot;
+
+/// Defines the shape used to draw the end points of lines.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum LineCap {
+    /// The ends of lines are squared off at the endpoints.
+    Butt,
+
+    /// The ends of lines are rounded.
+    Round,
+
+    /// The ends of lines are squared off by adding a box with half of the line width.
+    Square,
+}
+
+impl Default for LineCap {
+    fn default() -> Self {
+        LineCap::Butt
+    }
+}
+
+/// Defines the shape used to join two line segments where they meet.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum LineJoin {
+    /// Connected segments are joined by extending their outside edges to connect at a single point.
+    Miter,
+
+    /// Rounds off the corners of a shape.
+    Round,
+
+    /// Fills the area between the common endpoint of connected segments and their outside corners.
+    Bevel,
+}
+
+impl Default for LineJoin {
+    fn default() -> Self {
+        LineJoin::Miter
+    }
+}
+
 /// Defines the current configuration of the render ctx.
 #[derive(Debug, Clone)]
 pub struct RenderConfig {
     pub fill_style: utils::Brush,
     pub stroke_style: utils::Brush,
     pub line_width: f64,
+    pub line_dash: Vec<f64>,
+    pub line_dash_offset: f64,
+    pub line_cap: LineCap,
+    pub line_join: LineJoin,
+    pub miter_limit: f64,
     pub font_config: FontConfig,
     pub alpha: f32,
 }
@@ -32,6 +78,11 @@ impl Default for RenderConfig {
             fill_style: utils::Brush::default(),
             stroke_style: utils::Brush::default(),
             line_width: 1.,
+            line_dash: vec![],
+            line_dash_offset: 0.,
+            line_cap: LineCap::default(),
+            line_join: LineJoin::default(),
+            miter_limit: 10.,
             font_config: FontConfig::default(),
             alpha: 1.,
         }
@@ -50,6 +101,8 @@ pub struct TextMetrics {
 pub struct FontConfig {
     pub family: String,
     pub font_size: f64,
+    pub weight: utils::FontWeight,
+    pub style: utils::FontStyle,
 }
 
 impl ToString for FontConfig {
@@ -0,0 +1,316 @@
+//! This module contains helpers to compare rendered frames with stored golden images.
+
+use std::{
+    env, fmt,
+    path::{Path, PathBuf},
+};
+
+use crate::RenderContext2D;
+
+/// If this environment variable is set, `Snapshot::assert_matches` overwrites the stored images
+/// with the current snapshots instead of comparing them.
+pub const UPDATE_SNAPSHOTS_ENV: &str = "ORBTK_UPDATE_SNAPSHOTS";
+
+/// Describes the differences between two snapshots.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SnapshotDiff {
+    /// Number of pixels whose difference exceeds the tolerance.
+    pub mismatched_pixels: usize,
+
+    /// The biggest difference of a color channel over all pixels.
+    pub max_difference: u8,
+
+    /// The image that highlights the mismatched pixels in red.
+    pub image: Snapshot,
+}
+
+impl SnapshotDiff {
+    /// Returns `true` if there are no mismatched pixels.
+    pub fn is_match(&self) -> bool {
+        self.mismatched_pixels == 0
+    }
+}
+
+/// A `Snapshot` holds the pixels of a rendered frame as non premultiplied RGBA values.
+#[derive(Clone, Default, PartialEq)]
+pub struct Snapshot {
+    width: u32,
+    height: u32,
+    data: Vec<u8>,
+}
+
+impl fmt::Debug for Snapshot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Snapshot ( width: {}, height: {})",
+            self.width, self.height
+        )
+    }
+}
+
+impl Snapshot {
+    /// Creates a snapshot from non premultiplied RGBA data.
+    pub fn from_rgba(width: u32, height: u32, data: Vec<u8>) -> Result<Self, String> {
+        if data.len() != width as usize * height as usize * 4 {
+            return Err(format!(
+                "Snapshot data length {} does not match size {}x{}",
+                data.len(),
+                width,
+                height
+            ));
+        }
+
+        Ok(Snapshot {
+            width,
+            height,
+            data,
+        })
+    }
+
+    /// Creates a snapshot from the premultiplied BGRA frame data of a render context.
+    pub fn from_frame_data(width: u32, height: u32, data: &[u8]) -> Result<Self, String> {
+        let data = data
+            .chunks_exact(4)
+            .flat_map(|p| {
+                let a = p[3];
+                let demultiply = |c: u8| {
+                    if a == 0 {
+                        0
+                    } else {
+                        ((c as u32 * 255 + a as u32 / 2) / a as u32).min(255) as u8
+                    }
+                };
+                [demultiply(p[2]), demultiply(p[1]), demultiply(p[0]), a]
+            })
+            .collect();
+
+        Self::from_rgba(width, height, data)
+    }
+
+    /// Creates a snapshot of the current frame of the given render context.
+    pub fn from_render_context(render_context: &RenderContext2D) -> Self {
+        Self::from_frame_data(
+            render_context.width() as u32,
+            render_context.height() as u32,
+            render_context.data(),
+        )
+        .expect("Snapshot: render context data does not match its size.")
+    }
+
+    /// Loads a snapshot from a PNG file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let path = path.as_ref();
+
+        match image::open(path) {
+            Ok(image) => {
+                let image = image.to_rgba8();
+                Self::from_rgba(image.width(), image.height(), image.into_raw())
+            }
+            Err(e) => Err(format!("Could not load snapshot {:?}: {}", path, e)),
+        }
+    }
+
+    /// Saves the snapshot as PNG file. Missing parent directories are created.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), String> {
+        let path = path.as_ref();
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Could not create directory {:?}: {}", parent, e))?;
+        }
+
+        image::save_buffer(
+            path,
+            &self.data,
+            self.width,
+            self.height,
+            image::ColorType::Rgba8,
+        )
+        .map_err(|e| format!("Could not save snapshot {:?}: {}", path, e))
+    }
+
+    /// Gets the width.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Gets the height.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Gets the RGBA data.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Compares the snapshot with an `expected` one. A pixel is mismatched if one of its color channels
+    /// differs by more than `tolerance`.
+    ///
+    /// Returns an error if the sizes of the snapshots are different.
+    pub fn compare(&self, expected: &Snapshot, tolerance: u8) -> Result<SnapshotDiff, String> {
+        if self.width != expected.width || self.height != expected.height {
+            return Err(format!(
+                "Snapshot size {}x{} does not match expected size {}x{}",
+                self.width, self.height, expected.width, expected.height
+            ));
+        }
+
+        let mut diff = SnapshotDiff::default();
+        let mut image = Vec::with_capacity(self.data.len());
+
+        for (actual, expected) in self.data.chunks_exact(4).zip(expected.data.chunks_exact(4)) {
+            let difference = actual
+                .iter()
+                .zip(expected)
+                .map(|(a, e)| a.abs_diff(*e))
+                .max()
+                .unwrap_or(0);
+
+            diff.max_difference = diff.max_difference.max(difference);
+
+            if difference > tolerance {
+                diff.mismatched_pixels += 1;
+                image.extend_from_slice(&[255, 0, 0, 255]);
+            } else {
+                // draws matching pixels as faded gray scale of the expected image
+                let gray = ((expected[0] as u32 + expected[1] as u32 + expected[2] as u32) / 3
+                    * expected[3] as u32
+                    / 255) as u8;
+                let faded = 255 - (255 - gray) / 4;
+                image.extend_from_slice(&[faded, faded, faded, 255]);
+            }
+        }
+
+        diff.image = Snapshot::from_rgba(self.width, self.height, image)?;
+
+        Ok(diff)
+    }
+
+    /// Compares the snapshot with the golden image stored at `path`.
+    ///
+    /// If the `ORBTK_UPDATE_SNAPSHOTS` environment variable is set, the snapshot is stored as new
+    /// golden image. A missing golden image is an error. On a mismatch the current snapshot is
+    /// written to `<name>.actual.png` and a diff image to `<name>.diff.png` next to the golden image.
+    pub fn assert_matches<P: AsRef<Path>>(&self, path: P, tolerance: u8) -> Result<(), String> {
+        let path = path.as_ref();
+
+        if env::var_os(UPDATE_SNAPSHOTS_ENV).is_some() {
+            return self.save(path);
+        }
+
+        if !path.exists() {
+            return Err(format!(
+                "Golden image {:?} is missing, rerun with {}=1 to create it",
+                path, UPDATE_SNAPSHOTS_ENV
+            ));
+        }
+
+        let expected = Snapshot::load(path)?;
+        let actual_path = sibling_path(path, "actual");
+        let diff_path = sibling_path(path, "diff");
+
+        let diff = match self.compare(&expected, tolerance) {
+            Ok(diff) => diff,
+            Err(e) => {
+                self.save(&actual_path)?;
+                return Err(format!("{}, actual image written to {:?}", e, actual_path));
+            }
+        };
+
+        if diff.is_match() {
+            return Ok(());
+        }
+
+        self.save(&actual_path)?;
+        diff.image.save(&diff_path)?;
+
+        Err(format!(
+            "Snapshot {:?} does not match: {} pixels differ (max difference {}, tolerance {}), diff image written to {:?}",
+            path, diff.mismatched_pixels, diff.max_difference, tolerance, diff_path
+        ))
+    }
+}
+
+// -- Helpers --
+
+// builds e.g. `button.diff.png` from `button.png`.
+fn sibling_path(path: &Path, suffix: &str) -> PathBuf {
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    path.with_file_name(format!("{}.{}.png", stem, suffix))
+}
+
+// -- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(color: [u8; 4]) -> Snapshot {
+        Snapshot::from_rgba(2, 2, color.repeat(4)).unwrap()
+    }
+
+    #[test]
+    fn test_from_frame_data() {
+        let snapshot = Snapshot::from_frame_data(1, 2, &[0, 0, 128, 128, 10, 20, 30, 0]).unwrap();
+        assert_eq!(snapshot.data(), &[255, 0, 0, 128, 0, 0, 0, 0]);
+
+        assert!(Snapshot::from_frame_data(2, 2, &[0, 0, 0, 0]).is_err());
+    }
+
+    #[test]
+    fn test_compare() {
+        let expected = snapshot([100, 100, 100, 255]);
+
+        let diff = snapshot([102, 100, 100, 255])
+            .compare(&expected, 2)
+            .unwrap();
+        assert!(diff.is_match());
+        assert_eq!(diff.max_difference, 2);
+
+        let diff = snapshot([103, 100, 100, 255])
+            .compare(&expected, 2)
+            .unwrap();
+        assert!(!diff.is_match());
+        assert_eq!(diff.mismatched_pixels, 4);
+        assert_eq!(&diff.image.data()[0..4], &[255, 0, 0, 255]);
+
+        let other_size = Snapshot::from_rgba(1, 1, vec![0; 4]).unwrap();
+        assert!(other_size.compare(&expected, 0).is_err());
+    }
+
+    #[test]
+    fn test_assert_matches() {
+        let dir = env::temp_dir().join(format!("orbtk_snapshot_{}", std::process::id()));
+        let path = dir.join("golden.png");
+
+        let golden = snapshot([10, 20, 30, 255]);
+        assert!(golden.assert_matches(&path, 0).is_err());
+        assert!(!path.exists());
+
+        golden.save(&path).unwrap();
+        assert_eq!(Snapshot::load(&path).unwrap(), golden);
+        assert!(golden.assert_matches(&path, 0).is_ok());
+
+        assert!(snapshot([40, 20, 30, 255])
+            .assert_matches(&path, 0)
+            .is_err());
+        assert!(dir.join("golden.diff.png").exists());
+        assert!(dir.join("golden.actual.png").exists());
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_sibling_path() {
+        assert_eq!(
+            sibling_path(Path::new("tests/button.png"), "diff"),
+            PathBuf::from("tests/button.diff.png")
+        );
+    }
+}
@@ -1,5 +1,17 @@
+#[cfg(feature = "shaping")]
+use std::sync::Arc;
+use std::{
+    fmt,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
 use rusttype::OutlineBuilder;
-use tiny_skia::{FillRule, Paint, PathBuilder, Pixmap, Transform};
+use tiny_skia::{ClipMask, FillRule, Paint, PathBuilder, Pixmap, Transform};
+
+use super::GlyphMask;
+
+// Each loaded font gets an unique id, clones of a font share it.
+static NEXT_FONT_ID: AtomicUsize = AtomicUsize::new(0);
 
 #[derive(Debug)]
 struct GlyphTracer {
@@ -43,40 +55,120 @@ impl OutlineBuilder for GlyphTracer {
     }
 }
 
-#[derive(Debug, Clone)]
+// A glyph of a laid out text. All positions are in pixels and relative to the start of the
+// text on its base line.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Glyph {
+    pub id: rusttype::GlyphId,
+
+    // position of the pen on the x axis
+    pub x: f32,
+
+    // offset of the glyph from the pen position
+    pub offset: (f32, f32),
+
+    pub advance: f32,
+
+    // byte index of the first char of the cluster the glyph belongs to
+    pub cluster: usize,
+
+    // true if the glyph is part of a right-to-left run
+    pub rtl: bool,
+}
+
+/// Describes the underline and the strikethrough of a text. The positions are the distances of
+/// the top of the lines above the base line, so the underline has usually a negative position.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TextDecorationMetrics {
+    pub underline_position: f64,
+    pub underline_thickness: f64,
+    pub strikethrough_position: f64,
+    pub strikethrough_thickness: f64,
+}
+
+#[derive(Clone)]
 pub struct Font {
+    id: usize,
     inner: rusttype::Font<'static>,
+    // decoration metrics in font units
+    decorations: TextDecorationMetrics,
+    #[cfg(feature = "shaping")]
+    bytes: Arc<[u8]>,
+}
+
+impl fmt::Debug for Font {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Font")
+            .field("id", &self.id)
+            .field("inner", &self.inner)
+            .field("decorations", &self.decorations)
+            .finish()
+    }
 }
 
 impl Font {
     pub fn from_bytes(bytes: &'static [u8]) -> Result<Self, &'static str> {
         rusttype::Font::try_from_bytes(bytes)
-            .map(|font| Font { inner: font })
+            .map(|font| Font {
+                id: NEXT_FONT_ID.fetch_add(1, Ordering::Relaxed),
+                decorations: read_decoration_metrics(bytes, font.units_per_em()),
+                inner: font,
+                #[cfg(feature = "shaping")]
+                bytes: Arc::from(bytes),
+            })
             .ok_or("Could not load font from bytes")
     }
 
-    pub fn measure_text(&self, text: &str, size: f64) -> (f64, f64) {
-        let scale = rusttype::Scale::uniform(size as f32);
-        let v_metrics = self.inner.v_metrics(scale);
-        let offset = rusttype::point(0.0, v_metrics.ascent);
+    /// Loads a font from owned bytes, e.g. the content of a font file read from disk.
+    pub fn from_vec(bytes: Vec<u8>) -> Result<Self, &'static str> {
+        #[cfg(feature = "shaping")]
+        let shared: Arc<[u8]> = Arc::from(bytes.as_slice());
 
-        let pixel_height = size.ceil();
+        // the metrics are read before the bytes are moved into the font
+        let decorations = read_decoration_metrics(&bytes, 0);
+
+        rusttype::Font::try_from_vec(bytes)
+            .map(|font| Font {
+                id: NEXT_FONT_ID.fetch_add(1, Ordering::Relaxed),
+                decorations: fallback_decoration_metrics(decorations, font.units_per_em()),
+                inner: font,
+                #[cfg(feature = "shaping")]
+                bytes: shared,
+            })
+            .ok_or("Could not load font from bytes")
+    }
+
+    // Returns the unique id of the font.
+    pub(crate) fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Returns `true` if the font contains a glyph for the given char.
+    pub fn has_glyph(&self, c: char) -> bool {
+        self.inner.glyph(c).id().0 != 0
+    }
 
-        // Glyphs to draw for "RustType". Feel free to try other strings.
-        let glyphs: Vec<rusttype::PositionedGlyph> =
-            self.inner.layout(text, scale, offset).collect();
+    pub fn measure_text(&self, text: &str, size: f64) -> (f64, f64) {
+        let pixel_height = size.ceil();
 
-        let width = glyphs
+        let width = self
+            .layout(text, size)
             .iter()
-            .rev()
-            .map(|g| g.position().x as f32 + g.unpositioned().h_metrics().advance_width)
-            .next()
-            .unwrap_or(0.0)
+            .map(|g| g.x + g.advance)
+            .fold(0.0, f32::max)
             .ceil() as f64;
 
         (width, pixel_height)
     }
 
+    /// Returns the positions of the cursor on the x axis in front of each char of the given
+    /// text and at its end. For right-to-left text the position in front of a char is on its
+    /// right side.
+    pub fn caret_positions(&self, text: &str, size: f64) -> Vec<f64> {
+        caret_positions(text, &self.layout(text, size))
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn render_text(
         &self,
         font_size: f64,
@@ -84,6 +176,31 @@ impl Font {
         pixmap: &mut Pixmap,
         position: (f64, f64),
         text: &str,
+        transform: Transform,
+        clip_mask: Option<&ClipMask>,
+    ) {
+        self.render_glyphs(
+            &self.layout(text, font_size),
+            font_size,
+            paint,
+            pixmap,
+            position,
+            transform,
+            clip_mask,
+        );
+    }
+
+    // Draws already laid out glyphs of this font.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn render_glyphs(
+        &self,
+        glyphs: &[Glyph],
+        font_size: f64,
+        paint: &Paint,
+        pixmap: &mut Pixmap,
+        position: (f64, f64),
+        transform: Transform,
+        clip_mask: Option<&ClipMask>,
     ) {
         let scale = rusttype::Scale::uniform(font_size as f32);
 
@@ -92,10 +209,19 @@ impl Font {
         // v_metrics.ascent is the distance between the baseline and the highest edge of any glyph in
         // the font. That's enough to guarantee that there's no clipping.
         let v_metrics = self.inner.v_metrics(scale);
-        let offset = rusttype::point(0.0, v_metrics.ascent);
 
-        let glyphs: Vec<rusttype::PositionedGlyph> =
-            self.inner.layout(text, scale, offset).collect();
+        let glyphs: Vec<rusttype::PositionedGlyph> = glyphs
+            .iter()
+            .map(|g| {
+                self.inner
+                    .glyph(g.id)
+                    .scaled(scale)
+                    .positioned(rusttype::point(
+                        g.x + g.offset.0,
+                        v_metrics.ascent + g.offset.1,
+                    ))
+            })
+            .collect();
 
         let mut glyph_tracer = GlyphTracer {
             path_builder: PathBuilder::new(),
@@ -114,7 +240,321 @@ impl Font {
             g.build_outline(&mut glyph_tracer);
         }
         if let Some(path) = glyph_tracer.path_builder.finish() {
-            pixmap.fill_path(&path, paint, FillRule::Winding, Transform::identity(), None);
+            pixmap.fill_path(&path, paint, FillRule::Winding, transform, clip_mask);
+        }
+    }
+
+    // Returns the distance between the base line and the top of the highest glyph of the font.
+    pub(crate) fn ascent(&self, font_size: f64) -> f32 {
+        self.inner
+            .v_metrics(rusttype::Scale::uniform(font_size as f32))
+            .ascent
+    }
+
+    /// Returns the metrics of the underline and the strikethrough in pixels for the given font
+    /// size.
+    pub fn decoration_metrics(&self, font_size: f64) -> TextDecorationMetrics {
+        // rusttype scales a font by the distance between its ascent and descent
+        let v_metrics = self.inner.v_metrics_unscaled();
+        let height = (v_metrics.ascent - v_metrics.descent) as f64;
+
+        if height <= 0.0 {
+            return TextDecorationMetrics::default();
+        }
+
+        let scale = font_size / height;
+
+        TextDecorationMetrics {
+            underline_position: self.decorations.underline_position * scale,
+            underline_thickness: self.decorations.underline_thickness * scale,
+            strikethrough_position: self.decorations.strikethrough_position * scale,
+            strikethrough_thickness: self.decorations.strikethrough_thickness * scale,
+        }
+    }
+
+    // Rasterizes the coverage of a glyph, whose pen position is moved by the given sub pixel
+    // offset. Returns `None` if the glyph has no outline like a space.
+    pub(crate) fn rasterize_glyph(
+        &self,
+        id: rusttype::GlyphId,
+        font_size: f64,
+        offset: (f32, f32),
+    ) -> Option<GlyphMask> {
+        let glyph = self
+            .inner
+            .glyph(id)
+            .scaled(rusttype::Scale::uniform(font_size as f32))
+            .positioned(rusttype::point(offset.0, offset.1));
+        let bounds = glyph.pixel_bounding_box()?;
+
+        let width = bounds.width() as u32;
+        let height = bounds.height() as u32;
+        let mut coverage = vec![0; (width * height) as usize];
+
+        glyph.draw(|x, y, v| {
+            coverage[(y * width + x) as usize] = (v.min(1.0) * 255.0).round() as u8;
+        });
+
+        Some(GlyphMask {
+            x: bounds.min.x,
+            y: bounds.min.y,
+            width,
+            height,
+            coverage,
+        })
+    }
+
+    // Lays out the glyphs of the given text in visual order.
+    #[cfg(not(feature = "shaping"))]
+    pub(crate) fn layout(&self, text: &str, size: f64) -> Vec<Glyph> {
+        let scale = rusttype::Scale::uniform(size as f32);
+
+        text.char_indices()
+            .zip(self.inner.layout(text, scale, rusttype::point(0.0, 0.0)))
+            .map(|((cluster, _), g)| Glyph {
+                id: g.id(),
+                x: g.position().x,
+                offset: (0.0, 0.0),
+                advance: g.unpositioned().h_metrics().advance_width,
+                cluster,
+                rtl: false,
+            })
+            .collect()
+    }
+
+    // Lays out a run of text with a single direction. Without shaping all text is laid out from
+    // left to right.
+    #[cfg(not(feature = "shaping"))]
+    pub(crate) fn layout_run(&self, text: &str, _rtl: bool, size: f64) -> Vec<Glyph> {
+        self.layout(text, size)
+    }
+
+    // Shapes the glyphs of the given text and orders them visual.
+    #[cfg(feature = "shaping")]
+    pub(crate) fn layout(&self, text: &str, size: f64) -> Vec<Glyph> {
+        super::shaping::shape(&self.bytes, text, self.shaping_scale(size))
+    }
+
+    // Shapes a run of text with a single direction.
+    #[cfg(feature = "shaping")]
+    pub(crate) fn layout_run(&self, text: &str, rtl: bool, size: f64) -> Vec<Glyph> {
+        super::shaping::shape_run(&self.bytes, text, rtl, self.shaping_scale(size))
+    }
+
+    // rusttype scales the font by its height, that is the distance between ascent and descent
+    #[cfg(feature = "shaping")]
+    fn shaping_scale(&self, size: f64) -> f32 {
+        let v_metrics = self.inner.v_metrics_unscaled();
+        size as f32 / (v_metrics.ascent - v_metrics.descent)
+    }
+}
+
+// -- Helpers --
+
+pub(crate) fn caret_positions(text: &str, glyphs: &[Glyph]) -> Vec<f64> {
+    // the horizontal extent of each cluster
+    let mut clusters: Vec<(usize, f32, f32, bool)> = vec![];
+
+    for glyph in glyphs {
+        // glyphs of the same cluster are adjacent
+        match clusters.last_mut().filter(|c| c.0 == glyph.cluster) {
+            Some(cluster) => {
+                cluster.1 = cluster.1.min(glyph.x);
+                cluster.2 = cluster.2.max(glyph.x + glyph.advance);
+            }
+            None => clusters.push((glyph.cluster, glyph.x, glyph.x + glyph.advance, glyph.rtl)),
         }
     }
+
+    clusters.sort_by_key(|c| c.0);
+
+    let chars: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
+    let mut positions = vec![0.0; chars.len() + 1];
+
+    for (index, &(start, left, right, rtl)) in clusters.iter().enumerate() {
+        let end = clusters.get(index + 1).map_or(text.len(), |c| c.0);
+
+        // chars of a cluster like a ligature share its extent
+        let first = chars
+            .iter()
+            .position(|c| *c >= start)
+            .unwrap_or(chars.len());
+        let count = chars[first..].iter().take_while(|c| **c < end).count();
+
+        for i in 0..=count {
+            let fraction = i as f32 / count.max(1) as f32;
+            let x = if rtl {
+                right - (right - left) * fraction
+            } else {
+                left + (right - left) * fraction
+            };
+
+            // the end of a cluster is only used for the end of the text
+            if i < count || first + count == chars.len() {
+                positions[first + i] = x as f64;
+            }
+        }
+    }
+
+    positions
+}
+
+// Reads the decoration metrics of the `post` and the `OS/2` table of the given font file. Missing
+// values are zero and replaced by defaults if `units_per_em` is set.
+fn read_decoration_metrics(data: &[u8], units_per_em: u16) -> TextDecorationMetrics {
+    let post = find_table(data, b"post");
+    let os2 = find_table(data, b"OS/2");
+    let value = |table: Option<&[u8]>, offset: usize| {
+        table.and_then(|t| read_i16(t, offset)).unwrap_or(0) as f64
+    };
+
+    fallback_decoration_metrics(
+        TextDecorationMetrics {
+            underline_position: value(post, 8),
+            underline_thickness: value(post, 10),
+            strikethrough_position: value(os2, 28),
+            strikethrough_thickness: value(os2, 26),
+        },
+        units_per_em,
+    )
+}
+
+// Replaces missing decoration metrics by values that fit most fonts.
+fn fallback_decoration_metrics(
+    mut metrics: TextDecorationMetrics,
+    units_per_em: u16,
+) -> TextDecorationMetrics {
+    if units_per_em == 0 {
+        return metrics;
+    }
+
+    let em = units_per_em as f64;
+
+    if metrics.underline_thickness <= 0.0 {
+        metrics.underline_thickness = em * 0.05;
+    }
+
+    if metrics.underline_position == 0.0 {
+        metrics.underline_position = -em * 0.1;
+    }
+
+    if metrics.strikethrough_thickness <= 0.0 {
+        metrics.strikethrough_thickness = metrics.underline_thickness;
+    }
+
+    if metrics.strikethrough_position == 0.0 {
+        metrics.strikethrough_position = em * 0.3;
+    }
+
+    metrics
+}
+
+// Returns the data of the table with the given tag of a font file or the first font of a font
+// collection file.
+fn find_table<'a>(data: &'a [u8], tag: &[u8; 4]) -> Option<&'a [u8]> {
+    let base = if data.get(0..4)? == b"ttcf" {
+        read_u32(data, 12)? as usize
+    } else {
+        0
+    };
+
+    let count = read_u16(data, base + 4)? as usize;
+
+    (0..count).find_map(|index| {
+        let record = base + 12 + index * 16;
+
+        if data.get(record..record + 4)? != tag {
+            return None;
+        }
+
+        let offset = read_u32(data, record + 8)? as usize;
+        let length = read_u32(data, record + 12)? as usize;
+
+        data.get(offset..offset.checked_add(length)?)
+    })
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    data.get(offset..offset + 2)
+        .map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_i16(data: &[u8], offset: usize) -> Option<i16> {
+    read_u16(data, offset).map(|value| value as i16)
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    data.get(offset..offset + 4)
+        .map(|bytes| u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+// -- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glyph(x: f32, cluster: usize, rtl: bool) -> Glyph {
+        Glyph {
+            id: rusttype::GlyphId(0),
+            x,
+            offset: (0.0, 0.0),
+            advance: 10.0,
+            cluster,
+            rtl,
+        }
+    }
+
+    #[test]
+    fn test_caret_positions() {
+        let glyphs = vec![glyph(0.0, 0, false), glyph(10.0, 1, false)];
+        assert_eq!(caret_positions("ab", &glyphs), vec![0.0, 10.0, 20.0]);
+
+        // right-to-left text is ordered visual
+        let glyphs = vec![glyph(0.0, 2, true), glyph(10.0, 0, true)];
+        assert_eq!(caret_positions("אב", &glyphs), vec![20.0, 10.0, 0.0]);
+
+        // ligature of two chars
+        let glyphs = vec![glyph(0.0, 0, false)];
+        assert_eq!(caret_positions("fi", &glyphs), vec![0.0, 5.0, 10.0]);
+
+        assert_eq!(caret_positions("", &[]), vec![0.0]);
+    }
+
+    #[test]
+    fn test_read_decoration_metrics() {
+        // font file with a table directory of the post and the OS/2 table
+        let mut data = vec![0, 1, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0];
+        data.extend_from_slice(b"post");
+        data.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 12]);
+        data.extend_from_slice(b"OS/2");
+        data.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 56, 0, 0, 0, 30]);
+
+        // post: underline position -150, thickness 50
+        data.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0x6a, 0, 50]);
+        // OS/2: strikethrough thickness 60, position 300
+        data.extend_from_slice(&[0; 26]);
+        data.extend_from_slice(&[0, 60, 1, 44]);
+
+        assert_eq!(
+            read_decoration_metrics(&data, 1000),
+            TextDecorationMetrics {
+                underline_position: -150.0,
+                underline_thickness: 50.0,
+                strikethrough_position: 300.0,
+                strikethrough_thickness: 60.0,
+            }
+        );
+
+        // without tables the defaults are used
+        assert_eq!(
+            read_decoration_metrics(&[0, 1, 0, 0], 1000),
+            TextDecorationMetrics {
+                underline_position: -100.0,
+                underline_thickness: 50.0,
+                strikethrough_position: 300.0,
+                strikethrough_thickness: 50.0,
+            }
+        );
+    }
 }
@@ -0,0 +1,685 @@
+use std::{collections::HashMap, fs, ops::Range, path::PathBuf};
+
+use tiny_skia::{ClipMask, Paint, Pixmap, Shader, Transform};
+
+use super::{
+    font::{caret_positions, Glyph},
+    glyph_cache::{draw_glyph_masks, subpixel_position},
+    Font, GlyphCache, GlyphKey, TextDecorationMetrics, GLYPH_SUBPIXEL_STEPS,
+};
+use crate::utils::{FontStyle, FontWeight};
+
+/// Describes where a font is loaded from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FontSource {
+    /// Font file that is embedded in the binary.
+    Bytes(&'static [u8]),
+
+    /// Path of a font file on disk.
+    Path(PathBuf),
+
+    /// Name of a font file (without extension) that is searched in the font directories of the
+    /// system, e.g. `DejaVuSans` or `NotoColorEmoji`.
+    System(String),
+}
+
+impl FontSource {
+    /// Loads the font. Returns `None` if the font could not be found or read.
+    pub fn load(&self) -> Option<Font> {
+        match self {
+            FontSource::Bytes(bytes) => Font::from_bytes(bytes).ok(),
+            FontSource::Path(path) => fs::read(path)
+                .ok()
+                .and_then(|bytes| Font::from_vec(bytes).ok()),
+            FontSource::System(name) => find_system_font(name)
+                .and_then(|path| fs::read(path).ok())
+                .and_then(|bytes| Font::from_vec(bytes).ok()),
+        }
+    }
+
+    // Embedded fonts are compared by address, comparing the content of the files is too expensive.
+    fn is_same(&self, other: &FontSource) -> bool {
+        match (self, other) {
+            (FontSource::Bytes(a), FontSource::Bytes(b)) => std::ptr::eq(*a, *b),
+            _ => self == other,
+        }
+    }
+}
+
+impl From<&'static [u8]> for FontSource {
+    fn from(bytes: &'static [u8]) -> Self {
+        FontSource::Bytes(bytes)
+    }
+}
+
+impl From<PathBuf> for FontSource {
+    fn from(path: PathBuf) -> Self {
+        FontSource::Path(path)
+    }
+}
+
+/// Assigns the registered family `face` to a font family as face with the given weight and
+/// style, e.g. `Roboto-Bold` as bold face of `Roboto`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FontFace {
+    pub family: String,
+    pub weight: FontWeight,
+    pub style: FontStyle,
+    pub face: String,
+}
+
+impl FontFace {
+    /// Creates a new font face.
+    pub fn new(
+        family: impl Into<String>,
+        weight: FontWeight,
+        style: FontStyle,
+        face: impl Into<String>,
+    ) -> Self {
+        FontFace {
+            family: family.into(),
+            weight,
+            style,
+            face: face.into(),
+        }
+    }
+}
+
+/// The `FontCollection` stores the fonts of the render context by family. Each family has an
+/// ordered chain of fonts. If a char is not part of the first font of the chain, the next font
+/// that contains it is used, e.g. an emoji or CJK font.
+///
+/// Text with a family that is not registered is drawn with the first registered family.
+///
+/// A family can be split in faces of different weights and styles, e.g. `Roboto` in
+/// `Roboto-Regular` and `Roboto-Bold`. Each face is a registered family itself.
+///
+/// Rasterized glyphs are stored in a `GlyphCache` and reused on the next frames.
+#[derive(Debug, Default, Clone)]
+pub struct FontCollection {
+    families: HashMap<String, Vec<(FontSource, Font)>>,
+    faces: HashMap<String, Vec<(FontWeight, FontStyle, String)>>,
+    default_family: Option<String>,
+    glyph_cache: GlyphCache,
+}
+
+impl FontCollection {
+    /// Creates a new empty font collection.
+    pub fn new() -> Self {
+        FontCollection::default()
+    }
+
+    /// Adds a font to the fallback chain of the given family. A font that is already part of the
+    /// chain is ignored. Returns `false` if the font could not be loaded.
+    pub fn register(&mut self, family: &str, source: impl Into<FontSource>) -> bool {
+        let source = source.into();
+
+        if self
+            .families
+            .get(family)
+            .is_some_and(|fonts| fonts.iter().any(|(s, _)| s.is_same(&source)))
+        {
+            return true;
+        }
+
+        let font = match source.load() {
+            Some(font) => font,
+            None => return false,
+        };
+
+        self.families
+            .entry(family.to_string())
+            .or_default()
+            .push((source, font));
+
+        if self.default_family.is_none() {
+            self.default_family = Some(family.to_string());
+        }
+
+        true
+    }
+
+    /// Registers the family `face` as face of `family` with the given weight and style.
+    pub fn register_face(
+        &mut self,
+        family: &str,
+        weight: FontWeight,
+        style: FontStyle,
+        face: &str,
+    ) {
+        let faces = self.faces.entry(family.to_string()).or_default();
+        faces.retain(|(w, s, _)| *w != weight || *s != style);
+        faces.push((weight, style, face.to_string()));
+    }
+
+    /// Returns the face of the family that matches the given weight and style best. `family`
+    /// could also be a face, then the matching face of its family is returned, e.g. `Roboto-Bold`
+    /// for `Roboto-Regular` and bold.
+    ///
+    /// With normal weight and style of a registered family or without registered faces `family`
+    /// is returned unchanged. A family that is only registered by its faces returns its normal
+    /// face, e.g. `Roboto-Regular` for `Roboto`.
+    pub fn face<'a>(&'a self, family: &'a str, weight: FontWeight, style: FontStyle) -> &'a str {
+        if weight == FontWeight::Normal
+            && style == FontStyle::Normal
+            && self.families.contains_key(family)
+        {
+            return family;
+        }
+
+        let faces = self.faces.get(family).or_else(|| {
+            self.faces
+                .values()
+                .find(|faces| faces.iter().any(|(_, _, face)| face == family))
+        });
+
+        faces
+            .and_then(|faces| best_face(faces, weight, style))
+            .unwrap_or(family)
+    }
+
+    /// Returns `true` if at least one font is registered for the given family.
+    pub fn contains(&self, family: &str) -> bool {
+        self.families.contains_key(family)
+    }
+
+    /// Returns the fonts of the given family in fallback order. If the family is not registered,
+    /// the fonts of the default family are returned.
+    pub fn fonts(&self, family: &str) -> Vec<&Font> {
+        family_fonts(&self.families, self.default_family.as_deref(), family)
+    }
+
+    /// Returns the cache of the rasterized glyphs.
+    pub fn glyph_cache(&self) -> &GlyphCache {
+        &self.glyph_cache
+    }
+
+    /// Returns a mutable reference to the cache of the rasterized glyphs.
+    pub fn glyph_cache_mut(&mut self) -> &mut GlyphCache {
+        &mut self.glyph_cache
+    }
+
+    /// Measures the width and height of the given text.
+    pub fn measure_text(&self, family: &str, text: &str, size: f64) -> (f64, f64) {
+        let fonts = self.fonts(family);
+
+        if fonts.is_empty() {
+            return (0.0, 0.0);
+        }
+
+        let width = layout(&fonts, text, size)
+            .iter()
+            .flat_map(|(_, glyphs)| glyphs.iter())
+            .map(|g| g.x + g.advance)
+            .fold(0.0, f32::max)
+            .ceil() as f64;
+
+        (width, size.ceil())
+    }
+
+    /// Returns the distance between the top of the text and its base line.
+    pub fn ascent(&self, family: &str, size: f64) -> f64 {
+        self.fonts(family)
+            .first()
+            .map_or(0.0, |font| font.ascent(size) as f64)
+    }
+
+    /// Returns the metrics of the underline and the strikethrough of the given family.
+    pub fn decoration_metrics(&self, family: &str, size: f64) -> TextDecorationMetrics {
+        self.fonts(family)
+            .first()
+            .map_or(TextDecorationMetrics::default(), |font| {
+                font.decoration_metrics(size)
+            })
+    }
+
+    /// Returns the positions of the cursor on the x axis in front of each char of the given
+    /// text and at its end.
+    pub fn caret_positions(&self, family: &str, text: &str, size: f64) -> Vec<f64> {
+        let fonts = self.fonts(family);
+        let glyphs: Vec<Glyph> = layout(&fonts, text, size)
+            .into_iter()
+            .flat_map(|(_, glyphs)| glyphs)
+            .collect();
+
+        caret_positions(text, &glyphs)
+    }
+
+    /// Draws the given text. Each run of chars is drawn with the first font of the chain that
+    /// contains them.
+    ///
+    /// Text in a solid color is drawn with the cached glyphs, text with a gradient or a scaled,
+    /// skewed or rotated transform is filled as outline.
+    #[allow(clippy::too_many_arguments)]
+    pub fn render_text(
+        &mut self,
+        family: &str,
+        font_size: f64,
+        paint: &Paint,
+        pixmap: &mut Pixmap,
+        position: (f64, f64),
+        text: &str,
+        transform: Transform,
+        clip_mask: Option<&ClipMask>,
+    ) {
+        let fonts = family_fonts(&self.families, self.default_family.as_deref(), family);
+        let runs = layout(&fonts, text, font_size);
+
+        let color = match paint.shader {
+            Shader::SolidColor(color) if !transform.has_scale() && !transform.has_skew() => color,
+            _ => {
+                for (index, glyphs) in runs {
+                    fonts[index].render_glyphs(
+                        &glyphs, font_size, paint, pixmap, position, transform, clip_mask,
+                    );
+                }
+                return;
+            }
+        };
+
+        let mut origin = [tiny_skia::Point::from_xy(
+            position.0 as f32,
+            position.1 as f32,
+        )];
+        transform.map_points(&mut origin);
+
+        let steps = GLYPH_SUBPIXEL_STEPS as f32;
+        let mut glyphs = vec![];
+        self.glyph_cache.begin();
+
+        for (index, run) in runs {
+            let font = fonts[index];
+            let ascent = font.ascent(font_size);
+
+            for glyph in run {
+                let (x, subpixel_x) = subpixel_position(origin[0].x + glyph.x + glyph.offset.0);
+                let (y, subpixel_y) = subpixel_position(origin[0].y + ascent + glyph.offset.1);
+                let key = GlyphKey::new(font.id(), font_size, glyph.id.0, (subpixel_x, subpixel_y));
+
+                self.glyph_cache.get_or_insert(key, || {
+                    font.rasterize_glyph(
+                        glyph.id,
+                        font_size,
+                        (subpixel_x as f32 / steps, subpixel_y as f32 / steps),
+                    )
+                });
+                glyphs.push((key, x, y));
+            }
+        }
+
+        draw_glyph_masks(
+            &self.glyph_cache,
+            &glyphs,
+            color,
+            paint.blend_mode,
+            pixmap,
+            clip_mask,
+        );
+    }
+}
+
+// -- Helpers --
+
+fn family_fonts<'a>(
+    families: &'a HashMap<String, Vec<(FontSource, Font)>>,
+    default_family: Option<&str>,
+    family: &str,
+) -> Vec<&'a Font> {
+    families
+        .get(family)
+        .or_else(|| default_family.and_then(|family| families.get(family)))
+        .map_or_else(Vec::new, |fonts| {
+            fonts.iter().map(|(_, font)| font).collect()
+        })
+}
+
+// Selects the face with the requested style and the nearest weight. If two weights are equally
+// near, the bolder face is used.
+fn best_face(
+    faces: &[(FontWeight, FontStyle, String)],
+    weight: FontWeight,
+    style: FontStyle,
+) -> Option<&str> {
+    // italic and oblique faces replace each other before a normal face is used
+    let style_rank = |s: FontStyle| match (style, s) {
+        (a, b) if a == b => 0,
+        (FontStyle::Normal, _) | (_, FontStyle::Normal) => 2,
+        _ => 1,
+    };
+
+    faces
+        .iter()
+        .min_by_key(|(w, s, _)| {
+            (
+                style_rank(*s),
+                (w.value() as i32 - weight.value() as i32).abs(),
+                *w < weight,
+            )
+        })
+        .map(|(_, _, face)| face.as_str())
+}
+
+// Lays out the runs of the text in visual order, each with the index of its font in the chain. The
+// direction of the text is resolved once for the whole text, the runs of each direction are split
+// by font afterwards.
+fn layout(fonts: &[&Font], text: &str, size: f64) -> Vec<(usize, Vec<Glyph>)> {
+    let mut runs = vec![];
+    let mut x = 0.0;
+
+    for (direction_range, rtl) in direction_runs(text) {
+        let mut font_runs = font_runs(&text[direction_range.clone()], fonts.len(), |index, c| {
+            fonts[index].has_glyph(c)
+        });
+
+        // the first font run of a right-to-left run is placed on the right
+        if rtl {
+            font_runs.reverse();
+        }
+
+        for (index, range) in font_runs {
+            let start = direction_range.start + range.start;
+            let mut glyphs = fonts[index].layout_run(&text[start..start + range.len()], rtl, size);
+            let width = glyphs.iter().map(|g| g.x + g.advance).fold(0.0, f32::max);
+
+            for glyph in &mut glyphs {
+                glyph.x += x;
+                glyph.cluster += start;
+            }
+
+            x += width;
+            runs.push((index, glyphs));
+        }
+    }
+
+    runs
+}
+
+// Splits the text in runs of the same direction in visual order.
+#[cfg(feature = "shaping")]
+fn direction_runs(text: &str) -> Vec<(Range<usize>, bool)> {
+    super::shaping::visual_runs(text)
+}
+
+// Without shaping the text is laid out from left to right.
+#[cfg(not(feature = "shaping"))]
+fn direction_runs(text: &str) -> Vec<(Range<usize>, bool)> {
+    if text.is_empty() {
+        return vec![];
+    }
+
+    vec![(0..text.len(), false)]
+}
+
+// Splits the text into runs of chars that are drawn with the same font. A run is continued as long
+// as its font contains the chars, so spaces and punctuation do not break it.
+fn font_runs(
+    text: &str,
+    font_count: usize,
+    has_glyph: impl Fn(usize, char) -> bool,
+) -> Vec<(usize, Range<usize>)> {
+    let mut runs: Vec<(usize, Range<usize>)> = vec![];
+
+    if font_count == 0 {
+        return runs;
+    }
+
+    for (i, c) in text.char_indices() {
+        let end = i + c.len_utf8();
+
+        if let Some(run) = runs.last_mut().filter(|run| has_glyph(run.0, c)) {
+            run.1.end = end;
+            continue;
+        }
+
+        // chars that are part of no font are drawn with the font of the current run
+        let index = (0..font_count)
+            .find(|index| has_glyph(*index, c))
+            .or_else(|| runs.last().map(|run| run.0))
+            .unwrap_or(0);
+
+        match runs.last_mut().filter(|run| run.0 == index) {
+            Some(run) => run.1.end = end,
+            None => runs.push((index, i..end)),
+        }
+    }
+
+    runs
+}
+
+/// Returns the directories that are searched for system fonts.
+pub fn system_font_directories() -> Vec<PathBuf> {
+    let mut directories = vec![];
+    let home = std::env::var_os("HOME").map(PathBuf::from);
+
+    if cfg!(target_os = "redox") {
+        directories.push(PathBuf::from("/ui/fonts"));
+    } else if cfg!(target_os = "macos") {
+        directories.push(PathBuf::from("/System/Library/Fonts"));
+        directories.push(PathBuf::from("/Library/Fonts"));
+
+        if let Some(home) = home {
+            directories.push(home.join("Library/Fonts"));
+        }
+    } else if cfg!(target_os = "windows") {
+        if let Some(windows) = std::env::var_os("WINDIR") {
+            directories.push(PathBuf::from(windows).join("Fonts"));
+        }
+    } else {
+        directories.push(PathBuf::from("/usr/share/fonts"));
+        directories.push(PathBuf::from("/usr/local/share/fonts"));
+
+        if let Some(home) = home {
+            directories.push(home.join(".local/share/fonts"));
+            directories.push(home.join(".fonts"));
+        }
+    }
+
+    directories
+}
+
+/// Searches the system font directories for a `ttf` or `otf` file with the given name (without
+/// extension). The name is compared case-insensitive.
+pub fn find_system_font(name: &str) -> Option<PathBuf> {
+    system_font_directories()
+        .iter()
+        .find_map(|directory| find_font_file(directory, name))
+}
+
+// Searches the directory and its sub directories for the font file.
+fn find_font_file(directory: &std::path::Path, name: &str) -> Option<PathBuf> {
+    let mut directories = vec![];
+
+    for entry in fs::read_dir(directory).ok()?.flatten() {
+        let path = entry.path();
+
+        if path.is_dir() {
+            directories.push(path);
+            continue;
+        }
+
+        let is_font = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| e.eq_ignore_ascii_case("ttf") || e.eq_ignore_ascii_case("otf"));
+        let is_name = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .is_some_and(|s| s.eq_ignore_ascii_case(name));
+
+        if is_font && is_name {
+            return Some(path);
+        }
+    }
+
+    directories
+        .iter()
+        .find_map(|directory| find_font_file(directory, name))
+}
+
+// -- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_font_runs() {
+        // font 0 contains latin chars, font 1 contains '☺'
+        let has_glyph = |index: usize, c: char| match index {
+            0 => c.is_ascii(),
+            _ => c == '☺' || c == ' ',
+        };
+
+        assert_eq!(font_runs("ab c", 2, has_glyph), vec![(0, 0..4)]);
+        assert_eq!(
+            font_runs("a ☺ b", 2, has_glyph),
+            vec![(0, 0..2), (1, 2..6), (0, 6..7)]
+        );
+
+        // chars of no font stay in the current run
+        assert_eq!(font_runs("aä", 2, has_glyph), vec![(0, 0..3)]);
+        assert_eq!(font_runs("a", 0, has_glyph), vec![]);
+    }
+
+    #[cfg(feature = "shaping")]
+    #[test]
+    fn test_layout_right_to_left() {
+        static MATERIAL_ICONS: &[u8] =
+            include_bytes!("../../../orbtk_widgets/assets/fonts/material/MaterialIcons.ttf");
+        static ROBOTO: &[u8] =
+            include_bytes!("../../../orbtk_widgets/assets/fonts/roboto/Roboto-Regular.ttf");
+
+        let material_icons = Font::from_bytes(MATERIAL_ICONS).unwrap();
+        let roboto = Font::from_bytes(ROBOTO).unwrap();
+
+        // the hebrew chars are part of no font, the first one is drawn with the first font of the
+        // chain, '.' and the last char with roboto. The whole text is one right-to-left run.
+        let runs = layout(&[&material_icons, &roboto], "\u{5d0}.\u{5d1}", 16.0);
+
+        let fonts: Vec<usize> = runs.iter().map(|(index, _)| *index).collect();
+        assert_eq!(fonts, vec![1, 0]);
+
+        let glyphs: Vec<&Glyph> = runs.iter().flat_map(|(_, glyphs)| glyphs).collect();
+        let clusters: Vec<usize> = glyphs.iter().map(|g| g.cluster).collect();
+        assert_eq!(clusters, vec![3, 2, 0]);
+        assert!(glyphs.iter().all(|g| g.rtl));
+
+        for pair in glyphs.windows(2) {
+            assert!((pair[0].x + pair[0].advance - pair[1].x).abs() < 0.001);
+        }
+    }
+
+    #[test]
+    fn test_find_font_file() {
+        let directory = std::env::temp_dir().join("orbtk_test_find_font_file");
+        let sub_directory = directory.join("truetype");
+        fs::create_dir_all(&sub_directory).unwrap();
+        fs::write(sub_directory.join("Roboto-Regular.TTF"), []).unwrap();
+        fs::write(directory.join("Roboto-Regular.txt"), []).unwrap();
+
+        assert_eq!(
+            find_font_file(&directory, "roboto-regular"),
+            Some(sub_directory.join("Roboto-Regular.TTF"))
+        );
+        assert_eq!(find_font_file(&directory, "Roboto"), None);
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_register_invalid_font() {
+        static INVALID: &[u8] = &[0, 1, 2, 3];
+
+        let mut collection = FontCollection::new();
+        assert!(!collection.register("Invalid", INVALID));
+        assert!(!collection.register("Invalid", PathBuf::from("/does/not/exist.ttf")));
+        assert!(!collection.contains("Invalid"));
+        assert!(collection.fonts("Invalid").is_empty());
+    }
+
+    #[test]
+    fn test_face() {
+        let mut collection = FontCollection::new();
+        collection.register_face(
+            "Roboto",
+            FontWeight::Normal,
+            FontStyle::Normal,
+            "Roboto-Regular",
+        );
+        collection.register_face(
+            "Roboto",
+            FontWeight::Medium,
+            FontStyle::Normal,
+            "Roboto-Medium",
+        );
+        collection.register_face("Roboto", FontWeight::Bold, FontStyle::Normal, "Roboto-Bold");
+        collection.register_face(
+            "Roboto",
+            FontWeight::Normal,
+            FontStyle::Italic,
+            "Roboto-Italic",
+        );
+
+        assert_eq!(
+            collection.face("Roboto", FontWeight::Bold, FontStyle::Normal),
+            "Roboto-Bold"
+        );
+        assert_eq!(
+            collection.face("Roboto", FontWeight::SemiBold, FontStyle::Normal),
+            "Roboto-Bold"
+        );
+        assert_eq!(
+            collection.face("Roboto-Regular", FontWeight::Medium, FontStyle::Normal),
+            "Roboto-Medium"
+        );
+        assert_eq!(
+            collection.face("Roboto", FontWeight::Bold, FontStyle::Oblique),
+            "Roboto-Italic"
+        );
+        assert_eq!(
+            collection.face("Unknown", FontWeight::Bold, FontStyle::Normal),
+            "Unknown"
+        );
+    }
+
+    #[test]
+    fn test_face_of_unregistered_family() {
+        static ROBOTO_REGULAR: &[u8] =
+            include_bytes!("../../../orbtk_widgets/assets/fonts/roboto/Roboto-Regular.ttf");
+        static ROBOTO_MEDIUM: &[u8] =
+            include_bytes!("../../../orbtk_widgets/assets/fonts/roboto/Roboto-Medium.ttf");
+
+        // `Roboto` itself has no fonts, only its faces are registered
+        let mut collection = FontCollection::new();
+        assert!(collection.register("Roboto-Regular", ROBOTO_REGULAR));
+        assert!(collection.register("Roboto-Medium", ROBOTO_MEDIUM));
+        collection.register_face(
+            "Roboto",
+            FontWeight::Normal,
+            FontStyle::Normal,
+            "Roboto-Regular",
+        );
+        collection.register_face(
+            "Roboto",
+            FontWeight::Medium,
+            FontStyle::Normal,
+            "Roboto-Medium",
+        );
+
+        assert!(!collection.contains("Roboto"));
+        assert_eq!(
+            collection.face("Roboto", FontWeight::Normal, FontStyle::Normal),
+            "Roboto-Regular"
+        );
+        assert_eq!(
+            collection.face("Roboto", FontWeight::Medium, FontStyle::Normal),
+            "Roboto-Medium"
+        );
+
+        // normal weight and style keep a registered face
+        assert_eq!(
+            collection.face("Roboto-Medium", FontWeight::Normal, FontStyle::Normal),
+            "Roboto-Medium"
+        );
+    }
+}
@@ -0,0 +1,346 @@
+use std::collections::HashMap;
+
+use tiny_skia::{BlendMode, ClipMask, Color, Pixmap, PixmapPaint, Transform};
+
+/// Default number of glyphs a `GlyphCache` can store.
+pub const DEFAULT_GLYPH_CACHE_CAPACITY: usize = 2048;
+
+/// Number of positions a glyph can have between two pixels on each axis.
+pub const GLYPH_SUBPIXEL_STEPS: u8 = 4;
+
+/// Identifies a rasterized glyph in the `GlyphCache`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct GlyphKey {
+    /// Unique id of the font.
+    pub font: usize,
+
+    /// Bits of the font size.
+    pub size: u64,
+
+    /// Id of the glyph inside of the font.
+    pub glyph: u16,
+
+    /// Sub pixel offset of the glyph in steps of `1 / GLYPH_SUBPIXEL_STEPS` pixel.
+    pub subpixel: (u8, u8),
+}
+
+impl GlyphKey {
+    /// Creates a new glyph key.
+    pub fn new(font: usize, size: f64, glyph: u16, subpixel: (u8, u8)) -> Self {
+        GlyphKey {
+            font,
+            size: size.to_bits(),
+            glyph,
+            subpixel,
+        }
+    }
+}
+
+/// The rasterized coverage of a glyph. `x` and `y` are the offset of the top left corner from the
+/// pen position.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlyphMask {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub coverage: Vec<u8>,
+}
+
+/// The `GlyphCache` stores rasterized glyphs, so text must not be rasterized on each frame again.
+///
+/// If the cache is full, the least recently used half of the glyphs is evicted.
+#[derive(Debug, Clone)]
+pub struct GlyphCache {
+    glyphs: HashMap<GlyphKey, (Option<GlyphMask>, u64)>,
+    capacity: usize,
+    tick: u64,
+}
+
+impl Default for GlyphCache {
+    fn default() -> Self {
+        GlyphCache::with_capacity(DEFAULT_GLYPH_CACHE_CAPACITY)
+    }
+}
+
+impl GlyphCache {
+    /// Creates a new glyph cache with the default capacity.
+    pub fn new() -> Self {
+        GlyphCache::default()
+    }
+
+    /// Creates a new glyph cache that stores up to `capacity` glyphs.
+    pub fn with_capacity(capacity: usize) -> Self {
+        GlyphCache {
+            glyphs: HashMap::new(),
+            capacity: capacity.max(1),
+            tick: 0,
+        }
+    }
+
+    /// Returns the number of glyphs the cache can store.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Sets the number of glyphs the cache can store.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity.max(1);
+
+        while self.glyphs.len() > self.capacity {
+            if !self.evict() {
+                break;
+            }
+        }
+    }
+
+    /// Returns the number of cached glyphs.
+    pub fn len(&self) -> usize {
+        self.glyphs.len()
+    }
+
+    /// Returns `true` if no glyph is cached.
+    pub fn is_empty(&self) -> bool {
+        self.glyphs.is_empty()
+    }
+
+    /// Removes all glyphs from the cache.
+    pub fn clear(&mut self) {
+        self.glyphs.clear();
+    }
+
+    /// Starts a new text. Glyphs of the current text are never evicted.
+    pub fn begin(&mut self) {
+        self.tick += 1;
+    }
+
+    /// Returns `true` if the glyph is cached.
+    pub fn contains(&self, key: &GlyphKey) -> bool {
+        self.glyphs.contains_key(key)
+    }
+
+    /// Returns the cached glyph or rasterizes and caches it with `rasterize`. Returns `None` for
+    /// glyphs without coverage.
+    pub fn get_or_insert(
+        &mut self,
+        key: GlyphKey,
+        rasterize: impl FnOnce() -> Option<GlyphMask>,
+    ) -> Option<&GlyphMask> {
+        if !self.glyphs.contains_key(&key) && self.glyphs.len() >= self.capacity {
+            self.evict();
+        }
+
+        let tick = self.tick;
+        let glyph = self
+            .glyphs
+            .entry(key)
+            .or_insert_with(|| (rasterize(), tick));
+        glyph.1 = tick;
+
+        glyph.0.as_ref()
+    }
+
+    /// Returns the cached glyph without marking it as used.
+    pub fn get(&self, key: &GlyphKey) -> Option<&GlyphMask> {
+        self.glyphs.get(key).and_then(|glyph| glyph.0.as_ref())
+    }
+
+    // Removes the least recently used half of the glyphs. Returns `false` if nothing could be
+    // removed.
+    fn evict(&mut self) -> bool {
+        let mut ticks: Vec<u64> = self.glyphs.values().map(|glyph| glyph.1).collect();
+        ticks.sort_unstable();
+
+        let threshold = match ticks.get(ticks.len().saturating_sub(1) / 2) {
+            Some(threshold) => *threshold,
+            None => return false,
+        };
+        let tick = self.tick;
+        let len = self.glyphs.len();
+
+        self.glyphs
+            .retain(|_, glyph| glyph.1 > threshold || glyph.1 == tick);
+
+        self.glyphs.len() < len
+    }
+}
+
+// -- Helpers --
+
+// Splits a pen position in the pixel and the sub pixel step.
+pub(crate) fn subpixel_position(position: f32) -> (i32, u8) {
+    let steps = GLYPH_SUBPIXEL_STEPS as f32;
+    let position = (position * steps).round() / steps;
+    let pixel = position.floor();
+
+    (
+        pixel as i32,
+        ((position - pixel) * steps).round() as u8 % GLYPH_SUBPIXEL_STEPS,
+    )
+}
+
+// Draws the cached glyphs at the given pixel positions in one solid color.
+pub(crate) fn draw_glyph_masks(
+    cache: &GlyphCache,
+    glyphs: &[(GlyphKey, i32, i32)],
+    color: Color,
+    blend_mode: BlendMode,
+    pixmap: &mut Pixmap,
+    clip_mask: Option<&ClipMask>,
+) {
+    let masks: Vec<(i32, i32, &GlyphMask)> = glyphs
+        .iter()
+        .filter_map(|(key, x, y)| cache.get(key).map(|mask| (x + mask.x, y + mask.y, mask)))
+        .collect();
+
+    let left = masks.iter().map(|(x, _, _)| *x).min();
+    let top = masks.iter().map(|(_, y, _)| *y).min();
+    let (left, top) = match (left, top) {
+        (Some(left), Some(top)) => (left, top),
+        _ => return,
+    };
+    let right = masks
+        .iter()
+        .map(|(x, _, mask)| x + mask.width as i32)
+        .max()
+        .unwrap_or(left);
+    let bottom = masks
+        .iter()
+        .map(|(_, y, mask)| y + mask.height as i32)
+        .max()
+        .unwrap_or(top);
+
+    let width = (right - left) as u32;
+    let height = (bottom - top) as u32;
+    let mut text = match Pixmap::new(width, height) {
+        Some(text) => text,
+        None => return,
+    };
+
+    // overlapping glyphs keep the higher coverage
+    let mut coverage = vec![0_u8; (width * height) as usize];
+    for (x, y, mask) in masks {
+        for row in 0..mask.height {
+            let source = (row * mask.width) as usize;
+            let target = ((y - top) as u32 + row) * width + (x - left) as u32;
+
+            for column in 0..mask.width as usize {
+                let value = &mut coverage[target as usize + column];
+                *value = (*value).max(mask.coverage[source + column]);
+            }
+        }
+    }
+
+    let color = color.premultiply().to_color_u8();
+    for (pixel, value) in text.data_mut().chunks_exact_mut(4).zip(coverage) {
+        let value = value as u16;
+        pixel[0] = (color.red() as u16 * value / 255) as u8;
+        pixel[1] = (color.green() as u16 * value / 255) as u8;
+        pixel[2] = (color.blue() as u16 * value / 255) as u8;
+        pixel[3] = (color.alpha() as u16 * value / 255) as u8;
+    }
+
+    pixmap.draw_pixmap(
+        left,
+        top,
+        text.as_ref(),
+        &PixmapPaint {
+            blend_mode,
+            ..Default::default()
+        },
+        Transform::identity(),
+        clip_mask,
+    );
+}
+
+// -- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mask() -> Option<GlyphMask> {
+        Some(GlyphMask {
+            x: 0,
+            y: -1,
+            width: 1,
+            height: 1,
+            coverage: vec![255],
+        })
+    }
+
+    #[test]
+    fn test_get_or_insert() {
+        let mut cache = GlyphCache::new();
+        let key = GlyphKey::new(0, 12.0, 1, (0, 0));
+
+        assert!(cache.get_or_insert(key, mask).is_some());
+        assert!(cache.contains(&key));
+
+        // cached glyphs are not rasterized again
+        assert_eq!(cache.get_or_insert(key, || None), mask().as_ref());
+
+        // glyphs without coverage are cached too
+        let space = GlyphKey::new(0, 12.0, 2, (0, 0));
+        assert!(cache.get_or_insert(space, || None).is_none());
+        assert!(cache.contains(&space));
+        assert_eq!(cache.len(), 2);
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_eviction() {
+        let mut cache = GlyphCache::with_capacity(4);
+
+        for glyph in 0..4 {
+            cache.begin();
+            cache.get_or_insert(GlyphKey::new(0, 12.0, glyph, (0, 0)), mask);
+        }
+
+        // glyph 3 is the most recently used one
+        cache.begin();
+        cache.get_or_insert(GlyphKey::new(0, 12.0, 0, (0, 0)), mask);
+        cache.get_or_insert(GlyphKey::new(0, 12.0, 4, (0, 0)), mask);
+
+        assert!(cache.len() <= 4);
+        assert!(cache.contains(&GlyphKey::new(0, 12.0, 0, (0, 0))));
+        assert!(cache.contains(&GlyphKey::new(0, 12.0, 3, (0, 0))));
+        assert!(cache.contains(&GlyphKey::new(0, 12.0, 4, (0, 0))));
+        assert!(!cache.contains(&GlyphKey::new(0, 12.0, 1, (0, 0))));
+
+        // glyphs of the current text are not evicted
+        cache.set_capacity(1);
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn test_subpixel_position() {
+        assert_eq!(subpixel_position(10.0), (10, 0));
+        assert_eq!(subpixel_position(10.3), (10, 1));
+        assert_eq!(subpixel_position(10.5), (10, 2));
+        assert_eq!(subpixel_position(10.9), (11, 0));
+        assert_eq!(subpixel_position(-0.25), (-1, 3));
+    }
+
+    #[test]
+    fn test_draw_glyph_masks() {
+        let mut cache = GlyphCache::new();
+        let key = GlyphKey::new(0, 12.0, 1, (0, 0));
+        cache.get_or_insert(key, mask);
+
+        let mut pixmap = Pixmap::new(2, 2).unwrap();
+        draw_glyph_masks(
+            &cache,
+            &[(key, 1, 2)],
+            Color::from_rgba8(0, 0, 255, 255),
+            BlendMode::SourceOver,
+            &mut pixmap,
+            None,
+        );
+
+        assert_eq!(&pixmap.data()[12..16], &[0, 0, 255, 255]);
+        assert_eq!(&pixmap.data()[0..4], &[0, 0, 0, 0]);
+    }
+}
@@ -1,6 +1,5 @@
 use smallvec::SmallVec;
 use std::{
-    collections::HashMap,
     f64::consts::{FRAC_PI_2, PI},
     ptr,
 };
@@ -8,20 +7,33 @@ use tiny_skia::{
     ClipMask, FillRule, Paint, PathBuilder, Pixmap, PixmapPaint, Shader, Stroke, Transform,
 };
 
-use crate::{common::*, utils::*, PipelineTrait, RenderConfig, RenderTarget, TextMetrics};
+use crate::{
+    common::*, utils::*, LineCap, LineJoin, PipelineTrait, RenderConfig, RenderTarget, TextMetrics,
+};
 
 pub use self::font::*;
+pub use self::font_collection::*;
+pub use self::glyph_cache::*;
 pub use self::image::Image;
+pub use self::text_layout::*;
 
 mod font;
+mod font_collection;
+mod glyph_cache;
 mod image;
+#[cfg(feature = "shaping")]
+mod shaping;
+mod text_layout;
+
+// Fonts are drawn larger than the font size that is set by `set_font_size`.
+const FONT_SIZE_OFFSET: f64 = 4.0;
 
 #[derive(Debug)]
 struct State {
     config: RenderConfig,
     path_rect: PathRect,
     clips_count: usize,
-    //clip_mask: ClipMask,
+    clip_mask: Option<ClipMask>,
     transform: Transform,
 }
 
@@ -32,10 +44,12 @@ type StatesOnStack = [State; 2];
 pub struct RenderContext2D {
     background: Color,
     clips_count: usize,
-    //clip_mask: ClipMask,
+    clip_mask: Option<ClipMask>,
     config: RenderConfig,
+    damage: Option<Rectangle>,
     fill_paint: Paint<'static>,
-    fonts: HashMap<String, Font>,
+    fonts: FontCollection,
+    layers: Vec<Pixmap>,
     path_builder: PathBuilder,
     path_rect: PathRect,
     pixmap: Pixmap,
@@ -51,14 +65,16 @@ impl RenderContext2D {
         RenderContext2D {
             background: Color::default(),
             clips_count: 0,
-            //clip_mask: ClipMask::default(),
+            clip_mask: None,
             config: RenderConfig::default(),
+            damage: None,
             fill_paint: Self::paint_from_brush(
                 &Brush::default(),
                 Rectangle::new(Point::new(0.0, 0.0), Size::new(0.0, 0.0)),
                 1.0,
             ),
-            fonts: HashMap::new(),
+            fonts: FontCollection::new(),
+            layers: vec![],
             path_builder: PathBuilder::new(),
             path_rect: PathRect::new(None),
             pixmap,
@@ -132,6 +148,49 @@ impl RenderContext2D {
                 )
                 .unwrap_or(Shader::SolidColor(tiny_skia::Color::WHITE))
             }
+            Brush::Gradient(Gradient {
+                kind: GradientKind::Radial(coords),
+                stops,
+                repeat,
+            }) => {
+                let spread = match repeat {
+                    true => tiny_skia::SpreadMode::Repeat,
+                    false => tiny_skia::SpreadMode::Pad,
+                };
+                let center = coords.center.pixels(frame.size());
+                let radius = radial_gradient_radius(coords.radius, center, frame.size());
+                let focal = match coords.focal {
+                    Some(focal) => focal.pixels(frame.size()),
+                    None => center,
+                } + frame.position();
+                let center = center + frame.position();
+                let g_stops = build_unit_percent_gradient(stops, radius, |p, c| {
+                    let mut color = tiny_skia::Color::from_rgba8(c.b(), c.g(), c.r(), c.a());
+                    color.set_alpha(color.alpha() * global_alpha);
+                    tiny_skia::GradientStop::new(p as f32, color)
+                });
+                tiny_skia::RadialGradient::new(
+                    tiny_skia::Point::from_xy(focal.x() as f32, focal.y() as f32),
+                    tiny_skia::Point::from_xy(center.x() as f32, center.y() as f32),
+                    radius as f32,
+                    g_stops,
+                    spread,
+                    tiny_skia::Transform::identity(),
+                )
+                .unwrap_or(Shader::SolidColor(tiny_skia::Color::WHITE))
+            }
+            // conic gradients are drawn with a pattern from `conic_gradient_pixmap`, the first
+            // color is only used as fallback.
+            Brush::Gradient(Gradient {
+                kind: GradientKind::Conic(_),
+                stops,
+                ..
+            }) => {
+                let c = stops.first().map(|s| s.color).unwrap_or_default();
+                let mut color = tiny_skia::Color::from_rgba8(c.b(), c.g(), c.r(), c.a());
+                color.set_alpha(color.alpha() * global_alpha);
+                Shader::SolidColor(color)
+            }
         };
         Paint {
             shader,
@@ -140,20 +199,139 @@ impl RenderContext2D {
         }
     }
 
-    /// Registers a new font file.
-    pub fn register_font(&mut self, family: &str, font_file: &'static [u8]) {
-        if self.fonts.contains_key(family) {
-            return;
+    // tiny-skia has no conic gradient shader, so the gradient is rendered into a pixmap that covers the
+    // frame. The pixmap is used as pattern of the paint.
+    fn conic_gradient_pixmap(
+        brush: &Brush,
+        frame: Rectangle,
+        global_alpha: f32,
+    ) -> Option<(Pixmap, Transform)> {
+        let (coords, stops, repeat) = match brush {
+            Brush::Gradient(Gradient {
+                kind: GradientKind::Conic(coords),
+                stops,
+                repeat,
+            }) => (coords, stops, *repeat),
+            _ => return None,
+        };
+
+        let x = frame.x().floor();
+        let y = frame.y().floor();
+        let mut pixmap = Pixmap::new(
+            (frame.x() + frame.width() - x).ceil().max(1.0) as u32,
+            (frame.y() + frame.height() - y).ceil().max(1.0) as u32,
+        )?;
+        let center = coords.center.pixels(frame.size()) + frame.position() - Point::new(x, y);
+        let stops = build_unit_percent_gradient(stops, 1.0, |p, c| (p, c));
+        let width = pixmap.width() as usize;
+
+        for (i, pixel) in pixmap.data_mut().chunks_exact_mut(4).enumerate() {
+            let dx = (i % width) as f64 + 0.5 - center.x();
+            let dy = (i / width) as f64 + 0.5 - center.y();
+            let color =
+                conic_gradient_color(&stops, dx.atan2(-dy) - coords.angle.to_radians(), repeat);
+            let a = color.a() as f32 * global_alpha;
+            let premultiply = |c: u8| (c as f32 * a / 255.0).round() as u8;
+            pixel.copy_from_slice(&[
+                premultiply(color.b()),
+                premultiply(color.g()),
+                premultiply(color.r()),
+                a.round() as u8,
+            ]);
         }
 
-        if let Ok(font) = Font::from_bytes(font_file) {
-            self.fonts.insert(family.to_string(), font);
+        Some((pixmap, Transform::from_translate(x as f32, y as f32)))
+    }
+
+    // Calls `draw` with the given paint or with a pattern paint if the brush is a conic gradient.
+    fn draw_with_brush<F>(
+        pixmap: &mut Pixmap,
+        paint: &Paint<'static>,
+        brush: &Brush,
+        frame: Rectangle,
+        global_alpha: f32,
+        draw: F,
+    ) where
+        F: FnOnce(&mut Pixmap, &Paint),
+    {
+        match Self::conic_gradient_pixmap(brush, frame, global_alpha) {
+            Some((pattern, transform)) => {
+                let paint = Paint {
+                    shader: tiny_skia::Pattern::new(
+                        pattern.as_ref(),
+                        tiny_skia::SpreadMode::Pad,
+                        tiny_skia::FilterQuality::Nearest,
+                        1.0,
+                        transform,
+                    ),
+                    anti_alias: true,
+                    ..Default::default()
+                };
+                draw(pixmap, &paint);
+            }
+            None => draw(pixmap, paint),
         }
     }
 
+    // Returns the face of the current font family that matches the current weight and style.
+    fn font_face(&self) -> &str {
+        let font_config = &self.config.font_config;
+        self.fonts
+            .face(&font_config.family, font_config.weight, font_config.style)
+    }
+
+    // Draws a text decoration line. `position` is the distance of the top of the line above the
+    // base line of the text at (x, y).
+    fn fill_decoration(&mut self, x: f64, y: f64, width: f64, position: f64, thickness: f64) {
+        let ascent = self
+            .fonts
+            .ascent(self.font_face(), self.config.font_config.font_size);
+
+        // lines are snapped to pixels to keep them sharp
+        let thickness = thickness.round().max(1.0);
+        let top = (y + ascent - position).round();
+
+        self.fill_rect(x, top, width, thickness);
+    }
+
+    /// Registers a new font. If the family has already fonts, the font is used as fallback for
+    /// chars the previous fonts of the family do not contain.
+    pub fn register_font(&mut self, family: &str, font: impl Into<FontSource>) {
+        self.fonts.register(family, font);
+    }
+
+    /// Registers a face of a font family. Text with the weight and style of the face is drawn
+    /// with it, e.g. `Roboto-Bold` for `Roboto` and bold.
+    pub fn register_font_face(&mut self, face: FontFace) {
+        self.fonts
+            .register_face(&face.family, face.weight, face.style, &face.face);
+    }
+
+    /// Removes all rasterized glyphs from the glyph cache, e.g. after the theme is changed.
+    pub fn clear_glyph_cache(&mut self) {
+        self.fonts.glyph_cache_mut().clear();
+    }
+
+    /// Sets the number of rasterized glyphs that are cached.
+    pub fn set_glyph_cache_capacity(&mut self, capacity: usize) {
+        self.fonts.glyph_cache_mut().set_capacity(capacity);
+    }
+
     /// Resizes pixmap with new height and width
     pub fn resize(&mut self, width: f64, height: f64) {
         self.pixmap = Pixmap::new(width as u32, height as u32).unwrap();
+        self.clip_mask = None;
+        self.damage = Some(Rectangle::new((0.0, 0.0), (self.width(), self.height())));
+    }
+
+    /// Gets the width of the pixmap.
+    pub fn width(&self) -> f64 {
+        self.pixmap.width() as f64
+    }
+
+    /// Gets the height of the pixmap.
+    pub fn height(&self) -> f64 {
+        self.pixmap.height() as f64
     }
 
     /// Set the background of the render context.
@@ -172,17 +350,28 @@ impl RenderContext2D {
             let rect = self.path_rect.get_rect().unwrap();
             self.fill_paint =
                 Self::paint_from_brush(&self.config.fill_style, rect, self.config.alpha as f32);
-            self.pixmap.fill_rect(
-                tiny_skia::Rect::from_xywh(
-                    (x as f32).floor(),
-                    (y as f32).floor(),
-                    width as f32,
-                    height as f32,
-                )
-                .unwrap(),
+            let transform = self.transform;
+            let clip_mask = self.clip_mask.as_ref();
+            Self::draw_with_brush(
+                &mut self.pixmap,
                 &self.fill_paint,
-                tiny_skia::Transform::identity(),
-                None,
+                &self.config.fill_style,
+                rect,
+                self.config.alpha,
+                |pixmap, paint| {
+                    pixmap.fill_rect(
+                        tiny_skia::Rect::from_xywh(
+                            (x as f32).floor(),
+                            (y as f32).floor(),
+                            width as f32,
+                            height as f32,
+                        )
+                        .unwrap(),
+                        paint,
+                        transform,
+                        clip_mask,
+                    );
+                },
             );
         }
     }
@@ -348,16 +537,29 @@ impl RenderContext2D {
     /// paths. Everything drawn after calling clip() will only act
     /// inside the clipping path.
     pub fn clip(&mut self) {
-        if let Some(clip_path) = self.path_builder.clone().finish() {
-            let mut clip_mask = ClipMask::new();
-            clip_mask.set_path(
-                self.pixmap.width() as u32,
-                self.pixmap.height() as u32,
-                &clip_path,
-                FillRule::EvenOdd,
-                true,
-            );
-            //self.clip_mask = clip_mask;
+        if let Some(clip_path) = self
+            .path_builder
+            .clone()
+            .finish()
+            .and_then(|path| path.transform(self.transform))
+        {
+            if let Some(clip_mask) = &mut self.clip_mask {
+                clip_mask.intersect_path(&clip_path, FillRule::EvenOdd, true);
+            } else {
+                let mut clip_mask = ClipMask::new();
+                if clip_mask
+                    .set_path(
+                        self.pixmap.width(),
+                        self.pixmap.height(),
+                        &clip_path,
+                        FillRule::EvenOdd,
+                        true,
+                    )
+                    .is_some()
+                {
+                    self.clip_mask = Some(clip_mask);
+                }
+            }
         }
         self.path_rect.record_clip();
         self.clips_count += 1;
@@ -387,8 +589,8 @@ impl RenderContext2D {
             y as i32,
             pixmap.as_ref(),
             &PixmapPaint::default(),
-            Transform::identity(),
-            None,
+            self.transform,
+            self.clip_mask.as_ref(),
         );
     }
 
@@ -422,11 +624,46 @@ impl RenderContext2D {
             y as i32,
             pixmap.as_ref(),
             &PixmapPaint::default(),
-            Transform::identity(),
-            None,
+            self.transform,
+            self.clip_mask.as_ref(),
         );
     }
 
+    /// Starts a layer. Until `end_layer` is called everything is drawn into a transparent
+    /// offscreen pixmap that covers the given area instead of the pixmap of the context. Layers
+    /// can be nested.
+    pub fn begin_layer(&mut self, x: f64, y: f64, width: f64, height: f64) {
+        let layer = match Pixmap::new(width.max(1.0) as u32, height.max(1.0) as u32) {
+            Some(layer) => layer,
+            None => return,
+        };
+
+        self.save();
+        self.layers.push(std::mem::replace(&mut self.pixmap, layer));
+        self.transform = Transform::from_translate(-x as f32, -y as f32).pre_concat(self.transform);
+        self.clip_mask = None;
+        self.clips_count = 0;
+    }
+
+    /// Finishes the current layer and returns what was drawn into it. The layer can be drawn
+    /// with `draw_render_target`. Returns an empty render target if no layer was started.
+    pub fn end_layer(&mut self) -> RenderTarget {
+        let layer = match self.layers.pop() {
+            Some(pixmap) => std::mem::replace(&mut self.pixmap, pixmap),
+            None => return RenderTarget::default(),
+        };
+
+        self.restore();
+
+        let data = layer
+            .data()
+            .chunks_exact(4)
+            .map(|pixel| u32::from_ne_bytes([pixel[0], pixel[1], pixel[2], pixel[3]]))
+            .collect();
+
+        RenderTarget::from_data(layer.width(), layer.height(), data).unwrap_or_default()
+    }
+
     /// Fills the current or given path with the current file style.
     pub fn fill(&mut self) {
         let rect = match self.path_rect.get_rect() {
@@ -436,35 +673,194 @@ impl RenderContext2D {
         self.fill_paint =
             Self::paint_from_brush(&self.config.fill_style, rect, self.config.alpha as f32);
         if let Some(path) = self.path_builder.clone().finish() {
-            self.pixmap.fill_path(
-                &path,
+            let transform = self.transform;
+            let clip_mask = self.clip_mask.as_ref();
+            Self::draw_with_brush(
+                &mut self.pixmap,
                 &self.fill_paint,
-                FillRule::EvenOdd,
-                Transform::identity(),
-                None,
+                &self.config.fill_style,
+                rect,
+                self.config.alpha,
+                |pixmap, paint| {
+                    pixmap.fill_path(&path, paint, FillRule::EvenOdd, transform, clip_mask);
+                },
             );
         }
     }
 
-    /// Draws (fills) a given text at the given (x, y) position.
+    /// Fills the current path as blurred shadow. The shadow is moved by `offset_x` and `offset_y`,
+    /// `blur` is the blur radius and the shadow is drawn in the given `color`.
+    pub fn fill_shadow(&mut self, offset_x: f64, offset_y: f64, blur: f64, color: Color) {
+        if color.a() == 0 {
+            return;
+        }
+
+        let path = match self.path_builder.clone().finish().and_then(|path| {
+            path.transform(
+                self.transform
+                    .pre_translate(offset_x as f32, offset_y as f32),
+            )
+        }) {
+            Some(path) => path,
+            None => return,
+        };
+
+        // the offscreen pixmap needs space around the path for the blurred edges
+        let padding = blur.max(0.0).ceil() as f32 + 1.0;
+        let bounds = path.bounds();
+        let x = (bounds.left() - padding).floor();
+        let y = (bounds.top() - padding).floor();
+        let width = (bounds.right() + padding).ceil() - x;
+        let height = (bounds.bottom() + padding).ceil() - y;
+
+        let mut shadow = match Pixmap::new(width as u32, height as u32) {
+            Some(pixmap) => pixmap,
+            None => return,
+        };
+
+        let paint = Paint {
+            shader: Shader::SolidColor(tiny_skia::Color::from_rgba8(
+                color.b(),
+                color.g(),
+                color.r(),
+                color.a(),
+            )),
+            anti_alias: true,
+            ..Default::default()
+        };
+
+        shadow.fill_path(
+            &path,
+            &paint,
+            FillRule::EvenOdd,
+            Transform::from_translate(-x, -y),
+            None,
+        );
+        blur_pixels(
+            shadow.data_mut(),
+            width as usize,
+            height as usize,
+            blur / 2.0,
+        );
+
+        self.pixmap.draw_pixmap(
+            x as i32,
+            y as i32,
+            shadow.as_ref(),
+            &PixmapPaint {
+                opacity: self.config.alpha,
+                ..Default::default()
+            },
+            Transform::identity(),
+            self.clip_mask.as_ref(),
+        );
+    }
+
+    /// Draws (fills) a given text at the given (x, y) position. Explicit newlines of the text
+    /// start a new line.
     pub fn fill_text(&mut self, text: &str, x: f64, y: f64) {
         if text.is_empty() {
             return;
         }
 
-        // TODO: multiline
+        if text.contains('\n') {
+            let layout = self.layout_text(text, None, self.config.font_config.font_size);
+            self.fill_text_layout(&layout, x, y, layout.width(), TextAlignment::Left);
+            return;
+        }
+
         let tm = self.measure_text(text);
         let rect = Rectangle::new(Point::new(x, y), Size::new(tm.width, tm.height));
         self.fill_paint =
             Self::paint_from_brush(&self.config.fill_style, rect, self.config.alpha as f32);
 
-        if let Some(font) = self.fonts.get(&self.config.font_config.family) {
-            font.render_text(
-                self.config.font_config.font_size,
-                &self.fill_paint,
-                &mut self.pixmap,
-                (x, y),
+        let family = self.font_face().to_string();
+        let fonts = &mut self.fonts;
+        let font_size = self.config.font_config.font_size;
+        let transform = self.transform;
+        let clip_mask = self.clip_mask.as_ref();
+        Self::draw_with_brush(
+            &mut self.pixmap,
+            &self.fill_paint,
+            &self.config.fill_style,
+            rect,
+            self.config.alpha,
+            |pixmap, paint| {
+                fonts.render_text(
+                    &family,
+                    font_size,
+                    paint,
+                    pixmap,
+                    (x, y),
+                    text,
+                    transform,
+                    clip_mask,
+                );
+            },
+        );
+    }
+
+    /// Lays out the given text with the current font. The text is broken into lines at explicit
+    /// newlines and if `max_width` is set, wrapped at word boundaries. `line_height` is the
+    /// distance between the tops of two lines in pixels.
+    pub fn layout_text(&self, text: &str, max_width: Option<f64>, line_height: f64) -> TextLayout {
+        let font_size = self.config.font_config.font_size;
+        let fonts = &self.fonts;
+        let family = self.font_face();
+
+        TextLayout::new(text, max_width, line_height, |text| {
+            fonts.measure_text(family, text, font_size)
+        })
+    }
+
+    /// Lays out the given runs of text, each with its own font family, size, weight and style. The runs are
+    /// wrapped like `layout_text` and share the base line of each line. `line_height` is the
+    /// distance between the tops of two lines in pixels.
+    pub fn layout_runs(
+        &self,
+        runs: &[TextRun],
+        max_width: Option<f64>,
+        line_height: f64,
+    ) -> RunLayout {
+        let texts: Vec<&str> = runs.iter().map(|run| run.text).collect();
+        let families: Vec<&str> = runs
+            .iter()
+            .map(|run| self.fonts.face(run.family, run.font_weight, run.font_style))
+            .collect();
+        let ascents: Vec<f64> = runs
+            .iter()
+            .zip(&families)
+            .map(|(run, family)| self.fonts.ascent(family, run.font_size + FONT_SIZE_OFFSET))
+            .collect();
+
+        RunLayout::new(&texts, &ascents, max_width, line_height, |index, text| {
+            self.fonts.measure_text(
+                families[index],
                 text,
+                runs[index].font_size + FONT_SIZE_OFFSET,
+            )
+        })
+    }
+
+    /// Draws (fills) the lines of the given text layout. The top left corner of the text is at the
+    /// given (x, y) position and each line is aligned inside of the given `width`.
+    pub fn fill_text_layout(
+        &mut self,
+        layout: &TextLayout,
+        x: f64,
+        y: f64,
+        width: f64,
+        alignment: TextAlignment,
+    ) {
+        for (i, line) in layout.lines().iter().enumerate() {
+            if line.text.is_empty() {
+                continue;
+            }
+
+            self.fill_text(
+                &line.text,
+                x + layout.line_x(i, width, alignment),
+                y + layout.line_y(i),
             );
         }
     }
@@ -496,16 +892,64 @@ impl RenderContext2D {
             return text_metrics;
         }
 
-        if let Some(font) = self.fonts.get(&self.config.font_config.family) {
-            let (width, height) = font.measure_text(text, self.config.font_config.font_size);
+        let (width, height) =
+            self.fonts
+                .measure_text(self.font_face(), text, self.config.font_config.font_size);
 
-            text_metrics.width = width;
-            text_metrics.height = height;
-        }
+        text_metrics.width = width;
+        text_metrics.height = height;
 
         text_metrics
     }
 
+    /// Returns the positions of the cursor on the x axis in front of each char of the given text
+    /// and at its end. Use it to hit test text, because with the `shaping` feature the chars of
+    /// right-to-left text are drawn from right to left.
+    pub fn measure_caret_positions(
+        &mut self,
+        text: &str,
+        font_size: f64,
+        family: impl Into<String>,
+    ) -> Vec<f64> {
+        self.set_font_family(family);
+        self.set_font_size(font_size);
+
+        self.fonts
+            .caret_positions(self.font_face(), text, self.config.font_config.font_size)
+    }
+
+    /// Returns the metrics of the underline and the strikethrough of the current font.
+    pub fn measure_text_decoration(&self) -> TextDecorationMetrics {
+        self.fonts
+            .decoration_metrics(self.font_face(), self.config.font_config.font_size)
+    }
+
+    /// Draws (fills) the underline of a text with the current font and fill style. (x, y) is the
+    /// position of the text like on `fill_text`.
+    pub fn fill_underline(&mut self, x: f64, y: f64, width: f64) {
+        let metrics = self.measure_text_decoration();
+        self.fill_decoration(
+            x,
+            y,
+            width,
+            metrics.underline_position,
+            metrics.underline_thickness,
+        );
+    }
+
+    /// Draws (fills) the strikethrough of a text with the current font and fill style. (x, y) is
+    /// the position of the text like on `fill_text`.
+    pub fn fill_strikethrough(&mut self, x: f64, y: f64, width: f64) {
+        let metrics = self.measure_text_decoration();
+        self.fill_decoration(
+            x,
+            y,
+            width,
+            metrics.strikethrough_position,
+            metrics.strikethrough_thickness,
+        );
+    }
+
     /// Begins a new sub-path at given `point`. The point is specified
     /// by given {x, y} coordinates.
     pub fn move_to(&mut self, x: f64, y: f64) {
@@ -529,15 +973,18 @@ impl RenderContext2D {
         self.stroke_paint =
             Self::paint_from_brush(&self.config.stroke_style, rect, self.config.alpha as f32);
         if let Some(path) = self.path_builder.clone().finish() {
-            self.pixmap.stroke_path(
-                &path,
+            let stroke = stroke_from_config(&self.config);
+            let transform = self.transform;
+            let clip_mask = self.clip_mask.as_ref();
+            Self::draw_with_brush(
+                &mut self.pixmap,
                 &self.stroke_paint,
-                &Stroke {
-                    width: self.config.line_width as f32,
-                    ..Default::default()
+                &self.config.stroke_style,
+                rect,
+                self.config.alpha,
+                |pixmap, paint| {
+                    pixmap.stroke_path(&path, paint, &stroke, transform, clip_mask);
                 },
-                Transform::identity(),
-                None,
             );
         }
     }
@@ -563,7 +1010,19 @@ impl RenderContext2D {
 
     /// Specifies the font size.
     pub fn set_font_size(&mut self, size: f64) {
-        self.config.font_config.font_size = size + 4.0;
+        self.config.font_config.font_size = size + FONT_SIZE_OFFSET;
+    }
+
+    /// Specifies the font weight. The face of the font family that matches the weight best is
+    /// used to draw and measure text.
+    pub fn set_font_weight(&mut self, weight: FontWeight) {
+        self.config.font_config.weight = weight;
+    }
+
+    /// Specifies the font style. The face of the font family that matches the style best is used
+    /// to draw and measure text.
+    pub fn set_font_style(&mut self, style: FontStyle) {
+        self.config.font_config.style = style;
     }
 
     /// Sets the thickness of lines.
@@ -571,6 +1030,33 @@ impl RenderContext2D {
         self.config.line_width = line_width;
     }
 
+    /// Sets the line dash pattern used when stroking lines. The `segments` describe alternating
+    /// lengths of lines and gaps. If the number of segments is odd, the segments are repeated.
+    /// An empty list sets the line back to solid.
+    pub fn set_line_dash(&mut self, segments: impl Into<Vec<f64>>) {
+        self.config.line_dash = segments.into();
+    }
+
+    /// Sets the offset at which the line dash pattern starts.
+    pub fn set_line_dash_offset(&mut self, offset: f64) {
+        self.config.line_dash_offset = offset;
+    }
+
+    /// Sets the shape used to draw the end points of lines.
+    pub fn set_line_cap(&mut self, line_cap: LineCap) {
+        self.config.line_cap = line_cap;
+    }
+
+    /// Sets the shape used to join two line segments where they meet.
+    pub fn set_line_join(&mut self, line_join: LineJoin) {
+        self.config.line_join = line_join;
+    }
+
+    /// Sets the miter limit ratio, it is used to decide when a miter join is drawn as bevel.
+    pub fn set_miter_limit(&mut self, miter_limit: f64) {
+        self.config.miter_limit = miter_limit;
+    }
+
     // Fill and stroke style
 
     /// Specifies the fill color to use inside shapes.
@@ -583,6 +1069,56 @@ impl RenderContext2D {
         self.config.stroke_style = stroke_style.into();
     }
 
+    // Transformations
+
+    /// Adds a translation transformation to the current matrix by moving the
+    /// context horizontally by `x` and vertically by `y`.
+    pub fn translate(&mut self, x: f64, y: f64) {
+        self.transform = self.transform.pre_translate(x as f32, y as f32);
+    }
+
+    /// Adds a rotation to the transformation matrix. The `angle` is given in radians
+    /// and the rotation is clockwise around the current origin.
+    pub fn rotate(&mut self, angle: f64) {
+        self.transform = self
+            .transform
+            .pre_concat(Transform::from_rotate(angle.to_degrees() as f32));
+    }
+
+    /// Adds a scaling transformation to the current matrix. A `x` or `y` value of `1.0`
+    /// results in no scaling in this direction.
+    pub fn scale(&mut self, x: f64, y: f64) {
+        self.transform = self.transform.pre_scale(x as f32, y as f32);
+    }
+
+    /// Multiplies the current transformation with the matrix described by the arguments
+    /// of this method. The matrix is given in the form:
+    ///
+    /// ```text
+    /// | a c e |
+    /// | b d f |
+    /// | 0 0 1 |
+    /// ```
+    #[allow(clippy::many_single_char_names)]
+    pub fn transform(&mut self, a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) {
+        self.transform = self.transform.pre_concat(Transform::from_row(
+            a as f32, b as f32, c as f32, d as f32, e as f32, f as f32,
+        ));
+    }
+
+    /// Resets the current transformation to the identity matrix and then applies
+    /// the matrix described by the arguments, see `transform`.
+    #[allow(clippy::many_single_char_names)]
+    pub fn set_transform(&mut self, a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) {
+        self.transform =
+            Transform::from_row(a as f32, b as f32, c as f32, d as f32, e as f32, f as f32);
+    }
+
+    /// Resets the current transformation to the identity matrix.
+    pub fn reset_transform(&mut self) {
+        self.transform = Transform::identity();
+    }
+
     // Pixmap states
 
     /// Clear the given `brush`.
@@ -641,19 +1177,14 @@ impl RenderContext2D {
             config,
             path_rect,
             clips_count: former_clips_count,
-            //clip_mask,
+            clip_mask,
             transform,
         }) = self.saved_states.pop()
         {
             self.config = config;
             self.path_rect = path_rect;
-            // FIXME
-            /*for _ in former_clips_count.self.clips_count {
-                self.pixmap.pop_clip();
-            }*/
-            //self.pixmap.reset_clip();
             self.clips_count = former_clips_count;
-            //self.clip_mask = clip_mask;
+            self.clip_mask = clip_mask;
             self.transform = transform;
         }
     }
@@ -665,21 +1196,415 @@ impl RenderContext2D {
             config: self.config.clone(),
             path_rect: self.path_rect,
             clips_count: self.clips_count,
-            //clip_mask: self.clip_mask,
+            clip_mask: self.clip_mask.clone(),
             transform: self.transform,
         });
     }
 
     /// Fill the background pixmap colors using their rgba8 values.
+    /// Resets the transformation and the clipping of the previous frame.
     pub fn start(&mut self) {
+        self.transform = Transform::identity();
+        self.clip_mask = None;
+        self.clips_count = 0;
+
         self.pixmap.fill(tiny_skia::Color::from_rgba8(
             self.background.b(),
             self.background.g(),
             self.background.r(),
             self.background.a(),
         ));
+
+        self.damage = Some(Rectangle::new((0.0, 0.0), (self.width(), self.height())));
+    }
+
+    /// Like `start`, but only the given region is filled with the background and everything
+    /// drawn afterwards is clipped to it. The region is extended to whole pixels. Nothing is
+    /// drawn if the region is outside of the pixmap.
+    pub fn start_region(&mut self, region: Rectangle) {
+        let left = region.x().floor().max(0.0);
+        let top = region.y().floor().max(0.0);
+        let right = (region.x() + region.width()).ceil().min(self.width());
+        let bottom = (region.y() + region.height()).ceil().min(self.height());
+
+        if left <= 0.0 && top <= 0.0 && right >= self.width() && bottom >= self.height() {
+            self.start();
+            return;
+        }
+
+        self.transform = Transform::identity();
+        self.clips_count = 0;
+
+        let mut clip_mask = ClipMask::new();
+        let rect = match tiny_skia::Rect::from_ltrb(
+            left as f32,
+            top as f32,
+            right as f32,
+            bottom as f32,
+        ) {
+            Some(rect) => rect,
+            None => {
+                // a mask of a path outside of the pixmap clips everything
+                if let Some(outside) = tiny_skia::Rect::from_ltrb(-1.0, -1.0, 0.0, 0.0) {
+                    clip_mask.set_path(
+                        self.pixmap.width(),
+                        self.pixmap.height(),
+                        &PathBuilder::from_rect(outside),
+                        FillRule::Winding,
+                        false,
+                    );
+                }
+                self.clip_mask = Some(clip_mask);
+                return;
+            }
+        };
+
+        let paint = Paint {
+            shader: Shader::SolidColor(tiny_skia::Color::from_rgba8(
+                self.background.b(),
+                self.background.g(),
+                self.background.r(),
+                self.background.a(),
+            )),
+            blend_mode: tiny_skia::BlendMode::Source,
+            ..Default::default()
+        };
+        self.pixmap
+            .fill_rect(rect, &paint, Transform::identity(), None);
+
+        clip_mask.set_path(
+            self.pixmap.width(),
+            self.pixmap.height(),
+            &PathBuilder::from_rect(rect),
+            FillRule::Winding,
+            false,
+        );
+        self.clip_mask = Some(clip_mask);
+
+        let region = Rectangle::new((left, top), (right - left, bottom - top));
+        match &mut self.damage {
+            Some(damage) => damage.join_with_rectangle(&region),
+            None => self.damage = Some(region),
+        }
+    }
+
+    /// Returns the area of the pixmap that was drawn since the last call and resets it. The
+    /// shell uses it to present only the changed part of the pixmap.
+    pub fn take_damage(&mut self) -> Option<Rectangle> {
+        self.damage.take()
     }
 
     /// Cleanup, once we are finished.
     pub fn finish(&mut self) {}
 }
+
+// -- Helpers --
+
+fn stroke_from_config(config: &RenderConfig) -> Stroke {
+    let mut dash = config.line_dash.clone();
+    if dash.len() % 2 == 1 {
+        dash = dash.repeat(2);
+    }
+
+    Stroke {
+        width: config.line_width as f32,
+        miter_limit: config.miter_limit as f32,
+        line_cap: match config.line_cap {
+            LineCap::Butt => tiny_skia::LineCap::Butt,
+            LineCap::Round => tiny_skia::LineCap::Round,
+            LineCap::Square => tiny_skia::LineCap::Square,
+        },
+        line_join: match config.line_join {
+            LineJoin::Miter => tiny_skia::LineJoin::Miter,
+            LineJoin::Round => tiny_skia::LineJoin::Round,
+            LineJoin::Bevel => tiny_skia::LineJoin::Bevel,
+        },
+        dash: tiny_skia::StrokeDash::new(
+            dash.iter().map(|d| *d as f32).collect(),
+            config.line_dash_offset as f32,
+        ),
+    }
+}
+
+// -- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // returns the alpha value of the pixel at the given position.
+    fn alpha(ctx: &RenderContext2D, x: usize, y: usize) -> u8 {
+        ctx.data()[(y * ctx.width() as usize + x) * 4 + 3]
+    }
+
+    // returns the pixel at the given position as BGRA.
+    fn pixel(ctx: &RenderContext2D, x: usize, y: usize) -> &[u8] {
+        let i = (y * ctx.width() as usize + x) * 4;
+        &ctx.data()[i..i + 4]
+    }
+
+    fn context() -> RenderContext2D {
+        let mut ctx = RenderContext2D::new(20.0, 20.0);
+        ctx.set_fill_style(Color::rgb(255, 0, 0));
+        ctx.set_stroke_style(Color::rgb(255, 0, 0));
+        ctx
+    }
+
+    #[test]
+    fn test_translate() {
+        let mut ctx = context();
+        ctx.translate(10.0, 10.0);
+        ctx.fill_rect(0.0, 0.0, 5.0, 5.0);
+
+        assert_eq!(alpha(&ctx, 2, 2), 0);
+        assert_eq!(alpha(&ctx, 12, 12), 255);
+    }
+
+    #[test]
+    fn test_scale_and_rotate() {
+        let mut ctx = context();
+        ctx.scale(2.0, 2.0);
+        ctx.fill_rect(0.0, 0.0, 5.0, 5.0);
+
+        assert_eq!(alpha(&ctx, 8, 8), 255);
+        assert_eq!(alpha(&ctx, 12, 12), 0);
+
+        let mut ctx = context();
+        ctx.translate(10.0, 10.0);
+        ctx.rotate(PI);
+        ctx.fill_rect(0.0, 0.0, 5.0, 5.0);
+
+        assert_eq!(alpha(&ctx, 7, 7), 255);
+        assert_eq!(alpha(&ctx, 12, 12), 0);
+    }
+
+    #[test]
+    fn test_save_restore_transform() {
+        let mut ctx = context();
+        ctx.save();
+        ctx.set_transform(1.0, 0.0, 0.0, 1.0, 10.0, 0.0);
+        ctx.restore();
+        ctx.fill_rect(0.0, 0.0, 5.0, 5.0);
+
+        assert_eq!(alpha(&ctx, 2, 2), 255);
+        assert_eq!(alpha(&ctx, 12, 2), 0);
+    }
+
+    #[test]
+    fn test_clip() {
+        let mut ctx = context();
+        ctx.save();
+        ctx.translate(5.0, 5.0);
+        ctx.begin_path();
+        ctx.rect(0.0, 0.0, 5.0, 5.0);
+        ctx.clip();
+        ctx.reset_transform();
+        ctx.fill_rect(0.0, 0.0, 20.0, 20.0);
+        ctx.restore();
+
+        assert_eq!(alpha(&ctx, 2, 2), 0);
+        assert_eq!(alpha(&ctx, 7, 7), 255);
+        assert_eq!(alpha(&ctx, 12, 12), 0);
+
+        ctx.fill_rect(10.0, 10.0, 5.0, 5.0);
+        assert_eq!(alpha(&ctx, 12, 12), 255);
+    }
+
+    #[test]
+    fn test_radial_gradient() {
+        let mut ctx = context();
+        ctx.set_fill_style(Brush::from(
+            "radial-gradient(circle 10px at 10px 10px, #ff0000, #0000ff)",
+        ));
+        ctx.fill_rect(0.0, 0.0, 20.0, 20.0);
+
+        // red in the center, blue outside of the radius
+        assert!(pixel(&ctx, 10, 10)[2] > 200);
+        assert_eq!(pixel(&ctx, 0, 0), &[255, 0, 0, 255]);
+    }
+
+    #[test]
+    fn test_conic_gradient() {
+        let mut ctx = context();
+        ctx.set_fill_style(Brush::from(
+            "conic-gradient(#ff0000, #ff0000 50%, #0000ff 50%, #0000ff)",
+        ));
+        ctx.fill_rect(0.0, 0.0, 20.0, 20.0);
+
+        // right half is red, left half is blue
+        assert_eq!(pixel(&ctx, 15, 10), &[0, 0, 255, 255]);
+        assert_eq!(pixel(&ctx, 4, 10), &[255, 0, 0, 255]);
+
+        let mut ctx = context();
+        ctx.set_fill_style(Brush::from(
+            "conic-gradient(from 180deg, #ff0000, #ff0000 50%, #0000ff 50%, #0000ff)",
+        ));
+        ctx.begin_path();
+        ctx.rect(0.0, 0.0, 20.0, 20.0);
+        ctx.fill();
+
+        assert_eq!(pixel(&ctx, 15, 10), &[255, 0, 0, 255]);
+        assert_eq!(pixel(&ctx, 4, 10), &[0, 0, 255, 255]);
+    }
+
+    #[test]
+    fn test_line_dash() {
+        let mut ctx = context();
+        ctx.set_line_width(2.0);
+        ctx.set_line_dash(vec![4.0]);
+        ctx.begin_path();
+        ctx.move_to(0.0, 10.0);
+        ctx.line_to(20.0, 10.0);
+        ctx.stroke();
+
+        assert_eq!(alpha(&ctx, 2, 10), 255);
+        assert_eq!(alpha(&ctx, 6, 10), 0);
+        assert_eq!(alpha(&ctx, 10, 10), 255);
+
+        let mut ctx = context();
+        ctx.set_line_width(2.0);
+        ctx.set_line_dash(vec![4.0, 4.0]);
+        ctx.set_line_dash_offset(4.0);
+        ctx.begin_path();
+        ctx.move_to(0.0, 10.0);
+        ctx.line_to(20.0, 10.0);
+        ctx.stroke();
+
+        assert_eq!(alpha(&ctx, 2, 10), 0);
+        assert_eq!(alpha(&ctx, 6, 10), 255);
+    }
+
+    #[test]
+    fn test_line_cap() {
+        let mut ctx = context();
+        ctx.set_line_width(4.0);
+        ctx.set_line_cap(LineCap::Square);
+        ctx.begin_path();
+        ctx.move_to(5.0, 10.0);
+        ctx.line_to(15.0, 10.0);
+        ctx.stroke();
+
+        assert_eq!(alpha(&ctx, 4, 10), 255);
+        assert_eq!(alpha(&ctx, 16, 10), 255);
+    }
+
+    #[test]
+    fn test_save_restore_stroke_config() {
+        let mut ctx = context();
+        ctx.save();
+        ctx.set_line_dash(vec![1.0, 2.0]);
+        ctx.set_line_dash_offset(1.0);
+        ctx.set_line_cap(LineCap::Round);
+        ctx.set_line_join(LineJoin::Bevel);
+        ctx.set_miter_limit(2.0);
+        ctx.restore();
+
+        assert!(ctx.config.line_dash.is_empty());
+        assert!(orbtk_utils::f64_cmp(ctx.config.line_dash_offset, 0.0));
+        assert_eq!(ctx.config.line_cap, LineCap::Butt);
+        assert_eq!(ctx.config.line_join, LineJoin::Miter);
+        assert!(orbtk_utils::f64_cmp(ctx.config.miter_limit, 10.0));
+    }
+
+    #[test]
+    fn test_fill_shadow() {
+        let mut ctx = RenderContext2D::new(30.0, 30.0);
+        ctx.begin_path();
+        ctx.rect(5.0, 5.0, 12.0, 12.0);
+        ctx.fill_shadow(2.0, 2.0, 4.0, Color::rgb(0, 0, 0));
+
+        // the shadow is moved by the offset and blurred at its edges
+        assert!(alpha(&ctx, 13, 13) > 200);
+        assert!(alpha(&ctx, 21, 13) > 0);
+        assert!(alpha(&ctx, 21, 13) < 200);
+        assert_eq!(alpha(&ctx, 0, 0), 0);
+
+        let mut ctx = context();
+        ctx.begin_path();
+        ctx.rect(5.0, 5.0, 6.0, 6.0);
+        ctx.fill_shadow(0.0, 0.0, 4.0, Color::rgba(0, 0, 0, 0));
+        assert_eq!(alpha(&ctx, 8, 8), 0);
+    }
+
+    #[test]
+    fn test_measure_caret_positions() {
+        let mut ctx = context();
+        ctx.register_font(
+            "Roboto",
+            &include_bytes!("../../../orbtk_widgets/assets/fonts/roboto/Roboto-Regular.ttf")[..],
+        );
+
+        let positions = ctx.measure_caret_positions("Caret", 12.0, "Roboto");
+        let width = ctx.measure_text("Caret").width;
+
+        assert_eq!(positions.len(), 6);
+        assert!(orbtk_utils::f64_cmp(positions[0], 0.0));
+        assert!((positions[5] - width).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_start_region() {
+        let mut ctx = context();
+        ctx.set_background(Color::rgb(0, 0, 255));
+        ctx.start();
+        assert_eq!(
+            ctx.take_damage(),
+            Some(Rectangle::new((0.0, 0.0), (20.0, 20.0)))
+        );
+        assert_eq!(ctx.take_damage(), None);
+
+        ctx.fill_rect(0.0, 0.0, 20.0, 20.0);
+        ctx.start_region(Rectangle::new((2.5, 2.5), (5.0, 5.0)));
+
+        // only the region is cleared
+        assert_eq!(pixel(&ctx, 4, 4), &[255, 0, 0, 255]);
+        assert_eq!(pixel(&ctx, 10, 10), &[0, 0, 255, 255]);
+
+        // drawing is clipped to the region
+        ctx.set_fill_style(Color::rgb(0, 255, 0));
+        ctx.fill_rect(0.0, 0.0, 20.0, 20.0);
+        assert_eq!(pixel(&ctx, 2, 2), &[0, 255, 0, 255]);
+        assert_eq!(pixel(&ctx, 8, 8), &[0, 0, 255, 255]);
+
+        ctx.start_region(Rectangle::new((10.0, 10.0), (2.0, 2.0)));
+        assert_eq!(
+            ctx.take_damage(),
+            Some(Rectangle::new((2.0, 2.0), (10.0, 10.0)))
+        );
+
+        // regions outside of the pixmap clip everything
+        ctx.start_region(Rectangle::new((30.0, 30.0), (5.0, 5.0)));
+        ctx.fill_rect(0.0, 0.0, 20.0, 20.0);
+        assert_eq!(pixel(&ctx, 10, 10), &[255, 0, 0, 255]);
+        assert_eq!(ctx.take_damage(), None);
+    }
+
+    #[test]
+    fn test_layer() {
+        let mut ctx = context();
+        ctx.begin_layer(10.0, 10.0, 5.0, 5.0);
+        ctx.fill_rect(12.0, 12.0, 2.0, 2.0);
+        let layer = ctx.end_layer();
+
+        // nothing is drawn into the pixmap of the context
+        assert_eq!(alpha(&ctx, 12, 12), 0);
+        assert_eq!(layer.width(), 5.0);
+        assert_eq!(layer.height(), 5.0);
+
+        ctx.draw_render_target(&layer, 10.0, 10.0);
+        assert_eq!(pixel(&ctx, 12, 12), &[0, 0, 255, 255]);
+        assert_eq!(alpha(&ctx, 11, 11), 0);
+        assert_eq!(alpha(&ctx, 14, 14), 0);
+
+        assert_eq!(ctx.end_layer(), RenderTarget::default());
+    }
+
+    #[test]
+    fn test_layout_text_without_font() {
+        let ctx = context();
+        let layout = ctx.layout_text("one\ntwo", Some(10.0), 12.0);
+
+        assert_eq!(layout.lines().len(), 2);
+        assert!(orbtk_utils::f64_cmp(layout.width(), 0.0));
+    }
+}
@@ -0,0 +1,157 @@
+use std::ops::Range;
+
+use rustybuzz::{Direction, Face, UnicodeBuffer};
+use unicode_bidi::BidiInfo;
+
+use super::font::Glyph;
+
+/// Splits the given text in runs of the same direction by the unicode bidirectional algorithm.
+/// The runs are returned in visual order, each with its byte range and `true` for right-to-left.
+pub(crate) fn visual_runs(text: &str) -> Vec<(Range<usize>, bool)> {
+    let bidi_info = BidiInfo::new(text, None);
+    let mut visual_runs = vec![];
+
+    for paragraph in &bidi_info.paragraphs {
+        let (levels, runs) = bidi_info.visual_runs(paragraph, paragraph.range.clone());
+
+        for run in runs {
+            let rtl = levels[run.start].is_rtl();
+            visual_runs.push((run, rtl));
+        }
+    }
+
+    visual_runs
+}
+
+/// Shapes the given text with the font of the given bytes. The glyphs are returned in visual order.
+/// `scale` is used to convert font units to pixels.
+pub(crate) fn shape(bytes: &[u8], text: &str, scale: f32) -> Vec<Glyph> {
+    let mut glyphs = vec![];
+    let mut x = 0.0;
+
+    for (range, rtl) in visual_runs(text) {
+        for mut glyph in shape_run(bytes, &text[range.clone()], rtl, scale) {
+            glyph.x += x;
+            glyph.cluster += range.start;
+            glyphs.push(glyph);
+        }
+
+        x = glyphs.last().map_or(0.0, |g| g.x + g.advance);
+    }
+
+    glyphs
+}
+
+/// Shapes a run of text with a single direction. The glyphs are returned in visual order starting
+/// at `0.0`, their clusters are relative to the run.
+pub(crate) fn shape_run(bytes: &[u8], text: &str, rtl: bool, scale: f32) -> Vec<Glyph> {
+    let face = match Face::from_slice(bytes, 0) {
+        Some(face) => face,
+        None => return vec![],
+    };
+
+    let mut buffer = UnicodeBuffer::new();
+    buffer.push_str(text);
+    buffer.set_direction(if rtl {
+        Direction::RightToLeft
+    } else {
+        Direction::LeftToRight
+    });
+    buffer.guess_segment_properties();
+
+    // the output of a right-to-left run is already in visual order
+    let output = rustybuzz::shape(&face, &[], buffer);
+    let mut glyphs = vec![];
+    let mut x = 0.0;
+
+    for (info, position) in output.glyph_infos().iter().zip(output.glyph_positions()) {
+        let advance = position.x_advance as f32 * scale;
+
+        glyphs.push(Glyph {
+            id: rusttype::GlyphId(info.glyph_id as u16),
+            x,
+            offset: (
+                position.x_offset as f32 * scale,
+                -position.y_offset as f32 * scale,
+            ),
+            advance,
+            cluster: info.cluster as usize,
+            rtl,
+        });
+
+        x += advance;
+    }
+
+    glyphs
+}
+
+#[cfg(all(test, feature = "shaping"))]
+mod tests {
+    use super::*;
+    use crate::tinyskia::font::caret_positions;
+
+    static ROBOTO: &[u8] =
+        include_bytes!("../../../orbtk_widgets/assets/fonts/roboto/Roboto-Regular.ttf");
+
+    // "hi " followed by the hebrew word "shalom". Roboto has no hebrew glyphs, the chars are shaped
+    // as `.notdef` glyphs but keep their clusters and their direction.
+    const TEXT: &str = "hi \u{5e9}\u{5dc}\u{5d5}\u{5dd}";
+
+    #[test]
+    fn test_visual_runs() {
+        assert_eq!(visual_runs(TEXT), vec![(0..3, false), (3..11, true)]);
+
+        // a right-to-left paragraph starts at the right
+        assert_eq!(
+            visual_runs("\u{5e9}\u{5dc} ab"),
+            vec![(5..7, false), (0..5, true)]
+        );
+    }
+
+    #[test]
+    fn test_shape() {
+        let glyphs = shape(ROBOTO, TEXT, 0.01);
+        let font = rusttype::Font::try_from_bytes(ROBOTO).unwrap();
+
+        // the hebrew word is ordered from its last to its first char
+        let clusters: Vec<usize> = glyphs.iter().map(|g| g.cluster).collect();
+        assert_eq!(clusters, vec![0, 1, 2, 9, 7, 5, 3]);
+
+        let rtl: Vec<bool> = glyphs.iter().map(|g| g.rtl).collect();
+        assert_eq!(rtl, vec![false, false, false, true, true, true, true]);
+
+        assert_eq!(glyphs[0].id, font.glyph('h').id());
+        assert_eq!(glyphs[1].id, font.glyph('i').id());
+
+        for pair in glyphs.windows(2) {
+            assert!(pair[0].advance > 0.0);
+            assert!((pair[0].x + pair[0].advance - pair[1].x).abs() < 0.001);
+        }
+    }
+
+    #[test]
+    fn test_caret_positions() {
+        let glyphs = shape(ROBOTO, TEXT, 0.01);
+        let right = |index: usize| (glyphs[index].x + glyphs[index].advance) as f64;
+
+        // the caret in front of a hebrew char is on its right side, at the end of the text it is
+        // on the left side of the last char
+        let expected = vec![
+            glyphs[0].x as f64,
+            glyphs[1].x as f64,
+            glyphs[2].x as f64,
+            right(6),
+            right(5),
+            right(4),
+            right(3),
+            glyphs[3].x as f64,
+        ];
+
+        let positions = caret_positions(TEXT, &glyphs);
+        assert_eq!(positions.len(), expected.len());
+
+        for (position, expected) in positions.iter().zip(expected) {
+            assert!((position - expected).abs() < 0.001);
+        }
+    }
+}
@@ -0,0 +1,531 @@
+use std::ops::Range;
+
+use crate::utils::{FontStyle, FontWeight, TextAlignment};
+
+/// Describes a single line of a `TextLayout`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextLine {
+    /// The text of the line without line break and the whitespace at a wrapping position.
+    pub text: String,
+
+    /// The byte range of the line inside of the laid out text.
+    pub range: Range<usize>,
+
+    /// The width of the line in pixels.
+    pub width: f64,
+}
+
+/// A `TextLayout` breaks a text into lines at explicit newlines and optional wraps the words
+/// of the text to a maximum width.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextLayout {
+    lines: Vec<TextLine>,
+    line_height: f64,
+    glyph_height: f64,
+}
+
+impl TextLayout {
+    /// Lays out the given `text`. If `max_width` is set, lines that are wider are wrapped at word
+    /// boundaries. Words that are wider than `max_width` are broken between characters. The
+    /// `measure` function returns the width and height of the given text in pixels.
+    pub fn new<F>(text: &str, max_width: Option<f64>, line_height: f64, mut measure: F) -> Self
+    where
+        F: FnMut(&str) -> (f64, f64),
+    {
+        TextLayout::from_ranges(text, max_width, line_height, |range| measure(&text[range]))
+    }
+
+    /// Lays out the given `text` like `TextLayout::new`, but the `measure` function gets the byte
+    /// range of the part of the text that should be measured. It is used if the parts of the text
+    /// are measured with different fonts.
+    pub fn from_ranges<F>(
+        text: &str,
+        max_width: Option<f64>,
+        line_height: f64,
+        mut measure: F,
+    ) -> Self
+    where
+        F: FnMut(Range<usize>) -> (f64, f64),
+    {
+        let mut lines = vec![];
+        let mut start = 0;
+
+        for raw_paragraph in text.split('\n') {
+            let paragraph = raw_paragraph.strip_suffix('\r').unwrap_or(raw_paragraph);
+
+            match max_width {
+                Some(max_width) if measure(start..start + paragraph.len()).0 > max_width => {
+                    wrap_paragraph(paragraph, start, max_width, &mut measure, &mut lines)
+                }
+                _ => push_line(
+                    paragraph,
+                    start,
+                    0..paragraph.len(),
+                    &mut measure,
+                    &mut lines,
+                ),
+            }
+
+            start += raw_paragraph.len() + 1;
+        }
+
+        TextLayout {
+            lines,
+            line_height,
+            glyph_height: measure(0..0).1,
+        }
+    }
+
+    /// Gets the laid out lines.
+    pub fn lines(&self) -> &[TextLine] {
+        &self.lines
+    }
+
+    /// Gets the distance between the tops of two lines.
+    pub fn line_height(&self) -> f64 {
+        self.line_height
+    }
+
+    /// Gets the width of the widest line.
+    pub fn width(&self) -> f64 {
+        self.lines.iter().map(|l| l.width).fold(0.0, f64::max)
+    }
+
+    /// Gets the height of all lines.
+    pub fn height(&self) -> f64 {
+        if self.lines.is_empty() {
+            return 0.0;
+        }
+
+        (self.lines.len() - 1) as f64 * self.line_height + self.glyph_height
+    }
+
+    /// Gets the offset of the line with the given `index` on the x axis, if it is aligned inside of
+    /// the given `width`.
+    pub fn line_x(&self, index: usize, width: f64, alignment: TextAlignment) -> f64 {
+        let line_width = self.lines.get(index).map(|l| l.width).unwrap_or(0.0);
+
+        match alignment {
+            TextAlignment::Left | TextAlignment::Start => 0.0,
+            TextAlignment::Right | TextAlignment::End => width - line_width,
+            TextAlignment::Center => (width - line_width) / 2.0,
+        }
+    }
+
+    /// Gets the offset of the line with the given `index` on the y axis.
+    pub fn line_y(&self, index: usize) -> f64 {
+        index as f64 * self.line_height
+    }
+}
+
+/// A part of a text that is drawn with its own font family, size, weight and style.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TextRun<'a> {
+    /// The text of the run.
+    pub text: &'a str,
+
+    /// The font family of the run.
+    pub family: &'a str,
+
+    /// The font size of the run, like it is set by `RenderContext2D::set_font_size`.
+    pub font_size: f64,
+
+    /// The font weight of the run.
+    pub font_weight: FontWeight,
+
+    /// The font style of the run.
+    pub font_style: FontStyle,
+}
+
+/// Describes the part of a run of a `RunLayout` that is placed on one line.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextFragment {
+    /// The index of the run.
+    pub run: usize,
+
+    /// The index of the line.
+    pub line: usize,
+
+    /// The text of the fragment.
+    pub text: String,
+
+    /// The byte range of the fragment inside of the text of its run.
+    pub range: Range<usize>,
+
+    /// The offset from the start of the line on the x axis.
+    pub x: f64,
+
+    /// The offset from the top of the line on the y axis. Fragments of a line with different
+    /// fonts share the same base line.
+    pub y: f64,
+
+    /// The width of the fragment in pixels.
+    pub width: f64,
+}
+
+/// A `RunLayout` lays out a text that is split into runs, each drawn with its own font and size.
+/// The runs are wrapped like a `TextLayout` and placed on common base lines.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RunLayout {
+    layout: TextLayout,
+    fragments: Vec<TextFragment>,
+    baseline: f64,
+}
+
+impl RunLayout {
+    /// Lays out the given runs. `ascents` contains the distance between the top and the base line
+    /// of each run. The `measure` function returns the width and height of a text of the run with
+    /// the given index.
+    pub fn new<F>(
+        runs: &[&str],
+        ascents: &[f64],
+        max_width: Option<f64>,
+        line_height: f64,
+        mut measure: F,
+    ) -> Self
+    where
+        F: FnMut(usize, &str) -> (f64, f64),
+    {
+        let text = runs.concat();
+        let mut starts = Vec::with_capacity(runs.len());
+        let mut start = 0;
+        for run in runs {
+            starts.push(start);
+            start += run.len();
+        }
+
+        // the parts of the runs inside of the given range of the text
+        let parts = |range: Range<usize>| {
+            runs.iter()
+                .zip(&starts)
+                .enumerate()
+                .filter_map(move |(index, (run, start))| {
+                    let part_start = range.start.max(*start);
+                    let part_end = range.end.min(start + run.len());
+
+                    if part_start < part_end {
+                        Some((index, part_start - start..part_end - start))
+                    } else {
+                        None
+                    }
+                })
+        };
+
+        let layout = TextLayout::from_ranges(&text, max_width, line_height, |range| {
+            if range.is_empty() {
+                return (0..runs.len())
+                    .map(|index| measure(index, ""))
+                    .fold((0.0, 0.0), |size, part| (0.0, f64::max(size.1, part.1)));
+            }
+
+            parts(range).fold((0.0, 0.0), |size, (index, part)| {
+                let part = measure(index, &runs[index][part]);
+                (size.0 + part.0, f64::max(size.1, part.1))
+            })
+        });
+
+        let baseline = ascents.iter().copied().fold(0.0, f64::max);
+        let mut fragments = vec![];
+
+        for (line, text_line) in layout.lines().iter().enumerate() {
+            let mut x = 0.0;
+
+            for (run, range) in parts(text_line.range.clone()) {
+                let text = runs[run][range.clone()].to_string();
+                let width = measure(run, &text).0;
+
+                fragments.push(TextFragment {
+                    run,
+                    line,
+                    text,
+                    range,
+                    x,
+                    y: baseline - ascents.get(run).copied().unwrap_or(baseline),
+                    width,
+                });
+
+                x += width;
+            }
+        }
+
+        RunLayout {
+            layout,
+            fragments,
+            baseline,
+        }
+    }
+
+    /// Gets the layout of the lines of the joined runs.
+    pub fn layout(&self) -> &TextLayout {
+        &self.layout
+    }
+
+    /// Gets the laid out fragments of the runs.
+    pub fn fragments(&self) -> &[TextFragment] {
+        &self.fragments
+    }
+
+    /// Gets the distance between the top of a line and its base line.
+    pub fn baseline(&self) -> f64 {
+        self.baseline
+    }
+
+    /// Gets the width of the widest line.
+    pub fn width(&self) -> f64 {
+        self.layout.width()
+    }
+
+    /// Gets the height of all lines.
+    pub fn height(&self) -> f64 {
+        self.layout.height()
+    }
+
+    /// Gets the fragment at the given position, if the lines are aligned inside of the given
+    /// `width`.
+    pub fn fragment_at(
+        &self,
+        x: f64,
+        y: f64,
+        width: f64,
+        alignment: TextAlignment,
+    ) -> Option<&TextFragment> {
+        if y < 0.0 || y >= self.height() {
+            return None;
+        }
+
+        let line = ((y / self.layout.line_height()) as usize).min(self.layout.lines().len() - 1);
+        let x = x - self.layout.line_x(line, width, alignment);
+
+        self.fragments
+            .iter()
+            .find(|f| f.line == line && x >= f.x && x < f.x + f.width)
+    }
+}
+
+// -- Helpers --
+
+fn push_line<F>(
+    paragraph: &str,
+    start: usize,
+    range: Range<usize>,
+    measure: &mut F,
+    lines: &mut Vec<TextLine>,
+) where
+    F: FnMut(Range<usize>) -> (f64, f64),
+{
+    let range = start + range.start..start + range.end;
+    lines.push(TextLine {
+        text: paragraph[range.start - start..range.end - start].to_string(),
+        width: measure(range.clone()).0,
+        range,
+    });
+}
+
+// Returns the ranges of the words of the given text. Leading whitespace is part of the first word.
+fn words(text: &str) -> Vec<Range<usize>> {
+    let mut words = vec![];
+    let mut word_start = None;
+
+    for (i, c) in text.char_indices() {
+        match (c.is_whitespace(), word_start) {
+            (false, None) => word_start = Some(if words.is_empty() { 0 } else { i }),
+            (true, Some(s)) => {
+                words.push(s..i);
+                word_start = None;
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(s) = word_start {
+        words.push(s..text.len());
+    }
+
+    words
+}
+
+fn wrap_paragraph<F>(
+    paragraph: &str,
+    start: usize,
+    max_width: f64,
+    measure: &mut F,
+    lines: &mut Vec<TextLine>,
+) where
+    F: FnMut(Range<usize>) -> (f64, f64),
+{
+    let words = words(paragraph);
+
+    if words.is_empty() {
+        push_line(paragraph, start, 0..paragraph.len(), measure, lines);
+        return;
+    }
+
+    let mut line: Option<Range<usize>> = None;
+
+    for word in words {
+        if let Some(current) = line.clone() {
+            if measure(start + current.start..start + word.end).0 <= max_width {
+                line = Some(current.start..word.end);
+                continue;
+            }
+
+            push_line(paragraph, start, current, measure, lines);
+        }
+
+        // breaks words that does not fit in a line between its characters
+        let mut word_start = word.start;
+        loop {
+            let end = fitting_end(paragraph, start, word_start..word.end, max_width, measure);
+            if end == word.end {
+                line = Some(word_start..end);
+                break;
+            }
+
+            push_line(paragraph, start, word_start..end, measure, lines);
+            word_start = end;
+        }
+    }
+
+    if let Some(line) = line {
+        push_line(paragraph, start, line, measure, lines);
+    }
+}
+
+// Returns the end of the longest part of the given range that fits in `max_width`. The part
+// contains at least one character.
+fn fitting_end<F>(
+    paragraph: &str,
+    start: usize,
+    range: Range<usize>,
+    max_width: f64,
+    measure: &mut F,
+) -> usize
+where
+    F: FnMut(Range<usize>) -> (f64, f64),
+{
+    let mut ends = paragraph[range.clone()]
+        .char_indices()
+        .map(|(i, c)| range.start + i + c.len_utf8());
+
+    let mut fitting = ends.next().unwrap_or(range.end);
+
+    for end in ends {
+        if measure(start + range.start..start + end).0 > max_width {
+            break;
+        }
+        fitting = end;
+    }
+
+    fitting
+}
+
+// -- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // every character is 10 pixels wide and 12 pixels high.
+    fn measure(text: &str) -> (f64, f64) {
+        (text.chars().count() as f64 * 10.0, 12.0)
+    }
+
+    fn lines(layout: &TextLayout) -> Vec<&str> {
+        layout.lines().iter().map(|l| l.text.as_str()).collect()
+    }
+
+    #[test]
+    fn test_newlines() {
+        let layout = TextLayout::new("one\ntwo\r\n\nthree", None, 15.0, measure);
+
+        assert_eq!(lines(&layout), vec!["one", "two", "", "three"]);
+        assert_eq!(layout.lines()[1].range, 4..7);
+        assert_eq!(layout.lines()[3].range, 10..15);
+        assert!(orbtk_utils::f64_cmp(layout.width(), 50.0));
+        assert!(orbtk_utils::f64_cmp(layout.height(), 57.0));
+        assert!(orbtk_utils::f64_cmp(layout.line_y(2), 30.0));
+    }
+
+    #[test]
+    fn test_wrap() {
+        let layout = TextLayout::new("the quick brown fox", Some(100.0), 15.0, measure);
+        assert_eq!(lines(&layout), vec!["the quick", "brown fox"]);
+        assert_eq!(layout.lines()[1].range, 10..19);
+
+        let layout = TextLayout::new("the quick brown fox", None, 15.0, measure);
+        assert_eq!(lines(&layout), vec!["the quick brown fox"]);
+
+        let layout = TextLayout::new("  indented text", Some(100.0), 15.0, measure);
+        assert_eq!(lines(&layout), vec!["  indented", "text"]);
+    }
+
+    #[test]
+    fn test_wrap_long_words() {
+        let layout = TextLayout::new("a abcdefghijkl b", Some(50.0), 15.0, measure);
+        assert_eq!(lines(&layout), vec!["a", "abcde", "fghij", "kl b"]);
+
+        let layout = TextLayout::new("äöü", Some(5.0), 15.0, measure);
+        assert_eq!(lines(&layout), vec!["ä", "ö", "ü"]);
+    }
+
+    #[test]
+    fn test_alignment() {
+        let layout = TextLayout::new("ab\nabcd", None, 15.0, measure);
+
+        assert!(orbtk_utils::f64_cmp(
+            layout.line_x(0, 100.0, TextAlignment::Left),
+            0.0
+        ));
+        assert!(orbtk_utils::f64_cmp(
+            layout.line_x(0, 100.0, TextAlignment::Center),
+            40.0
+        ));
+        assert!(orbtk_utils::f64_cmp(
+            layout.line_x(1, 100.0, TextAlignment::End),
+            60.0
+        ));
+    }
+
+    #[test]
+    fn test_run_layout() {
+        let layout = RunLayout::new(
+            &["the quick ", "brown", " fox"],
+            &[8.0, 10.0, 8.0],
+            Some(100.0),
+            15.0,
+            |_, text| measure(text),
+        );
+
+        assert_eq!(layout.layout().lines()[0].text, "the quick");
+        assert_eq!(layout.layout().lines()[1].text, "brown fox");
+        assert!(orbtk_utils::f64_cmp(layout.baseline(), 10.0));
+
+        let fragments: Vec<(usize, usize, &str)> = layout
+            .fragments()
+            .iter()
+            .map(|f| (f.run, f.line, f.text.as_str()))
+            .collect();
+        assert_eq!(
+            fragments,
+            vec![(0, 0, "the quick"), (1, 1, "brown"), (2, 1, " fox")]
+        );
+
+        let fox = &layout.fragments()[2];
+        assert_eq!(fox.range, 0..4);
+        assert!(orbtk_utils::f64_cmp(fox.x, 50.0));
+        assert!(orbtk_utils::f64_cmp(fox.y, 2.0));
+
+        assert_eq!(
+            layout
+                .fragment_at(55.0, 20.0, 100.0, TextAlignment::Left)
+                .map(|f| f.run),
+            Some(2)
+        );
+        assert!(layout
+            .fragment_at(95.0, 5.0, 100.0, TextAlignment::Left)
+            .is_none());
+        assert!(layout
+            .fragment_at(5.0, 40.0, 100.0, TextAlignment::Left)
+            .is_none());
+    }
+}
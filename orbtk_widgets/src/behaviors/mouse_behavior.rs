@@ -1,10 +1,11 @@
-use crate::{api::prelude::*, proc_macros::*};
+use crate::{api::prelude::*, proc_macros::*, shell::prelude::Key};
 
 #[derive(Debug, Copy, Clone)]
 enum Action {
     Press(Mouse),
     Release(Mouse),
     Scroll(Point),
+    Activate,
 }
 
 /// The `MouseBehaviorState` handles the `MouseBehavior` widget.
@@ -14,15 +15,42 @@ pub struct MouseBehaviorState {
     target: Entity,
 }
 
+impl MouseBehaviorState {
+    // Returns the key of the command the target is bound to.
+    fn command(&self, ctx: &mut Context) -> Option<String> {
+        ctx.get_widget(self.target)
+            .try_clone::<String>("command")
+            .filter(|command| !command.is_empty())
+    }
+
+    fn execute_command(&self, registry: &mut Registry, ctx: &mut Context) {
+        let command = self.command(ctx).and_then(|command| {
+            registry
+                .try_get::<Commands>("commands")
+                .and_then(|commands| commands.get(&command).cloned())
+        });
+
+        if let Some(command) = command {
+            command.execute(registry, ctx);
+        }
+    }
+}
+
 impl State for MouseBehaviorState {
-    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+    fn init(&mut self, registry: &mut Registry, ctx: &mut Context) {
         self.target = (*MouseBehavior::target_ref(&ctx.widget())).into();
+
+        if let Some(command) = self.command(ctx) {
+            if let Some(commands) = registry.try_get_mut::<Commands>("commands") {
+                commands.bind(self.target, command);
+            }
+        }
     }
 
     fn messages(
         &mut self,
         mut messages: MessageReader,
-        _registry: &mut Registry,
+        registry: &mut Registry,
         ctx: &mut Context,
     ) {
         for message in messages.read::<Action>() {
@@ -46,12 +74,38 @@ impl State for MouseBehaviorState {
                                 position: p.position,
                             },
                         );
+                        self.execute_command(registry, ctx);
                     }
                 }
                 Action::Scroll(p) => {
                     MouseBehavior::position_set(&mut ctx.widget(), p);
                     self.has_delta = true;
                 }
+                Action::Activate => {
+                    // only the focused widget is activated by the keyboard
+                    if !ctx
+                        .get_widget(self.target)
+                        .try_clone::<bool>("focused")
+                        .unwrap_or(false)
+                    {
+                        continue;
+                    }
+
+                    // clicks in the center of the target
+                    let position: Point = ctx.get_widget(self.target).clone("position");
+                    let bounds: Rectangle = ctx.get_widget(self.target).clone("bounds");
+
+                    ctx.event_adapter().push_event(
+                        self.target,
+                        ClickEvent {
+                            position: Point::new(
+                                position.x() + bounds.width() / 2.0,
+                                position.y() + bounds.height() / 2.0,
+                            ),
+                        },
+                    );
+                    self.execute_command(registry, ctx);
+                }
             }
 
             ctx.get_widget(self.target).update(false);
@@ -68,9 +122,10 @@ impl State for MouseBehaviorState {
 
 widget!(
     /// The `MouseBehavior` widget is used to handle internal the pressed behavior of a widget.
+    /// If the target is focused, `Space` and `Enter` click the target like the mouse.
     ///
     /// **style:** `check-box`
-    MouseBehavior<MouseBehaviorState>: MouseHandler {
+    MouseBehavior<MouseBehaviorState>: MouseHandler, KeyDownHandler {
         /// Sets or shares the target of the behavior.
         target: u32,
 
@@ -98,5 +153,11 @@ impl Template for MouseBehavior {
                 ctx.send_message(Action::Scroll(p), id);
                 false
             })
+            .on_key_down(move |ctx, event| {
+                if event.key == Key::Space || event.key == Key::Enter {
+                    ctx.send_message(Action::Activate, id);
+                }
+                false
+            })
     }
 }
@@ -6,6 +6,7 @@ use crate::{
     themes::theme_orbtk::orbtk_fonts,
     Cursor, TextBlock,
 };
+use std::iter;
 
 // --- KEYS --
 pub static NOT_EMPTY_STATE: &str = "not_empty";
@@ -223,16 +224,26 @@ impl TextBehaviorState {
     // -- Selection --
 
     fn update_cursor(&mut self, ctx: &mut Context) {
-        let selection = self.selection(ctx);
-        let (start, end) = self.selection_start_end(selection);
+        let cursor_x = if self.multi_line(ctx) {
+            self.update_multi_line_cursor(ctx)
+        } else {
+            let selection = self.selection(ctx);
+            let (start, end) = self.selection_start_end(selection);
+
+            // with right-to-left text the selection start could be on the right of its end
+            let len = self.len(ctx);
+            let caret_positions = self.caret_positions(ctx, 0, len);
+            let cursor_x = caret_x(&caret_positions, selection.start());
+            let start_x = caret_x(&caret_positions, start);
+            let end_x = caret_x(&caret_positions, end);
 
-        let cursor_start_measure = self.measure(ctx, 0, selection.start());
-        Cursor::cursor_x_set(&mut ctx.get_widget(self.cursor), cursor_start_measure.width);
+            let mut cursor = ctx.get_widget(self.cursor);
+            Cursor::cursor_x_set(&mut cursor, cursor_x);
+            Cursor::selection_x_set(&mut cursor, start_x.min(end_x));
+            Cursor::selection_width_set(&mut cursor, (end_x - start_x).abs());
 
-        let start_measure = self.measure(ctx, 0, start);
-        Cursor::selection_x_set(&mut ctx.get_widget(self.cursor), start_measure.width);
-        let length_measure = self.measure(ctx, start, end);
-        Cursor::selection_width_set(&mut ctx.get_widget(self.cursor), length_measure.width);
+            cursor_x
+        };
 
         if self.direction == Direction::None {
             return;
@@ -243,14 +254,14 @@ impl TextBehaviorState {
         let width = Cursor::bounds_ref(&ctx.get_widget(self.cursor)).width();
         let delta = width - offset;
 
-        if self.direction == Direction::Right && cursor_start_measure.width > delta {
-            let offset_delta = delta - cursor_start_measure.width;
+        if self.direction == Direction::Right && cursor_x > delta {
+            let offset_delta = delta - cursor_x;
             Cursor::offset_set(&mut ctx.get_widget(self.cursor), offset + offset_delta);
             TextBlock::offset_set(&mut ctx.get_widget(self.text_block), offset + offset_delta);
         }
 
-        if self.direction == Direction::Left && cursor_start_measure.width + offset < 0. {
-            let offset_delta = cursor_start_measure.width + offset;
+        if self.direction == Direction::Left && cursor_x + offset < 0. {
+            let offset_delta = cursor_x + offset;
             Cursor::offset_set(&mut ctx.get_widget(self.cursor), offset - offset_delta);
             TextBlock::offset_set(&mut ctx.get_widget(self.text_block), offset - offset_delta);
         }
@@ -304,6 +315,106 @@ impl TextBehaviorState {
 
     // -- Selection --
 
+    // -- Multi line --
+
+    fn move_cursor(&mut self, ctx: &mut Context, index: usize) {
+        let mut selection = self.selection(ctx);
+
+        self.direction = if index < selection.start() {
+            Direction::Left
+        } else {
+            Direction::Right
+        };
+
+        if self.is_shift_down(ctx) {
+            selection.set_start(index);
+        } else {
+            selection.set(index);
+        }
+
+        self.set_selection(ctx, selection);
+    }
+
+    fn move_to_line_start(&mut self, ctx: &mut Context) {
+        let line_starts = self.line_starts(ctx);
+        let line = line_index(&line_starts, self.selection(ctx).start());
+        let (start, _) = line_range(&line_starts, line, self.len(ctx));
+
+        self.move_cursor(ctx, start);
+    }
+
+    fn move_to_line_end(&mut self, ctx: &mut Context) {
+        let line_starts = self.line_starts(ctx);
+        let line = line_index(&line_starts, self.selection(ctx).start());
+        let (_, end) = line_range(&line_starts, line, self.len(ctx));
+
+        self.move_cursor(ctx, end);
+    }
+
+    // moves the cursor the given count of lines up (negative) or down and keeps its x position
+    fn move_lines(&mut self, ctx: &mut Context, lines: isize) {
+        let line_starts = self.line_starts(ctx);
+        let cursor = self.selection(ctx).start();
+        let line = line_index(&line_starts, cursor);
+        let target_line = (line as isize + lines)
+            .max(0)
+            .min(line_starts.len() as isize - 1) as usize;
+
+        // moves to the start of the first or to the end of the last line
+        if target_line == line {
+            if lines < 0 {
+                self.move_to_line_start(ctx);
+            } else {
+                self.move_to_line_end(ctx);
+            }
+            return;
+        }
+
+        let (x, _) = self.line_position(ctx, &line_starts, cursor, 0.);
+        let index = self.line_index_at(ctx, &line_starts, target_line, x);
+
+        self.move_cursor(ctx, index);
+    }
+
+    // gets the count of lines that fits in the target
+    fn page_lines(&self, ctx: &mut Context) -> isize {
+        let height = {
+            let target = ctx.get_widget(self.target);
+            let padding = *target.get::<Thickness>("padding");
+            target.get::<Rectangle>("bounds").height() - padding.top() - padding.bottom()
+        };
+
+        ((height / self.line_height(ctx)) as isize).max(1)
+    }
+
+    // updates the position of the cursor and the selection over multiple lines. Returns the x
+    // position of the cursor inside of its line.
+    fn update_multi_line_cursor(&mut self, ctx: &mut Context) -> f64 {
+        let line_starts = self.line_starts(ctx);
+        let line_height = self.line_height(ctx);
+        let selection = self.selection(ctx);
+        let (start, end) = self.selection_start_end(selection);
+
+        let (cursor_x, cursor_y) =
+            self.line_position(ctx, &line_starts, selection.start(), line_height);
+        let (start_x, start_y) = self.line_position(ctx, &line_starts, start, line_height);
+        let (end_x, end_y) = self.line_position(ctx, &line_starts, end, line_height);
+
+        let mut cursor = ctx.get_widget(self.cursor);
+        Cursor::line_height_set(&mut cursor, line_height);
+        Cursor::cursor_x_set(&mut cursor, cursor_x);
+        Cursor::cursor_y_set(&mut cursor, cursor_y);
+        Cursor::selection_x_set(&mut cursor, start_x);
+        Cursor::selection_y_set(&mut cursor, start_y);
+        Cursor::selection_end_x_set(&mut cursor, end_x);
+        Cursor::selection_end_y_set(&mut cursor, end_y);
+        Cursor::selection_width_set(&mut cursor, end_x - start_x);
+
+        cursor_x
+    }
+
+    // -- Multi line --
+
     fn activate(&self, ctx: &mut Context) {
         if *ctx.widget().get::<bool>("lose_focus_on_activation") {
             self.event_adapter
@@ -338,6 +449,37 @@ impl TextBehaviorState {
                     self.move_selection_right(ctx);
                 }
             }
+            Key::Up => {
+                if self.multi_line(ctx) {
+                    self.move_lines(ctx, -1);
+                }
+            }
+            Key::Down => {
+                if self.multi_line(ctx) {
+                    self.move_lines(ctx, 1);
+                }
+            }
+            Key::PageUp => {
+                if self.multi_line(ctx) {
+                    let lines = self.page_lines(ctx);
+                    self.move_lines(ctx, -lines);
+                }
+            }
+            Key::PageDown => {
+                if self.multi_line(ctx) {
+                    let lines = self.page_lines(ctx);
+                    self.move_lines(ctx, lines);
+                }
+            }
+            // on macOS and the web the home key is used as command key
+            Key::Home => {
+                if !cfg!(target_os = "macos") && !cfg!(target_arch = "wasm32") {
+                    self.move_to_line_start(ctx);
+                }
+            }
+            Key::End => {
+                self.move_to_line_end(ctx);
+            }
             Key::Backspace => {
                 self.back_space(ctx);
             }
@@ -345,7 +487,11 @@ impl TextBehaviorState {
                 self.delete(ctx);
             }
             Key::Enter => {
-                self.activate(ctx);
+                if self.multi_line(ctx) {
+                    self.insert_text("\n".to_string(), ctx);
+                } else {
+                    self.activate(ctx);
+                }
             }
             Key::X(..) => {
                 if self.is_ctlr_home_down(ctx) {
@@ -492,6 +638,64 @@ impl TextBehaviorState {
             .count()
     }
 
+    // gets the multi line state
+    fn multi_line(&self, ctx: &mut Context) -> bool {
+        *TextBehavior::multi_line_ref(&ctx.widget())
+    }
+
+    // gets the height of a text line
+    fn line_height(&self, ctx: &mut Context) -> f64 {
+        let font_size = *TextBehavior::font_size_ref(&ctx.widget());
+        font_size * *TextBlock::line_height_ref(&ctx.get_widget(self.text_block))
+    }
+
+    // gets the char indices of the line starts
+    fn line_starts(&self, ctx: &mut Context) -> Vec<usize> {
+        line_starts(TextBlock::text_ref(&ctx.get_widget(self.text_block)))
+    }
+
+    // gets the x position inside of its line and the y position of the char with the given index
+    fn line_position(
+        &self,
+        ctx: &mut Context,
+        line_starts: &[usize],
+        index: usize,
+        line_height: f64,
+    ) -> (f64, f64) {
+        let line = line_index(line_starts, index);
+        let (start, end) = line_range(line_starts, line, self.len(ctx));
+        let caret_positions = self.caret_positions(ctx, start, end);
+
+        (
+            caret_x(&caret_positions, index - start),
+            line as f64 * line_height,
+        )
+    }
+
+    // gets the index of the char of the given line that is next to the given x position
+    fn line_index_at(
+        &self,
+        ctx: &mut Context,
+        line_starts: &[usize],
+        line: usize,
+        x: f64,
+    ) -> usize {
+        let (start, end) = line_range(line_starts, line, self.len(ctx));
+
+        let mut index = start;
+        let mut distance = f64::MAX;
+
+        for (i, position) in self.caret_positions(ctx, start, end).iter().enumerate() {
+            let current_distance = (x - position).abs();
+            if current_distance < distance {
+                index = start + i;
+                distance = current_distance;
+            }
+        }
+
+        index
+    }
+
     // gets the focused state
     fn focused(&self, ctx: &mut Context) -> bool {
         *TextBehavior::focused_ref(&ctx.widget())
@@ -541,6 +745,10 @@ impl TextBehaviorState {
 
     // Get new position for the selection based on current mouse position
     fn get_new_selection_position(&self, ctx: &mut Context, position: Point) -> usize {
+        if self.multi_line(ctx) {
+            return self.get_new_multi_line_selection_position(ctx, position);
+        }
+
         if let Some((index, _x)) = self
             .map_chars_index_to_position(ctx)
             .iter()
@@ -552,6 +760,24 @@ impl TextBehaviorState {
         0
     }
 
+    // Get new position for the selection based on the current mouse position over multiple lines
+    fn get_new_multi_line_selection_position(&self, ctx: &mut Context, position: Point) -> usize {
+        let line_starts = self.line_starts(ctx);
+        let line_height = self.line_height(ctx);
+
+        let origin = {
+            let text_block = ctx.get_widget(self.text_block);
+            let offset = *TextBlock::offset_ref(&text_block);
+            let position = *text_block.get::<Point>("position");
+            Point::new(position.x() + offset, position.y())
+        };
+
+        let line = (((position.y() - origin.y()) / line_height).max(0.) as usize)
+            .min(line_starts.len() - 1);
+
+        self.line_index_at(ctx, &line_starts, line, position.x() - origin.x())
+    }
+
     // Returns a vector with a tuple of each char's starting index (usize) and position (f64)
     fn map_chars_index_to_position(&self, ctx: &mut Context) -> Vec<(usize, f64)> {
         let len = self.len(ctx);
@@ -562,24 +788,42 @@ impl TextBehaviorState {
             + *TextBlock::offset_ref(&ctx.get_widget(self.text_block));
 
         // array which will hold char index and it's x position
-        let mut position_index: Vec<(usize, f64)> = Vec::with_capacity(len);
-        position_index.push((0, start_position));
+        self.caret_positions(ctx, 0, len)
+            .iter()
+            .enumerate()
+            .map(|(i, x)| (i, start_position + x))
+            .collect()
+    }
 
-        for i in 0..len {
-            let bound_width: f64 = self.measure(ctx, 0, i + 1).width;
+    // gets the positions of the cursor in front of each char of the text part and at its end
+    fn caret_positions(&self, ctx: &mut Context, start: usize, end: usize) -> Vec<f64> {
+        let font = TextBehavior::font_clone(&ctx.widget());
+        let font_size = *TextBehavior::font_size_ref(&ctx.widget());
+        self.set_font_face(ctx);
 
-            let next_position: f64 = start_position + bound_width;
+        let text_part =
+            String16::from(TextBlock::text_ref(&ctx.get_widget(self.text_block)).as_str())
+                .get_string(start, end)
+                .unwrap_or_default();
 
-            position_index.push((i + 1, next_position));
-        }
+        ctx.render_context_2_d()
+            .measure_caret_positions(text_part.as_str(), font_size, font)
+    }
 
-        position_index
+    // the text is measured with the weight and style it is drawn with
+    fn set_font_face(&self, ctx: &mut Context) {
+        let font_weight = *TextBehavior::font_weight_ref(&ctx.widget());
+        let font_style = *TextBehavior::font_style_ref(&ctx.widget());
+
+        ctx.render_context_2_d().set_font_weight(font_weight);
+        ctx.render_context_2_d().set_font_style(font_style);
     }
 
     // measure text part
     fn measure(&self, ctx: &mut Context, start: usize, end: usize) -> TextMetrics {
         let font = TextBehavior::font_clone(&ctx.widget());
         let font_size = *TextBehavior::font_size_ref(&ctx.widget());
+        self.set_font_face(ctx);
 
         if let Some(text_part) =
             String16::from(TextBlock::text_ref(&ctx.get_widget(self.text_block)).as_str())
@@ -737,8 +981,11 @@ widget!(
     /// * select all text with Ctrl+A key combination
     /// * delete selected text with Backspace or Delete
     /// * move cursor by the left or right arrow keys or clicking with mouse
+    /// * move cursor to the start or end of the line by the Home or End key
+    /// * if `multi_line` is set, insert a new line on Enter and move cursor by the up or down
+    ///   arrow keys and the Page Up or Page Down key
     /// * delete characters by pressing the Backspace or the Delete key
-    /// * run on_activate() callback on pressing the Enter key
+    /// * run on_activate() callback on pressing the Enter key (if `multi_line` is not set)
     ///
     /// TextBehavior needs the following prerequisites to able to work:
     /// * a `cursor`: the [`Entity`] of a [`Cursor`] widget
@@ -824,6 +1071,12 @@ widget!(
         /// Sets or shares the font size property.
         font_size: f64,
 
+        /// Sets or shares the font weight property.
+        font_weight: FontWeight,
+
+        /// Sets or shares the font style property.
+        font_style: FontStyle,
+
         /// Sets or shares ta value that describes if the widget should lose focus on activation (when Enter pressed).
         lose_focus_on_activation: bool,
 
@@ -837,7 +1090,10 @@ widget!(
         selection: TextSelection,
 
         /// If set to `true` all character will be focused when the widget gets focus. Default is `true`
-        select_all_on_focus: bool
+        select_all_on_focus: bool,
+
+        /// If set to `true` the text can contain multiple lines. Default is `false`.
+        multi_line: bool
     }
 );
 
@@ -846,11 +1102,14 @@ impl Template for TextBehavior {
         self.name("TextBehavior")
             .font_size(orbtk_fonts::FONT_SIZE_12)
             .font("Roboto-Regular")
+            .font_weight(FontWeight::Normal)
+            .font_style(FontStyle::Normal)
             .text("")
             .selection(TextSelection::default())
             .focused(false)
             .lose_focus_on_activation(true)
             .select_all_on_focus(false)
+            .multi_line(false)
             .on_key_down(move |ctx, event| -> bool {
                 ctx.send_message(TextAction::KeyDown(event), id);
                 false
@@ -917,6 +1176,41 @@ fn move_selection_right(mut selection: TextSelection, len: usize) -> TextSelecti
     selection
 }
 
+// Returns the cursor position of the given char index or the end position if the index is out of
+// range.
+fn caret_x(caret_positions: &[f64], index: usize) -> f64 {
+    caret_positions
+        .get(index)
+        .or_else(|| caret_positions.last())
+        .copied()
+        .unwrap_or(0.)
+}
+
+// Returns the char indices of the starts of the lines of the given text.
+fn line_starts(text: &str) -> Vec<usize> {
+    iter::once(0)
+        .chain(
+            text.chars()
+                .enumerate()
+                .filter(|(_, c)| *c == '\n')
+                .map(|(i, _)| i + 1),
+        )
+        .collect()
+}
+
+// Returns the index of the line that contains the char with the given index.
+fn line_index(line_starts: &[usize], index: usize) -> usize {
+    line_starts.iter().rposition(|s| *s <= index).unwrap_or(0)
+}
+
+// Returns the start and end char index of the given line without its line break.
+fn line_range(line_starts: &[usize], line: usize, len: usize) -> (usize, usize) {
+    let start = line_starts[line];
+    let end = line_starts.get(line + 1).map_or(len, |s| s - 1);
+
+    (start, end)
+}
+
 // --- Helpers --
 
 #[cfg(test)]
@@ -978,4 +1272,41 @@ mod tests {
         assert_eq!(result.start(), 6);
         assert_eq!(result.end(), 6);
     }
+
+    #[test]
+    fn test_line_starts() {
+        assert_eq!(line_starts(""), vec![0]);
+        assert_eq!(line_starts("abc"), vec![0]);
+        assert_eq!(line_starts("ab\ncd\n\nü"), vec![0, 3, 6, 7]);
+    }
+
+    #[test]
+    fn test_line_index() {
+        let line_starts = line_starts("ab\ncd\n\nü");
+
+        assert_eq!(line_index(&line_starts, 0), 0);
+        assert_eq!(line_index(&line_starts, 2), 0);
+        assert_eq!(line_index(&line_starts, 3), 1);
+        assert_eq!(line_index(&line_starts, 6), 2);
+        assert_eq!(line_index(&line_starts, 8), 3);
+    }
+
+    #[test]
+    fn test_line_range() {
+        let line_starts = line_starts("ab\ncd\n\nü");
+
+        assert_eq!(line_range(&line_starts, 0, 8), (0, 2));
+        assert_eq!(line_range(&line_starts, 1, 8), (3, 5));
+        assert_eq!(line_range(&line_starts, 2, 8), (6, 6));
+        assert_eq!(line_range(&line_starts, 3, 8), (7, 8));
+    }
+
+    #[test]
+    fn test_caret_x() {
+        let caret_positions = vec![20., 10., 0.];
+
+        assert!((caret_x(&caret_positions, 1) - 10.).abs() < f64::EPSILON);
+        assert!((caret_x(&caret_positions, 5) - 0.).abs() < f64::EPSILON);
+        assert!((caret_x(&[], 0) - 0.).abs() < f64::EPSILON);
+    }
 }
@@ -19,6 +19,12 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the border style property.
+        border_style: BorderStyle,
+
+        /// Sets or shares the drop shadow property.
+        shadow: Shadow,
+
         /// Sets or shares the padding property.
         padding: Thickness,
 
@@ -34,6 +40,18 @@ widget!(
         /// Sets or shares the font property.
         font: String,
 
+        /// Sets or shares the font weight property.
+        font_weight: FontWeight,
+
+        /// Sets or shares the font style property.
+        font_style: FontStyle,
+
+        /// If set to `true` the text is underlined.
+        underline: bool,
+
+        /// If set to `true` the text is struck through.
+        strikethrough: bool,
+
         /// Sets or shares the icon property.
         icon: String,
 
@@ -46,9 +64,21 @@ widget!(
         /// Sets or shares the icon font property.
         icon_font: String,
 
+        /// Sets or shares the command property. The widget executes the command of the
+        /// `Commands` service with this key on click and is only enabled if the command can be
+        /// executed.
+        command: String,
+
+        /// Sets or shares the command enabled property. It is set to `false` while the command
+        /// of the widget can't be executed, the `enabled` property is kept.
+        command_enabled: bool,
+
         /// Sets or shares the pressed property.
         pressed: bool,
 
+        /// Sets or shares the focused property. It is set if the widget has the keyboard focus.
+        focused: bool,
+
         /// Sets or shares the spacing between icon and text.
         spacing: f64,
 
@@ -63,6 +93,7 @@ widget!(
 impl Template for Button {
     fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
         self.name("Button")
+            .role(Role::Button)
             .style("button")
             .height(36.0)
             .min_width(64.0)
@@ -70,16 +101,25 @@ impl Template for Button {
             .border_radius(4.0)
             .border_width(0.0)
             .border_brush("transparent")
+            .border_style("solid")
+            .shadow("none")
             .padding((16.0, 0.0, 16.0, 0.0))
             .foreground(colors::LINK_WATER_COLOR)
             .text("")
             .font_size(orbtk_fonts::FONT_SIZE_12)
             .font("Roboto-Regular")
+            .font_weight(FontWeight::Normal)
+            .font_style(FontStyle::Normal)
+            .underline(false)
+            .strikethrough(false)
             .icon("")
             .icon_font("MaterialIcons-Regular")
             .icon_size(orbtk_fonts::ICON_FONT_SIZE_12)
             .icon_brush(colors::LINK_WATER_COLOR)
             .pressed(false)
+            .command_enabled(true)
+            .focused(false)
+            .focusable(true)
             .spacing(8.0)
             .container_margin(0)
             .child(
@@ -93,6 +133,8 @@ impl Template for Button {
                             .border_radius(id)
                             .border_width(id)
                             .border_brush(id)
+                            .border_style(id)
+                            .shadow(id)
                             .padding(id)
                             .opacity(id)
                             .margin(("container_margin", id))
@@ -118,6 +160,10 @@ impl Template for Button {
                                             .text(id)
                                             .font_size(id)
                                             .font(id)
+                                            .font_weight(id)
+                                            .font_style(id)
+                                            .underline(id)
+                                            .strikethrough(id)
                                             .opacity(id)
                                             .build(ctx),
                                     )
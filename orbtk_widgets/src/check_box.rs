@@ -48,6 +48,9 @@ widget!(
         /// Sets or shares the pressed property.
         pressed: bool,
 
+        /// Sets or shares the focused property. It is set if the widget has the keyboard focus.
+        focused: bool,
+
         /// Sets or shares the selected property.
         selected: bool,
 
@@ -59,6 +62,7 @@ widget!(
 impl Template for CheckBox {
     fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
         self.name("CheckBox")
+            .role(Role::CheckBox)
             .style("check_box")
             .selected(false)
             .height(24.0)
@@ -76,6 +80,8 @@ impl Template for CheckBox {
             .icon_size(orbtk_fonts::ICON_FONT_SIZE_12)
             .icon_brush(colors::LINK_WATER_COLOR)
             .pressed(false)
+            .focused(false)
+            .focusable(true)
             .child(
                 MouseBehavior::new()
                     .pressed(id)
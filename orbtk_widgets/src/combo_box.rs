@@ -5,7 +5,9 @@ use std::{
 
 use super::behaviors::{MouseBehavior, SelectionBehavior};
 
-use crate::{api::prelude::*, prelude::*, proc_macros::*, themes::theme_orbtk::*};
+use crate::{
+    api::prelude::*, prelude::*, proc_macros::*, shell::prelude::Key, themes::theme_orbtk::*,
+};
 
 // --- KEYS --
 
@@ -19,6 +21,12 @@ type SelectedItem = Option<Entity>;
 #[derive(Debug, Copy, Clone)]
 enum Action {
     CheckMouseUpOutside { position: Point },
+    KeyDown(Key),
+}
+
+// Used to select a `ComboBoxItem` with the keyboard.
+enum ItemAction {
+    Select,
 }
 
 /// The `ComboBoxItemState` handles the interaction an selection of a `ComboBoxItem`.
@@ -39,6 +47,22 @@ impl ComboBoxItemState {
 }
 
 impl State for ComboBoxItemState {
+    fn messages(
+        &mut self,
+        mut messages: MessageReader,
+        registry: &mut Registry,
+        ctx: &mut Context,
+    ) {
+        for action in messages.read::<ItemAction>() {
+            match action {
+                ItemAction::Select => {
+                    self.toggle_selection();
+                    self.update(registry, ctx);
+                }
+            }
+        }
+    }
+
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
         let selected_index = ctx
             .get_widget(self.combo_box)
@@ -173,6 +197,7 @@ impl ComboBoxItem {
 impl Template for ComboBoxItem {
     fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
         self.name("ComboBoxItem")
+            .role(Role::ListItem)
             .style("combo_box_item")
             .min_width(64)
             .height(24)
@@ -217,6 +242,7 @@ pub struct ComboBoxState {
     builder: Option<Arc<RefCell<dyn Fn(&mut BuildContext, usize) -> Entity + 'static>>>,
     count: usize,
     items_panel: Entity,
+    items: Vec<Entity>,
     selected_container: Entity,
 }
 
@@ -233,19 +259,66 @@ impl ComboBoxState {
         let combo_box_global_bounds = Rectangle::new(combo_box_position, combo_box_bounds.size());
 
         if !combo_box_global_bounds.contains(p) {
-            ctx.widget().set("selected", false);
-            ctx.widget()
-                .get_mut::<Selector>("selector")
-                .remove_state("selected");
-            ctx.get_widget(self.popup)
-                .set("visibility", Visibility::Collapsed);
-            ctx.get_widget(self.popup).update(false);
-            ctx.widget().update(false);
+            self.close(ctx);
+        }
+    }
+
+    // closes the popup.
+    fn close(&self, ctx: &mut Context) {
+        ctx.widget().set("selected", false);
+        ctx.widget()
+            .get_mut::<Selector>("selector")
+            .remove_state("selected");
+        ctx.get_widget(self.popup)
+            .set("visibility", Visibility::Collapsed);
+        ctx.get_widget(self.popup).update(false);
+        ctx.widget().update(false);
+    }
+
+    // closes the popup with escape, the arrow keys select the items if the combobox is focused.
+    fn key_down(&self, key: Key, ctx: &mut Context) {
+        if key == Key::Escape {
+            if *ctx.widget().get::<bool>("selected") {
+                self.close(ctx);
+            }
+            return;
+        }
+
+        if !*ctx.widget().get::<bool>("focused") || self.items.is_empty() {
+            return;
+        }
+
+        let selected_index = *ctx.widget().get::<i32>("selected_index");
+        let last = self.items.len() as i32 - 1;
+
+        let index = match key {
+            Key::Up => (selected_index - 1).max(0),
+            Key::Down => (selected_index + 1).min(last),
+            Key::Home => 0,
+            Key::End => last,
+            _ => return,
+        };
+
+        if index != selected_index {
+            ctx.send_message(ItemAction::Select, self.items[index as usize]);
         }
     }
 }
 
 impl State for ComboBoxState {
+    fn messages(
+        &mut self,
+        mut messages: MessageReader,
+        _registry: &mut Registry,
+        ctx: &mut Context,
+    ) {
+        for action in messages.read::<Action>() {
+            if let Action::KeyDown(key) = action {
+                self.key_down(key, ctx);
+            }
+        }
+    }
+
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
         let count = ctx.widget().clone_or_default::<usize>("count");
         let entity = ctx.entity();
@@ -254,6 +327,7 @@ impl State for ComboBoxState {
         if count != self.count {
             if let Some(builder) = &self.builder {
                 ctx.clear_children_of(self.items_panel);
+                self.items.clear();
 
                 for i in 0..count {
                     let item = {
@@ -292,6 +366,7 @@ impl State for ComboBoxState {
 
                         item
                     };
+                    self.items.push(item);
                     ctx.get_widget(item).update_widget(entity, false, false);
                 }
             }
@@ -310,6 +385,7 @@ impl State for ComboBoxState {
                 Action::CheckMouseUpOutside { position } => {
                     self.close_popup(ctx, position);
                 }
+                Action::KeyDown(_) => {}
             }
         }
     }
@@ -322,10 +398,12 @@ impl State for ComboBoxState {
 // todo use code of list view item, by create combobox item insert entity of popup container
 
 widget!(
-    /// The `ComboBox` represents an selection widget with a drop-down list.
+    /// The `ComboBox` represents an selection widget with a drop-down list. If the combobox is
+    /// focused, `Space` and `Enter` open and close the drop-down and the arrow keys select the
+    /// items. `Escape` closes the drop-down.
     ///
     /// **style:** `combo_box`
-    ComboBox<ComboBoxState>: MouseHandler {
+    ComboBox<ComboBoxState>: MouseHandler, KeyDownHandler {
         /// Sets or shares the background property.
         background: Brush,
 
@@ -362,6 +440,9 @@ widget!(
         /// Sets or shares the pressed property.
         pressed: bool,
 
+        /// Sets or shares the focused property. It is set if the widget has the keyboard focus.
+        focused: bool,
+
         /// Sets or shares the flag if the drop down is open.
         selected: bool,
 
@@ -456,6 +537,7 @@ impl Template for ComboBox {
         let _ = ctx.append_child_to_overlay(popup);
 
         self.name("ComboBox")
+            .role(Role::ComboBox)
             .style("combo_box")
             .icon(material_icons_font::MD_ARROW_DROP_DOWN)
             .icon_font("MaterialIcons-Regular")
@@ -465,6 +547,8 @@ impl Template for ComboBox {
             .min_width(80.0)
             .selected(false)
             .selected_index(-1)
+            .focused(false)
+            .focusable(true)
             .child(
                 MouseBehavior::new()
                     .pressed(id)
@@ -487,5 +571,14 @@ impl Template for ComboBox {
                         position: e.position,
                     })
             })
+            .on_key_down(move |states, event| {
+                match event.key {
+                    Key::Up | Key::Down | Key::Home | Key::End | Key::Escape => {
+                        states.send_message(Action::KeyDown(event.key), id);
+                    }
+                    _ => {}
+                }
+                false
+            })
     }
 }
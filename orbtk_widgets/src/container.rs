@@ -15,6 +15,12 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the border style property.
+        border_style: BorderStyle,
+
+        /// Sets or shares the drop shadow property.
+        shadow: Shadow,
+
         /// Sets or shares the padding property.
         padding: Thickness
     }
@@ -28,6 +34,8 @@ impl Template for Container {
             .border_radius(0.0)
             .border_width(0.0)
             .border_brush("transparent")
+            .border_style("solid")
+            .shadow("none")
     }
 
     fn render_object(&self) -> Box<dyn RenderObject> {
@@ -37,6 +37,23 @@ widget!(
         /// Defines the x position of the cursor.
         cursor_x: f64,
 
+        /// Defines the y position of the cursor. Only used if `line_height` is greater than `0`.
+        cursor_y: f64,
+
+        /// Defines the y position of the first line of the selection.
+        selection_y: f64,
+
+        /// Defines the end position of the selection on the x axis if the selection spans
+        /// multiple lines.
+        selection_end_x: f64,
+
+        /// Defines the y position of the last line of the selection.
+        selection_end_y: f64,
+
+        /// Defines the height of a text line. If set to `0` the cursor and the selection fill
+        /// the whole height of the cursor widget.
+        line_height: f64,
+
         /// Defines the of the cursor.
         offset: f64
     }
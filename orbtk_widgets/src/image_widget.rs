@@ -17,7 +17,10 @@ widget!(
 
 impl Template for ImageWidget {
     fn template(self, _: Entity, _: &mut BuildContext) -> Self {
-        self.name("ImageWidget").style("image-widget").image("")
+        self.name("ImageWidget")
+            .role(Role::Image)
+            .style("image-widget")
+            .image("")
     }
 
     fn render_object(&self) -> Box<dyn RenderObject> {
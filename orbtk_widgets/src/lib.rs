@@ -33,6 +33,7 @@ pub use self::slider::*;
 pub use self::stack::*;
 pub use self::switch::*;
 pub use self::tab_widget::*;
+pub use self::text_area::*;
 pub use self::text_block::*;
 pub use self::text_box::*;
 pub use self::toggle_button::*;
@@ -63,6 +64,7 @@ mod slider;
 mod stack;
 mod switch;
 mod tab_widget;
+mod text_area;
 mod text_block;
 mod text_box;
 pub mod themes;
@@ -248,6 +248,7 @@ widget!(
 impl Template for ListViewItem {
     fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
         self.name("ListViewItem")
+            .role(Role::ListItem)
             .style("list_view_item")
             .min_width(64.0)
             .height(24.0)
@@ -348,6 +349,7 @@ impl Template for ListView {
             .build(ctx);
 
         self.name("ListView")
+            .role(Role::List)
             .style("list_view")
             .background(colors::LYNCH_COLOR)
             .border_radius(2.0)
@@ -226,6 +226,7 @@ widget!(
 impl Template for NumericBox {
     fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
         self.name("NumericBox")
+            .role(Role::SpinButton)
             .style("numeric_box")
             .background("transparent")
             .foreground(colors::LINK_WATER_COLOR)
@@ -233,6 +234,7 @@ impl Template for NumericBox {
             .border_width(1.0)
             .border_radius(3.0)
             .focused(false)
+            .focusable(true)
             .height(32.0)
             .lose_focus_on_activation(true)
             .min(0.0)
@@ -285,6 +287,7 @@ impl Template for NumericBox {
                     .child(
                         Button::new()
                             .style("button_small")
+                            .focusable(false)
                             .attach(Grid::column(1))
                             .attach(Grid::row(0))
                             .min_width(14)
@@ -302,6 +305,7 @@ impl Template for NumericBox {
                     .child(
                         Button::new()
                             .style("button_small")
+                            .focusable(false)
                             .attach(Grid::column(1))
                             .attach(Grid::row(1))
                             .min_width(14)
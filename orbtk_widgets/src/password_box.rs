@@ -148,6 +148,7 @@ impl Template for PasswordBox {
         self.state_mut().text_behavior = text_behavior;
 
         self.name("PasswordBox")
+            .role(Role::PasswordInput)
             .style(STYLE_TEXT_BOX)
             .echo('*')
             .text("")
@@ -164,6 +165,7 @@ impl Template for PasswordBox {
             .min_width(128.0)
             .height(32.0)
             .focused(false)
+            .focusable(true)
             .lose_focus_on_activation(true)
             .select_all_on_focus(true)
             .child(text_behavior)
@@ -1,9 +1,28 @@
+use std::time::Duration;
+
 use crate::{api::prelude::*, proc_macros::*};
 
+// Duration of the fade if the theme defines no transition of the popup opacity.
+const FADE_DURATION: Duration = Duration::from_millis(150);
+
 /// The `PopupState` handles the open and close behavior of the `Popup` widget.
 #[derive(Default, AsAny)]
 pub struct PopupState {}
 
+impl PopupState {
+    // fades the opacity of the popup to the given value
+    fn fade(&self, ctx: &mut Context, opacity: f32) {
+        let selector = ctx.widget().clone::<Selector>("selector");
+        let transition = ctx
+            .theme
+            .transition(&selector, "opacity")
+            .unwrap_or_else(|| Transition::new(FADE_DURATION).easing(Easing::EaseOut));
+        let entity = ctx.entity();
+
+        ctx.transition(entity, "opacity", opacity, transition);
+    }
+}
+
 impl State for PopupState {
     fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
         ctx.widget().set("visibility", Visibility::Hidden)
@@ -13,9 +32,21 @@ impl State for PopupState {
         let visibility = ctx.widget().clone::<Visibility>("visibility");
         let open = *ctx.widget().get::<bool>("open");
 
-        if open && visibility != Visibility::Visible {
-            ctx.widget().set("visibility", Visibility::Visible);
-        } else if !open && visibility == Visibility::Visible {
+        if open {
+            // fades in from transparent
+            if visibility != Visibility::Visible {
+                ctx.widget().set("opacity", 0.0_f32);
+                ctx.widget().set("visibility", Visibility::Visible);
+            }
+
+            self.fade(ctx, 1.0);
+        } else if visibility == Visibility::Visible {
+            // the popup stays visible until it is faded out, the opacity is updated on each frame
+            if *ctx.widget().get::<f32>("opacity") > 0.0 {
+                self.fade(ctx, 0.0);
+                return;
+            }
+
             // todo (workaround) should be collapsed but is set to hidden to force the layout to calculate.
             // There is an issue with the ScrollIndicator that does now update on open.
             ctx.widget().set("visibility", Visibility::Hidden);
@@ -60,14 +91,22 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the drop shadow property.
+        shadow: Shadow,
+
         /// Sets or shares the padding property.
         padding: Thickness,
 
         /// Sets or shares the target id to place the popup.
         target: u32,
 
-        /// Sets or shares the value if the popup is open and visible.
-        open: bool
+        /// Sets or shares the value if the popup is open and visible. The popup fades in when it is
+        /// opened and stays visible until it is faded out after it is closed.
+        open: bool,
+
+        /// Sets or shares the modal property. If set to `true` the `Tab` key only moves the
+        /// focus between the children of the popup while it is open.
+        modal: bool
     }
 );
 
@@ -76,11 +115,13 @@ impl Template for Popup {
         self.name("Popup")
             .style("popup")
             .open(false)
+            .modal(false)
             .padding(0.0)
             .background("transparent")
             .border_radius(0.0)
             .border_width(0.0)
             .border_brush("transparent")
+            .shadow("none")
             .on_mouse_down(|_, _| true)
     }
 
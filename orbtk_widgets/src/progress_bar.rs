@@ -107,6 +107,7 @@ widget!(
 impl Template for ProgressBar {
     fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
         self.name("ProgressBar")
+            .role(Role::ProgressBar)
             .style(STYLE_PROGRESS_BAR)
             .val(0.0)
             .background("#000000")
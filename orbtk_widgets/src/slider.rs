@@ -1,4 +1,4 @@
-use crate::{api::prelude::*, prelude::*, proc_macros::*};
+use crate::{api::prelude::*, prelude::*, proc_macros::*, shell::prelude::Key};
 
 // --- KEYS --
 pub static STYLE_SLIDER: &str = "slider";
@@ -10,6 +10,7 @@ static ID_ACCENT_TRACK: &str = "id_accent_track";
 #[derive(Copy, Clone)]
 enum SliderAction {
     Move { mouse_x: f64 },
+    KeyDown(Key),
 }
 
 /// The `SliderState` is used to manipulate the position of the thumb of the slider widget.
@@ -22,6 +23,7 @@ pub struct SliderState {
     thumb: Entity,
     track: Entity,
     accent_track: Entity,
+    focused: bool,
 }
 
 impl SliderState {
@@ -95,6 +97,49 @@ impl SliderState {
             .get_mut::<Thickness>("margin")
             .set_left(thumb_x);
     }
+
+    // changes the val with the keyboard if the slider is focused
+    fn change_by_key(&mut self, key: Key, ctx: &mut Context) {
+        if !*ctx.widget().get::<bool>("focused") {
+            return;
+        }
+
+        if let Some(val) = calculate_val_by_key(
+            key,
+            *ctx.widget().get::<f64>("val"),
+            *ctx.widget().get::<f64>("step"),
+            *ctx.widget().get::<f64>("min"),
+            *ctx.widget().get::<f64>("max"),
+        ) {
+            ctx.widget().set("val", val);
+
+            if self.adjust(ctx) {
+                self.adjust_thumb_x(ctx);
+            }
+        }
+    }
+
+    // shows the keyboard focus of the slider on the thumb
+    fn update_focus(&mut self, ctx: &mut Context) {
+        let focused = *ctx.widget().get::<bool>("focused");
+
+        if focused == self.focused {
+            return;
+        }
+
+        self.focused = focused;
+        let mut thumb = ctx.get_widget(self.thumb);
+
+        if focused {
+            thumb.get_mut::<Selector>("selector").push_state("focused");
+        } else {
+            thumb
+                .get_mut::<Selector>("selector")
+                .remove_all_similar_states("focused");
+        }
+
+        thumb.update(false);
+    }
 }
 
 impl State for SliderState {
@@ -106,6 +151,10 @@ impl State for SliderState {
         // todo call update
     }
 
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.update_focus(ctx);
+    }
+
     fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
         if let Some(action) = self.action {
             match action {
@@ -143,6 +192,9 @@ impl State for SliderState {
                         ctx.widget().clear_dirty();
                     }
                 }
+                SliderAction::KeyDown(key) => {
+                    self.change_by_key(key, ctx);
+                }
             }
 
             self.action = None;
@@ -156,7 +208,8 @@ impl State for SliderState {
 }
 
 widget!(
-    /// The `Slider` allows to use a val in a range of values.
+    /// The `Slider` allows to use a val in a range of values. If the slider is focused, the arrow
+    /// keys change the val by `step`, `Home` and `End` set it to `min` and `max`.
     ///
     /// **style:** `slider`
     ///
@@ -165,7 +218,7 @@ widget!(
     /// ```rust
     /// Slider::new().min(0).max(100).val(50).build(ctx)
     /// ```
-    Slider<SliderState>: MouseHandler {
+    Slider<SliderState>: MouseHandler, KeyDownHandler {
         /// Sets or shares the min val of the range.
         min: f64,
 
@@ -175,6 +228,12 @@ widget!(
         /// Sets or shares the current val of the range.
         val: f64,
 
+        /// Sets or shares the value the val is changed by with the arrow keys.
+        step: f64,
+
+        /// Sets or shares the focused property. It is set if the widget has the keyboard focus.
+        focused: bool,
+
         /// Sets or shares the background property.
         background: Brush,
 
@@ -204,10 +263,14 @@ widget!(
 impl Template for Slider {
     fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
         self.name("Slider")
+            .role(Role::Slider)
             .style(STYLE_SLIDER)
             .min(0.0)
             .max(100.0)
             .val(0.0)
+            .step(1.0)
+            .focused(false)
+            .focusable(true)
             .height(24.0)
             .border_radius(2.0)
             .container_margin((0, 11, 0, 11))
@@ -246,6 +309,7 @@ impl Template for Slider {
                     .child(
                         Button::new()
                             .style("thumb")
+                            .focusable(false)
                             .opacity(id)
                             .id(ID_THUMB)
                             .v_align("center")
@@ -263,6 +327,17 @@ impl Template for Slider {
                     .action(SliderAction::Move { mouse_x: p.x() });
                 false
             })
+            .on_key_down(move |states, event| {
+                match event.key {
+                    Key::Left | Key::Right | Key::Up | Key::Down | Key::Home | Key::End => {
+                        states
+                            .get_mut::<SliderState>(id)
+                            .action(SliderAction::KeyDown(event.key));
+                    }
+                    _ => {}
+                }
+                false
+            })
     }
 }
 
@@ -316,6 +391,16 @@ fn calculate_thumb_x_from_val(
     (val / (max - min)) * (track_width - thumb_width)
 }
 
+fn calculate_val_by_key(key: Key, val: f64, step: f64, min: f64, max: f64) -> Option<f64> {
+    match key {
+        Key::Left | Key::Down => Some(adjust_val(val - step, min, max)),
+        Key::Right | Key::Up => Some(adjust_val(val + step, min, max)),
+        Key::Home => Some(min),
+        Key::End => Some(max),
+        _ => None,
+    }
+}
+
 // --- Helpers --
 
 #[cfg(test)]
@@ -371,4 +456,16 @@ mod tests {
         assert!((34.0 - calculate_thumb_x_from_val(50.0, 0.0, 100.0, 100.0, 32.0)).abs() < ERROR);
         assert!((68.0 - calculate_thumb_x_from_val(100.0, 0.0, 100.0, 100.0, 32.0)).abs() < ERROR);
     }
+
+    #[test]
+    fn test_calculate_val_by_key() {
+        let val = |key| calculate_val_by_key(key, 98.0, 5.0, 0.0, 100.0).unwrap();
+
+        assert!((93.0 - val(Key::Left)).abs() < ERROR);
+        assert!((93.0 - val(Key::Down)).abs() < ERROR);
+        assert!((100.0 - val(Key::Up)).abs() < ERROR);
+        assert!((0.0 - val(Key::Home)).abs() < ERROR);
+        assert!((100.0 - val(Key::End)).abs() < ERROR);
+        assert!(calculate_val_by_key(Key::Enter, 98.0, 5.0, 0.0, 100.0).is_none());
+    }
 }
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use super::behaviors::MouseBehavior;
 
 use crate::{api::prelude::*, prelude::*, proc_macros::*};
@@ -31,9 +33,20 @@ impl SwitchState {
         ctx.widget().update(false);
     }
 
-    // update the visual state to the selection state.
-    fn update_visual(&self, ctx: &mut Context) {
+    // update the visual state to the selection state. If `animate` is set the toggle slides
+    // from its current to its new position.
+    fn update_visual(&self, ctx: &mut Context, animate: bool) {
         let selected: bool = *Switch::selected_ref(&ctx.widget());
+
+        // position of the toggle before its alignment is changed
+        let (x, travel) = {
+            let width = ctx.widget().get::<Rectangle>("bounds").width();
+            let bounds = ctx
+                .get_widget(self.switch_toggle)
+                .clone::<Rectangle>("bounds");
+            (bounds.x(), width - bounds.width())
+        };
+
         let mut switch_toggle = ctx.get_widget(self.switch_toggle);
 
         if selected {
@@ -49,13 +62,36 @@ impl SwitchState {
         }
 
         switch_toggle.update(true);
+
+        if animate && travel > 0.0 {
+            self.animate_toggle(ctx, selected, x, travel);
+        }
+    }
+
+    // keeps the toggle at the position `x` by its margin after the alignment has changed and
+    // animates the margin to zero. The layout moves the toggle on each frame.
+    fn animate_toggle(&self, ctx: &mut Context, selected: bool, x: f64, travel: f64) {
+        let margin = if selected {
+            Thickness::new(0.0, 0.0, (travel - x).max(0.0), 0.0)
+        } else {
+            Thickness::new(x.max(0.0), 0.0, 0.0, 0.0)
+        };
+
+        ctx.get_widget(self.switch_toggle).set("margin", margin);
+        ctx.animate(
+            self.switch_toggle,
+            "margin",
+            Animation::new(margin, Thickness::default())
+                .duration(Duration::from_millis(150))
+                .easing(Easing::EaseOut),
+        );
     }
 }
 
 impl State for SwitchState {
     fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
         self.switch_toggle = ctx.child(ID_SWITCH_TOGGLE).entity();
-        self.update_visual(ctx);
+        self.update_visual(ctx, false);
     }
 
     fn messages(
@@ -67,7 +103,7 @@ impl State for SwitchState {
         for message in messages.read::<SwitchAction>() {
             match message {
                 SwitchAction::ToggleSelection => self.toggle_selection(ctx),
-                SwitchAction::SelectionChanged => self.update_visual(ctx),
+                SwitchAction::SelectionChanged => self.update_visual(ctx, true),
             }
         }
     }
@@ -102,6 +138,9 @@ widget!(
         /// Sets or shares the pressed property.
         pressed: bool,
 
+        /// Sets or shares the focused property. It is set if the widget has the keyboard focus.
+        focused: bool,
+
         /// Sets or shares the selected property.
         selected: bool,
 
@@ -116,8 +155,11 @@ widget!(
 impl Template for Switch {
     fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
         self.name("Switch")
+            .role(Role::Switch)
             .style("switch")
             .pressed(false)
+            .focused(false)
+            .focusable(true)
             .selected(false)
             .width(36.0)
             .height(30.0)
@@ -1,6 +1,8 @@
 use super::behaviors::MouseBehavior;
 
-use crate::{api::prelude::*, prelude::*, proc_macros::*, themes::theme_orbtk::*};
+use crate::{
+    api::prelude::*, prelude::*, proc_macros::*, shell::prelude::Key, themes::theme_orbtk::*,
+};
 
 // --- KEYS --
 const TAB_HEADER_CONTAINER: &str = "tab_header_container";
@@ -142,6 +144,7 @@ impl Template for TabHeader {
         let mut button = Button::new()
             .style("tab_icon_only")
             .icon(material_icons_font::MD_CLOSE)
+            .focusable(false)
             .visibility(("close_button", id));
 
         if let Some(callback) = self.state.on_close_click_callback.take() {
@@ -156,6 +159,7 @@ impl Template for TabHeader {
         }
 
         self.name("TabHeader")
+            .role(Role::Tab)
             .style("tab_header")
             .selected(false)
             .min_width(64)
@@ -225,6 +229,7 @@ enum TabWidgetAction {
     Add(String, Entity),
     Remove(Entity),
     SetCloseButtonVisibility(bool),
+    KeyDown(Key),
 }
 /**
 Through the TabWidgetState it is possible to control the behaviour of TabWidget.
@@ -427,6 +432,21 @@ impl TabWidgetState {
         }
     }
 
+    //Select the previous or next tab with the arrow keys if the widget is focused. For internal use.
+    fn key_down_internal(&mut self, ctx: &mut Context, key: Key) {
+        if self.tabs.is_empty() || !*ctx.widget().get::<bool>("focused") {
+            return;
+        }
+
+        match key {
+            Key::Left if self.selected > 0 => self.select_by_index_internal(ctx, self.selected - 1),
+            Key::Right => self.select_by_index_internal(ctx, self.selected + 1),
+            Key::Home => self.select_by_index_internal(ctx, 0),
+            Key::End => self.select_by_index_internal(ctx, self.tabs.len() - 1),
+            _ => {}
+        }
+    }
+
     //Create a new TabHeader entity and return it. For internal use.
     fn create_tab_header(&self, ctx: &mut Context, text: String, body: Entity) -> Entity {
         let cloned_entity = ctx.entity();
@@ -482,6 +502,9 @@ impl State for TabWidgetState {
                 TabWidgetAction::SetCloseButtonVisibility(value) => {
                     self.set_close_button_visibility_internal(ctx, value);
                 }
+                TabWidgetAction::KeyDown(key) => {
+                    self.key_down_internal(ctx, key);
+                }
             }
         }
     }
@@ -499,8 +522,10 @@ widget!(
     .tab("Tab header 3",TextBlock::new().text("Tab content 3").build(ctx))
     .build(ctx)
      ```
+
+    If the widget is focused, the left and right arrow keys switch to the previous and next tab.
      */
-    TabWidget<TabWidgetState> {
+    TabWidget<TabWidgetState>: KeyDownHandler {
         /// Sets or shares the spacing between tabs.
         spacing: f64,
 
@@ -517,7 +542,10 @@ widget!(
         border_brush: Brush,
 
         /// Sets or shares the padding property.
-        padding: Thickness
+        padding: Thickness,
+
+        /// Sets or shares the focused property. It is set if the widget has the keyboard focus.
+        focused: bool
     }
 );
 
@@ -540,27 +568,44 @@ impl TabWidget {
 
 impl Template for TabWidget {
     fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
-        self.name("TabWidget").style("tab_widget").child(
-            Grid::new()
-                .rows("34, *")
-                .child(
-                    Stack::new()
-                        .id(HEADER_CONTAINER)
-                        .orientation("horizontal")
-                        .spacing(id)
-                        .build(ctx),
-                )
-                .child(
-                    Container::new()
-                        .id(BODY_CONTAINER)
-                        .background(id)
-                        .border_brush(id)
-                        .border_width(id)
-                        .border_radius(id)
-                        .attach(Grid::row(1))
-                        .build(ctx),
-                )
-                .build(ctx),
-        )
+        self.name("TabWidget")
+            .role(Role::TabList)
+            .style("tab_widget")
+            .focused(false)
+            .focusable(true)
+            .on_key_down(move |states, event| {
+                match event.key {
+                    Key::Left | Key::Right | Key::Home | Key::End => {
+                        states
+                            .get_mut::<TabWidgetState>(id)
+                            .actions
+                            .push(TabWidgetAction::KeyDown(event.key));
+                    }
+                    _ => {}
+                }
+                false
+            })
+            .child(
+                Grid::new()
+                    .rows("34, *")
+                    .child(
+                        Stack::new()
+                            .id(HEADER_CONTAINER)
+                            .orientation("horizontal")
+                            .spacing(id)
+                            .build(ctx),
+                    )
+                    .child(
+                        Container::new()
+                            .id(BODY_CONTAINER)
+                            .background(id)
+                            .border_brush(id)
+                            .border_width(id)
+                            .border_radius(id)
+                            .attach(Grid::row(1))
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
     }
 }
@@ -0,0 +1,213 @@
+use super::behaviors::{TextAction, TextBehavior};
+
+use crate::{api::prelude::*, prelude::*, proc_macros::*, themes::theme_orbtk::*};
+
+// --- KEYS --
+pub static STYLE_TEXT_AREA: &str = "text_area";
+static ID_CURSOR: &str = "id_cursor";
+static ID_SCROLL_VIEWER: &str = "id_scroll_viewer";
+// --- KEYS --
+
+/// The `TextAreaState` scrolls the text of the `TextArea` to keep the cursor visible.
+#[derive(Default, AsAny)]
+pub struct TextAreaState {
+    cursor: Entity,
+    scroll_viewer: Entity,
+    cursor_y: f64,
+}
+
+impl TextAreaState {
+    fn scroll_to_cursor(&mut self, ctx: &mut Context) {
+        let (cursor_y, line_height) = {
+            let cursor = ctx.get_widget(self.cursor);
+            (
+                *Cursor::cursor_y_ref(&cursor),
+                *Cursor::line_height_ref(&cursor),
+            )
+        };
+
+        // only scrolls if the cursor is moved, otherwise scrolling by mouse wheel is not possible
+        if (cursor_y - self.cursor_y).abs() < f64::EPSILON {
+            return;
+        }
+
+        self.cursor_y = cursor_y;
+
+        let mut scroll_viewer = ctx.get_widget(self.scroll_viewer);
+        let height = scroll_viewer.get::<Rectangle>("bounds").height();
+        let mut padding = *ScrollViewer::padding_ref(&scroll_viewer);
+
+        if cursor_y + padding.top() < 0. {
+            padding.set_top(-cursor_y);
+        } else if cursor_y + line_height + padding.top() > height {
+            padding.set_top((height - cursor_y - line_height).min(0.));
+        } else {
+            return;
+        }
+
+        ScrollViewer::padding_set(&mut scroll_viewer, padding);
+    }
+}
+
+impl State for TextAreaState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.cursor = ctx
+            .entity_of_child(ID_CURSOR)
+            .expect("TextAreaState.init: Cursor child could not be found.");
+        self.scroll_viewer = ctx
+            .entity_of_child(ID_SCROLL_VIEWER)
+            .expect("TextAreaState.init: ScrollViewer child could not be found.");
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.scroll_to_cursor(ctx);
+    }
+}
+
+widget!(
+    /// The `TextArea` widget represents a multi line text input widget.
+    ///
+    /// Enter inserts a new line. The cursor can be moved between the lines by the arrow, Home,
+    /// End, Page Up and Page Down keys. If the text is higher than the widget, it can be scrolled
+    /// vertically. Lines are not wrapped.
+    ///
+    /// * style: `text_area`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// TextArea::new().text("first line\nsecond line").build(ctx)
+    /// ```
+    TextArea<TextAreaState>: KeyDownHandler,
+    TextInputHandler {
+        /// Sets or shares the text property.
+        text: String,
+
+        /// Sets or shares the water_mark text property.
+        water_mark: String,
+
+        /// Sets or shares the text selection property.
+        selection: TextSelection,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or shares the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: f64,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the focused property.
+        focused: bool,
+
+        /// Used to request focus from outside. Set to `true` tor request focus.
+        request_focus: bool,
+
+        /// If set to `true` all character will be focused when the widget gets focus. Default is `false`
+        select_all_on_focus: bool,
+
+        /// Indicates if the widget is hovered by the mouse cursor.
+        hover: bool
+    }
+);
+
+impl Template for TextArea {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let text_block = TextBlock::new()
+            .v_align("start")
+            .h_align("start")
+            .foreground(id)
+            .text(id)
+            .water_mark(id)
+            .font(id)
+            .font_size(id)
+            .localizable(false)
+            .build(ctx);
+
+        let cursor = Cursor::new().id(ID_CURSOR).selection(id).build(ctx);
+
+        let text_behavior = TextBehavior::new()
+            .cursor(cursor.0)
+            .target(id.0)
+            .text_block(text_block.0)
+            .focused(id)
+            .font(id)
+            .font_size(id)
+            .select_all_on_focus(id)
+            .request_focus(id)
+            .text(id)
+            .selection(id)
+            .multi_line(true)
+            .build(ctx);
+
+        let content = Grid::new()
+            .v_align("start")
+            .child(cursor)
+            .child(text_block)
+            .build(ctx);
+
+        let scroll_viewer = ScrollViewer::new()
+            .id(ID_SCROLL_VIEWER)
+            .mode(("disabled", "auto"))
+            .child(content)
+            .build(ctx);
+
+        self.name("TextArea")
+            .role(Role::TextArea)
+            .style(STYLE_TEXT_AREA)
+            .text("")
+            .foreground(colors::LINK_WATER_COLOR)
+            .font_size(orbtk_fonts::FONT_SIZE_12)
+            .font("Roboto-Regular")
+            .selection(TextSelection::default())
+            .padding(4.0)
+            .background(colors::LYNCH_COLOR)
+            .border_brush("transparent")
+            .border_width(0.0)
+            .border_radius(2.0)
+            .min_width(128.0)
+            .height(96.0)
+            .focused(false)
+            .focusable(true)
+            .select_all_on_focus(false)
+            .child(text_behavior)
+            .child(
+                Container::new()
+                    .background(id)
+                    .border_radius(id)
+                    .border_width(id)
+                    .border_brush(id)
+                    .padding(id)
+                    .child(scroll_viewer)
+                    .child(
+                        ScrollIndicator::new()
+                            .padding(2.0)
+                            .content_bounds(("bounds", content))
+                            .view_port_bounds(("bounds", scroll_viewer))
+                            .scroll_padding(("padding", scroll_viewer))
+                            .mode(scroll_viewer)
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+            .on_changed("text", move |ctx, _| {
+                ctx.send_message(TextAction::ForceUpdate(false), text_behavior);
+            })
+    }
+}
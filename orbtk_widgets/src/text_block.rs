@@ -2,11 +2,15 @@ use crate::{api::prelude::*, proc_macros::*, themes::theme_orbtk::*};
 
 enum TextAction {
     Localize,
+    Press(Point),
+    Release(Point),
 }
 
-/// Handles the localization of the text.
+/// Handles the localization of the text and clicks on links of rich text.
 #[derive(Debug, Clone, Default, AsAny)]
-pub struct TextBlockState;
+pub struct TextBlockState {
+    pressed_link: Option<String>,
+}
 
 impl TextBlockState {
     fn localize(&self, ctx: &mut Context) {
@@ -19,6 +23,72 @@ impl TextBlockState {
 
         TextBlock::localized_text_set(&mut ctx.widget(), localized_text);
     }
+
+    // Remembers the link under the mouse if the mouse button is pressed.
+    fn press(&mut self, position: Point, ctx: &mut Context) {
+        self.pressed_link = self.link_at(position, ctx);
+    }
+
+    // Raises a `LinkClickedEvent` if the mouse button is released over the pressed link.
+    fn release(&mut self, position: Point, ctx: &mut Context) {
+        let pressed_link = match self.pressed_link.take() {
+            Some(link) => link,
+            None => return,
+        };
+
+        if self.link_at(position, ctx).as_ref() != Some(&pressed_link) {
+            return;
+        }
+
+        let entity = ctx.entity();
+        ctx.event_adapter()
+            .push_event_direct(entity, LinkClickedEvent(entity, pressed_link));
+    }
+
+    // Returns the link of the span at the given position.
+    fn link_at(&self, position: Point, ctx: &mut Context) -> Option<String> {
+        let spans = TextBlock::spans_clone(&ctx.widget());
+
+        if !spans.has_links() {
+            return None;
+        }
+
+        let font = TextBlock::font_clone(&ctx.widget());
+        let font_size = *TextBlock::font_size_ref(&ctx.widget());
+        let font_weight = *TextBlock::font_weight_ref(&ctx.widget());
+        let font_style = *TextBlock::font_style_ref(&ctx.widget());
+        let text_wrap = *TextBlock::text_wrap_ref(&ctx.widget());
+        let line_height = *TextBlock::line_height_ref(&ctx.widget());
+        let text_align = *TextBlock::text_align_ref(&ctx.widget());
+        let offset = *TextBlock::offset_ref(&ctx.widget());
+        let bounds = *TextBlock::bounds_ref(&ctx.widget());
+        let widget_position = *TextBlock::position_ref(&ctx.widget());
+
+        let max_width = if text_wrap {
+            Some(bounds.width())
+        } else {
+            None
+        };
+        let layout = spans.layout(
+            ctx.render_context_2_d(),
+            &font,
+            font_size,
+            font_weight,
+            font_style,
+            max_width,
+            line_height,
+        );
+
+        layout
+            .fragment_at(
+                position.x() - widget_position.x() - offset,
+                position.y() - widget_position.y(),
+                bounds.width(),
+                text_align,
+            )
+            .and_then(|fragment| spans.get(fragment.run))
+            .and_then(|span| span.link.clone())
+    }
 }
 
 impl State for TextBlockState {
@@ -39,6 +109,8 @@ impl State for TextBlockState {
         for message in messages.read::<TextAction>() {
             match message {
                 TextAction::Localize => self.localize(ctx),
+                TextAction::Press(position) => self.press(*position, ctx),
+                TextAction::Release(position) => self.release(*position, ctx),
             }
         }
     }
@@ -47,11 +119,33 @@ impl State for TextBlockState {
 widget!(
     /// The `TextBlock` widget is used to draw text. It is not interactive.
     ///
+    /// Explicit newlines start a new line. If `text_wrap` is set the text is wrapped to the
+    /// available width.
+    ///
+    /// Rich text can be drawn by setting `spans`. Each span can have its own font, size, color and
+    /// decoration. If a span with a link is clicked, a `LinkClickedEvent` is raised.
+    ///
+    /// ```rust
+    /// TextBlock::new()
+    ///     .spans(
+    ///         TextSpans::new()
+    ///             .span("Read the ")
+    ///             .span(TextSpan::new("docs").link("https://docs.rs/orbtk").underline(true))
+    ///             .span("."),
+    ///     )
+    ///     .on_link_clicked(|_, _, link| println!("{}", link))
+    ///     .build(ctx)
+    /// ```
+    ///
     /// **style:** `text-block`
-    TextBlock<TextBlockState> {
+    TextBlock<TextBlockState>: MouseHandler, LinkClickedHandler {
         /// Sets or shares the text property.
         text: String,
 
+        /// Sets or shares the rich text spans. If not empty, the spans are drawn instead of the
+        /// text.
+        spans: TextSpans,
+
         /// If the `TextBlock` is localizable and the localized text is not empty, the localized_text will be drawn.
         localized_text: String,
 
@@ -67,9 +161,33 @@ widget!(
         /// Sets or shares the font property.
         font: String,
 
+        /// Sets or shares the font weight property. The text is drawn with the face of the font
+        /// family that matches the weight best.
+        font_weight: FontWeight,
+
+        /// Sets or shares the font style property. The text is drawn with the face of the font
+        /// family that matches the style best.
+        font_style: FontStyle,
+
+        /// If set to `true` the text is underlined.
+        underline: bool,
+
+        /// If set to `true` the text is struck through.
+        strikethrough: bool,
+
         /// Defines an extra offset that can be used to the text on x axis.
         offset: f64,
 
+        /// Sets or shares the text wrap property. If set to `true` the text is wrapped at word
+        /// boundaries to the available width.
+        text_wrap: bool,
+
+        /// Sets or shares the line height property. It is a factor of the font size.
+        line_height: f64,
+
+        /// Sets or shares the alignment of the lines of the text.
+        text_align: TextAlignment,
+
         /// Defines if the text is localizable. If set to `false` the text will not be localized.
         localizable: bool
     }
@@ -78,14 +196,27 @@ widget!(
 impl Template for TextBlock {
     fn template(self, id: Entity, _: &mut BuildContext) -> Self {
         self.name("TextBlock")
+            .role(Role::Label)
             .text("")
             .foreground(colors::LINK_WATER_COLOR)
             .font_size(orbtk_fonts::FONT_SIZE_12)
             .font("Roboto-Regular")
+            .font_weight(FontWeight::Normal)
+            .font_style(FontStyle::Normal)
+            .underline(false)
+            .strikethrough(false)
+            .line_height(DEFAULT_LINE_HEIGHT)
             .localizable(true)
             .on_changed("text", move |ctx, _| {
                 ctx.send_message(TextAction::Localize, id)
             })
+            .on_mouse_down(move |ctx, m| {
+                ctx.send_message(TextAction::Press(m.position), id);
+                false
+            })
+            .on_mouse_up(move |ctx, m| {
+                ctx.send_message(TextAction::Release(m.position), id);
+            })
     }
 
     fn render_object(&self) -> Box<dyn RenderObject> {
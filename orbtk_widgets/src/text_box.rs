@@ -32,6 +32,18 @@ widget!(
         /// Sets or shares the font property.
         font: String,
 
+        /// Sets or shares the font weight property.
+        font_weight: FontWeight,
+
+        /// Sets or shares the font style property.
+        font_style: FontStyle,
+
+        /// If set to `true` the text is underlined.
+        underline: bool,
+
+        /// If set to `true` the text is struck through.
+        strikethrough: bool,
+
         /// Sets or shares the background property.
         background: Brush,
 
@@ -74,6 +86,10 @@ impl Template for TextBox {
             .water_mark(id)
             .font(id)
             .font_size(id)
+            .font_weight(id)
+            .font_style(id)
+            .underline(id)
+            .strikethrough(id)
             .localizable(false)
             .build(ctx);
 
@@ -86,6 +102,8 @@ impl Template for TextBox {
             .focused(id)
             .font(id)
             .font_size(id)
+            .font_weight(id)
+            .font_style(id)
             .lose_focus_on_activation(id)
             .select_all_on_focus(id)
             .request_focus(id)
@@ -94,11 +112,16 @@ impl Template for TextBox {
             .build(ctx);
 
         self.name("TextBox")
+            .role(Role::TextInput)
             .style(STYLE_TEXT_BOX)
             .text("")
             .foreground(colors::LINK_WATER_COLOR)
             .font_size(orbtk_fonts::FONT_SIZE_12)
             .font("Roboto-Regular")
+            .font_weight(FontWeight::Normal)
+            .font_style(FontStyle::Normal)
+            .underline(false)
+            .strikethrough(false)
             .selection(TextSelection::default())
             .padding(4.0)
             .background(colors::LYNCH_COLOR)
@@ -108,6 +131,7 @@ impl Template for TextBox {
             .min_width(128.0)
             .height(32.0)
             .focused(false)
+            .focusable(true)
             .lose_focus_on_activation(true)
             .select_all_on_focus(true)
             .child(text_behavior)
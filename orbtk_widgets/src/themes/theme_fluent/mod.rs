@@ -5,6 +5,8 @@ It provides fonts, icons and colors.
 
  */
 use orbtk_core::theming::*;
+#[cfg(not(target_arch = "wasm32"))]
+use orbtk_utils::{FontStyle, FontWeight};
 
 /// provides `constants` associated to fonts.
 pub mod fluent_fonts;
@@ -68,6 +70,24 @@ pub fn register_fluent_fonts(theme: Theme) -> Theme {
             "MaterialIcons-Regular",
             super::theme_orbtk::orbtk_fonts::MATERIAL_ICONS_FONT,
         )
+        .register_font_face(
+            "Selawik",
+            FontWeight::Light,
+            FontStyle::Normal,
+            "Selawik-Light",
+        )
+        .register_font_face(
+            "Selawik",
+            FontWeight::Normal,
+            FontStyle::Normal,
+            "Selawik-Regular",
+        )
+        .register_font_face(
+            "Selawik",
+            FontWeight::Bold,
+            FontStyle::Normal,
+            "Selawik-Bold",
+        )
 }
 
 /// Dummy implementation for web to be compatible to other platforms.
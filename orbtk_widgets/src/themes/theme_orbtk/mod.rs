@@ -6,6 +6,8 @@ It provides fonts, icons and colors.
  */
 
 use orbtk_core::theming::*;
+#[cfg(not(target_arch = "wasm32"))]
+use orbtk_utils::{FontStyle, FontWeight};
 
 /// provides `constants` to reference colors.
 pub mod colors;
@@ -66,6 +68,18 @@ pub fn register_default_fonts(theme: Theme) -> Theme {
         .register_font("Roboto-Regular", orbtk_fonts::ROBOTO_REGULAR_FONT)
         .register_font("Roboto-Medium", orbtk_fonts::ROBOTO_MEDIUM_FONT)
         .register_font("MaterialIcons-Regular", orbtk_fonts::MATERIAL_ICONS_FONT)
+        .register_font_face(
+            "Roboto",
+            FontWeight::Normal,
+            FontStyle::Normal,
+            "Roboto-Regular",
+        )
+        .register_font_face(
+            "Roboto",
+            FontWeight::Medium,
+            FontStyle::Normal,
+            "Roboto-Medium",
+        )
 }
 
 /// Dummy implementation for web to be compatible to other platforms.
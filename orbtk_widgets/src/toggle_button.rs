@@ -47,9 +47,21 @@ widget!(
         /// Sets or shares the icon font property.
         icon_font: String,
 
+        /// Sets or shares the command property. The widget executes the command of the
+        /// `Commands` service with this key on click and is only enabled if the command can be
+        /// executed.
+        command: String,
+
+        /// Sets or shares the command enabled property. It is set to `false` while the command
+        /// of the widget can't be executed, the `enabled` property is kept.
+        command_enabled: bool,
+
         /// Sets or shares the pressed property.
         pressed: bool,
 
+        /// Sets or shares the focused property. It is set if the widget has the keyboard focus.
+        focused: bool,
+
         /// Sets or shares the selected property.
         selected: bool,
 
@@ -64,6 +76,7 @@ widget!(
 impl Template for ToggleButton {
     fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
         self.name("ToggleButton")
+            .role(Role::ToggleButton)
             .style("button")
             .selected(false)
             .height(36.0)
@@ -82,6 +95,9 @@ impl Template for ToggleButton {
             .icon_size(orbtk_fonts::ICON_FONT_SIZE_12)
             .icon_brush(colors::LINK_WATER_COLOR)
             .pressed(false)
+            .command_enabled(true)
+            .focused(false)
+            .focusable(true)
             .spacing(8.0)
             .child(
                 MouseBehavior::new()
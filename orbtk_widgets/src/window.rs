@@ -62,6 +62,18 @@ impl WindowState {
         Window::focus_state_set(&mut ctx.widget(), focus_state);
     }
 
+    fn move_focus(&self, reverse: bool, ctx: &mut Context) {
+        let mut focus_state: FocusState = Window::focus_state_clone(&ctx.widget());
+
+        if reverse {
+            focus_state.focus_previous(ctx);
+        } else {
+            focus_state.focus_next(ctx);
+        }
+
+        Window::focus_state_set(&mut ctx.widget(), focus_state);
+    }
+
     fn set_background(&mut self, ctx: &mut Context) {
         let background: Brush = ctx.widget().clone("background");
         if let Brush::SolidColor(color) = background {
@@ -107,6 +119,12 @@ impl State for WindowState {
                     FocusEvent::RemoveFocus(entity) => {
                         self.remove_focus(entity, ctx);
                     }
+                    FocusEvent::FocusNext => {
+                        self.move_focus(false, ctx);
+                    }
+                    FocusEvent::FocusPrevious => {
+                        self.move_focus(true, ctx);
+                    }
                 },
             }
         }
@@ -174,6 +192,7 @@ impl Window {
 impl Template for Window {
     fn template(self, id: Entity, _: &mut BuildContext) -> Self {
         self.name("Window")
+            .role(Role::Window)
             .background(colors::BRIGHT_GRAY_COLOR)
             .size(100.0, 100.0)
             .style(STYLE_WINDOW)
@@ -1,4 +1,58 @@
-use super::{Brush, Thickness};
+use super::{Brush, Thickness, Value};
+
+/// Describes the line style of a border.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BorderStyle {
+    /// The border is drawn as a solid line.
+    Solid,
+
+    /// The border is drawn as a series of dashes.
+    Dashed,
+
+    /// The border is drawn as a series of dots.
+    Dotted,
+}
+
+impl BorderStyle {
+    /// Returns the line dash pattern of the style for the given border `width`. A solid style returns an empty pattern.
+    pub fn line_dash(&self, width: f64) -> Vec<f64> {
+        let width = width.max(1.0);
+        match self {
+            BorderStyle::Solid => vec![],
+            BorderStyle::Dashed => vec![width * 3.0, width * 2.0],
+            BorderStyle::Dotted => vec![width, width],
+        }
+    }
+}
+
+impl Default for BorderStyle {
+    fn default() -> Self {
+        BorderStyle::Solid
+    }
+}
+
+impl From<&str> for BorderStyle {
+    fn from(s: &str) -> Self {
+        match s {
+            "Dashed" | "dashed" => BorderStyle::Dashed,
+            "Dotted" | "dotted" => BorderStyle::Dotted,
+            _ => BorderStyle::Solid,
+        }
+    }
+}
+
+impl From<String> for BorderStyle {
+    fn from(s: String) -> Self {
+        Self::from(&s[..])
+    }
+}
+
+impl From<Value> for BorderStyle {
+    fn from(v: Value) -> Self {
+        let value = v.get::<String>();
+        BorderStyle::from(value)
+    }
+}
 
 /// Used to build a border, specifying additional details.
 #[derive(Default)]
@@ -6,6 +60,7 @@ pub struct BorderBuilder {
     brush: Brush,
     thickness: Thickness,
     radius: f64,
+    style: BorderStyle,
 }
 
 impl BorderBuilder {
@@ -32,22 +87,30 @@ impl BorderBuilder {
         self
     }
 
+    /// Inserts a border style.
+    pub fn style(mut self, style: impl Into<BorderStyle>) -> Self {
+        self.style = style.into();
+        self
+    }
+
     /// Builds the border.
     pub fn build(self) -> Border {
         Border {
             brush: self.brush,
             thickness: self.thickness,
             radius: self.radius,
+            style: self.style,
         }
     }
 }
 
-/// Describes a border of a shape with border `brush`, `thickness` and `radius`.
+/// Describes a border of a shape with border `brush`, `thickness`, `radius` and `style`.
 #[derive(Clone, Default, Debug, PartialEq)]
 pub struct Border {
     brush: Brush,
     thickness: Thickness,
     radius: f64,
+    style: BorderStyle,
 }
 
 impl Border {
@@ -85,6 +148,16 @@ impl Border {
     pub fn set_radius(&mut self, radius: f64) {
         self.radius = radius
     }
+
+    /// Gets the style.
+    pub fn style(&self) -> BorderStyle {
+        self.style
+    }
+
+    /// Sets the style.
+    pub fn set_style(&mut self, style: impl Into<BorderStyle>) {
+        self.style = style.into();
+    }
 }
 
 /// Contains a set of getters and setters to read and write to a border.
@@ -147,6 +220,33 @@ mod tests {
         assert!(crate::f64_cmp(border.radius(), radius));
     }
 
+    #[test]
+    fn test_style() {
+        let border = BorderBuilder::new().style("dashed").build();
+        assert_eq!(border.style(), BorderStyle::Dashed);
+
+        let mut border = Border::default();
+        assert_eq!(border.style(), BorderStyle::Solid);
+        border.set_style(BorderStyle::Dotted);
+        assert_eq!(border.style(), BorderStyle::Dotted);
+    }
+
+    #[test]
+    fn test_style_into() {
+        let style: BorderStyle = "Dashed".into();
+        assert_eq!(style, BorderStyle::Dashed);
+
+        let style: BorderStyle = "dotted".into();
+        assert_eq!(style, BorderStyle::Dotted);
+
+        let style: BorderStyle = "other".into();
+        assert_eq!(style, BorderStyle::Solid);
+
+        assert!(BorderStyle::Solid.line_dash(2.0).is_empty());
+        assert_eq!(BorderStyle::Dashed.line_dash(2.0), vec![6.0, 4.0]);
+        assert_eq!(BorderStyle::Dotted.line_dash(0.5), vec![1.0, 1.0]);
+    }
+
     #[test]
     fn test_set_brush() {
         let brush = Brush::from("#000000");
@@ -14,7 +14,7 @@ use crate::prelude::*;
 ///
 /// 1. The `color name`
 /// 2. The `gradient` string
-///    * the gradient type (linear, repeating-linear)
+///    * the gradient type (linear, repeating-linear, radial, repeating-radial, conic, repeating-conic)
 ///    * gradient attributes (direction-identifier, angles, color names )
 ///
 /// ## Examples
@@ -27,6 +27,8 @@ use crate::prelude::*;
 /// .background("repeating-linear-gradient(0.25turn, rgba(255, 255, 0, 0.6), dodgerblue, deepskyblue)")
 /// .background("linear-gradient(-90deg, hsv(201, 94%, 80.5%), steelblue)")
 /// .background("linear-gradient(to top right, white, skyblue 60%, lightskyblue 80%, yellow 83%, yellow)")
+/// .background("radial-gradient(circle 40px at 30% 40%, white, steelblue)")
+/// .background("conic-gradient(from 90deg, red, yellow 120deg, blue)")
 /// ```
 /// Read on to see how the syntax is composed.
 ///
@@ -123,6 +125,21 @@ use crate::prelude::*;
 /// [repeating-]linear-gradient({direction-identifier}, {initial color-name}, {terminating color-name}
 /// ```
 ///
+/// Radial gradients spread circular from their center. The radius defaults to the distance
+/// from the center to the farthest corner (`100%`), the center to the middle of the entity.
+/// Keywords like `left`, `center` or `bottom` could be used instead of positions.
+///
+/// ```text
+/// [repeating-]radial-gradient([circle] [{Radius}{%|px}] [at {X Position}{%|px} {Y Position}{%|px}], {Color} [{Stop position}{%|px}], ...)
+/// ```
+///
+/// Conic gradients rotate their colors clockwise around the center, starting at the top.
+/// Their stop positions could also be given as angles.
+///
+/// ```text
+/// [repeating-]conic-gradient([from {Start angle}{deg|rad|turn}] [at {X Position}{%|px} {Y Position}{%|px}], {Color} [{Stop position}{%|deg|rad|turn}], ...)
+/// ```
+///
 //#[cfg(feature = "nightly")]
 //#[doc(include = "../colors.md")]
 
@@ -201,6 +218,87 @@ impl From<Value> for Brush {
 
 #[cfg(test)]
 mod tests {
-    //  use crate::prelude::*;
-    // todo: tbd after brush struct is finished
+    use crate::prelude::*;
+
+    fn gradient(brush: &str) -> Gradient {
+        match Brush::from(brush) {
+            Brush::Gradient(gradient) => gradient,
+            _ => panic!("{} is not a gradient", brush),
+        }
+    }
+
+    fn percent(x: f64, y: f64) -> OnPlanePos {
+        OnPlanePos::new(
+            OnLinePos::new(x, OnLinePosKind::Percentage),
+            OnLinePos::new(y, OnLinePosKind::Percentage),
+        )
+    }
+
+    #[test]
+    fn test_radial_gradient() {
+        let g = gradient("radial-gradient(white, black)");
+        assert_eq!(
+            g.kind,
+            GradientKind::Radial(RadialGradientCoords::default())
+        );
+        assert_eq!(g.stops.len(), 2);
+        assert!(!g.repeat);
+
+        let g = gradient("repeating-radial-gradient(circle 40px at 30% left, white, black 20%)");
+        assert_eq!(
+            g.kind,
+            GradientKind::Radial(RadialGradientCoords::new(
+                OnPlanePos::new(
+                    OnLinePos::new(30.0, OnLinePosKind::Percentage),
+                    OnLinePos::new(0.0, OnLinePosKind::Percentage)
+                ),
+                OnLinePos::new(40.0, OnLinePosKind::Pixels)
+            ))
+        );
+        assert!(g.repeat);
+
+        let g = gradient("radial-gradient(at 10px 20px, white, black)");
+        assert_eq!(
+            g.kind,
+            GradientKind::Radial(RadialGradientCoords::new(
+                OnPlanePos::new(
+                    OnLinePos::new(10.0, OnLinePosKind::Pixels),
+                    OnLinePos::new(20.0, OnLinePosKind::Pixels)
+                ),
+                OnLinePos::new(100.0, OnLinePosKind::Percentage)
+            ))
+        );
+
+        assert_eq!(
+            Brush::from("radial-gradient(square, white, black)"),
+            Brush::default()
+        );
+    }
+
+    #[test]
+    fn test_conic_gradient() {
+        let g = gradient("conic-gradient(red, blue)");
+        assert_eq!(g.kind, GradientKind::Conic(ConicGradientCoords::default()));
+
+        let g = gradient(
+            "repeating-conic-gradient(from 0.25turn at 20% 80%, red, blue 90deg, green 50%)",
+        );
+        assert_eq!(
+            g.kind,
+            GradientKind::Conic(ConicGradientCoords::new(
+                percent(20.0, 80.0),
+                Angle::from_turn(0.25)
+            ))
+        );
+        assert!(g.repeat);
+        assert_eq!(g.stops[0].pos, None);
+        assert_eq!(
+            g.stops[1].pos,
+            Some(OnLinePos::new(25.0, OnLinePosKind::Percentage))
+        );
+        assert_eq!(
+            g.stops[2].pos,
+            Some(OnLinePos::new(50.0, OnLinePosKind::Percentage))
+        );
+    }
 }
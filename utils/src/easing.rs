@@ -0,0 +1,170 @@
+use crate::Value;
+
+/// Describes how the progress of an animation changes over time. The curves match the easing
+/// functions of CSS transitions.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Easing {
+    /// Constant speed.
+    Linear,
+
+    /// Starts fast and slows down at the end, `cubic-bezier(0.25, 0.1, 0.25, 1.0)`.
+    Ease,
+
+    /// Starts slow, `cubic-bezier(0.42, 0.0, 1.0, 1.0)`.
+    EaseIn,
+
+    /// Slows down at the end, `cubic-bezier(0.0, 0.0, 0.58, 1.0)`.
+    EaseOut,
+
+    /// Starts slow and slows down at the end, `cubic-bezier(0.42, 0.0, 0.58, 1.0)`.
+    EaseInOut,
+
+    /// A cubic bezier curve from (0, 0) to (1, 1) with the control points (x1, y1) and (x2, y2).
+    CubicBezier(f64, f64, f64, f64),
+}
+
+impl Easing {
+    /// Returns the eased progress for the linear progress `t` between 0.0 and 1.0.
+    pub fn ease(&self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+
+        match *self {
+            Easing::Linear => t,
+            Easing::Ease => cubic_bezier(0.25, 0.1, 0.25, 1.0, t),
+            Easing::EaseIn => cubic_bezier(0.42, 0.0, 1.0, 1.0, t),
+            Easing::EaseOut => cubic_bezier(0.0, 0.0, 0.58, 1.0, t),
+            Easing::EaseInOut => cubic_bezier(0.42, 0.0, 0.58, 1.0, t),
+            Easing::CubicBezier(x1, y1, x2, y2) => cubic_bezier(x1, y1, x2, y2, t),
+        }
+    }
+}
+
+impl Default for Easing {
+    fn default() -> Self {
+        Easing::Linear
+    }
+}
+
+impl ToString for Easing {
+    fn to_string(&self) -> String {
+        match self {
+            Easing::Linear => "linear".to_string(),
+            Easing::Ease => "ease".to_string(),
+            Easing::EaseIn => "ease-in".to_string(),
+            Easing::EaseOut => "ease-out".to_string(),
+            Easing::EaseInOut => "ease-in-out".to_string(),
+            Easing::CubicBezier(x1, y1, x2, y2) => {
+                format!("cubic-bezier({}, {}, {}, {})", x1, y1, x2, y2)
+            }
+        }
+    }
+}
+
+impl From<&str> for Easing {
+    fn from(s: &str) -> Self {
+        match s.trim() {
+            "ease" | "Ease" => Easing::Ease,
+            "ease-in" | "ease_in" | "EaseIn" => Easing::EaseIn,
+            "ease-out" | "ease_out" | "EaseOut" => Easing::EaseOut,
+            "ease-in-out" | "ease_in_out" | "EaseInOut" => Easing::EaseInOut,
+            s if s.starts_with("cubic-bezier(") && s.ends_with(')') => {
+                let values: Vec<f64> = s["cubic-bezier(".len()..s.len() - 1]
+                    .split(',')
+                    .filter_map(|value| value.trim().parse().ok())
+                    .collect();
+
+                match values[..] {
+                    [x1, y1, x2, y2] => Easing::CubicBezier(x1, y1, x2, y2),
+                    _ => Easing::Linear,
+                }
+            }
+            _ => Easing::Linear,
+        }
+    }
+}
+
+impl From<String> for Easing {
+    fn from(s: String) -> Self {
+        Self::from(&s[..])
+    }
+}
+
+impl From<Value> for Easing {
+    fn from(v: Value) -> Self {
+        let value = v.get::<String>();
+        Easing::from(value)
+    }
+}
+
+// -- Helpers --
+
+// Returns the y value of the cubic bezier curve at the given x value. The x value of the curve is
+// increasing, so the parameter of x is found by bisection.
+fn cubic_bezier(x1: f64, y1: f64, x2: f64, y2: f64, x: f64) -> f64 {
+    let curve = |a: f64, b: f64, t: f64| {
+        let u = 1.0 - t;
+        3.0 * u * u * t * a + 3.0 * u * t * t * b + t * t * t
+    };
+
+    let mut low = 0.0;
+    let mut high = 1.0;
+    let mut t = x;
+
+    for _ in 0..32 {
+        let value = curve(x1, x2, t);
+        if (value - x).abs() < 1e-7 {
+            break;
+        }
+
+        if value < x {
+            low = t;
+        } else {
+            high = t;
+        }
+        t = (low + high) / 2.0;
+    }
+
+    curve(y1, y2, t)
+}
+
+// -- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_ease() {
+        for easing in &[
+            Easing::Linear,
+            Easing::Ease,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+        ] {
+            assert!(f64_cmp(easing.ease(0.0), 0.0));
+            assert!((easing.ease(1.0) - 1.0).abs() < 1e-6);
+        }
+
+        assert!(f64_cmp(Easing::Linear.ease(0.25), 0.25));
+        assert!(Easing::EaseIn.ease(0.25) < 0.25);
+        assert!(Easing::EaseOut.ease(0.25) > 0.25);
+        assert!((Easing::EaseInOut.ease(0.5) - 0.5).abs() < 1e-6);
+        assert!(f64_cmp(
+            Easing::EaseOut.ease(2.0),
+            Easing::EaseOut.ease(1.0)
+        ));
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(Easing::from("ease-out"), Easing::EaseOut);
+        assert_eq!(Easing::from("EaseInOut"), Easing::EaseInOut);
+        assert_eq!(
+            Easing::from("cubic-bezier(0.1, 0.2, 0.3, 0.4)"),
+            Easing::CubicBezier(0.1, 0.2, 0.3, 0.4)
+        );
+        assert_eq!(Easing::from("cubic-bezier(0.1)"), Easing::Linear);
+        assert_eq!(Easing::from("unknown"), Easing::Linear);
+    }
+}
@@ -101,6 +101,55 @@ impl Expression {
         }
     }
 
+    // Gradient stop of a conic gradient, its position could also be given as angle.
+    fn conic_gradient_stop(&self) -> Option<GradientStop> {
+        if let Some(stop) = self.gradient_stop() {
+            return Some(stop);
+        }
+        match self {
+            Expression::Complex(v) if v.len() == 2 => {
+                let color = v[0].color()?;
+                let angle = match &v[1] {
+                    Expression::Number(_, unit) if !unit.is_empty() => v[1].angle()?,
+                    _ => return None,
+                };
+                Some(GradientStop {
+                    pos: Some(OnLinePos::new(
+                        angle.to_degrees() / 3.6,
+                        OnLinePosKind::Percentage,
+                    )),
+                    color,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    // Returns the whitespace separated parts of the expression.
+    fn tokens(&self) -> &[Expression] {
+        match self {
+            Expression::Complex(v) => &v[..],
+            _ => std::slice::from_ref(self),
+        }
+    }
+
+    // Interprets the expression as position on a line, keywords like `left` or `center` are mapped to percentages.
+    fn line_pos(&self) -> Option<OnLinePos> {
+        match self {
+            Expression::Number(n, unit) => OnLinePos::try_from((*n, &unit[..])).ok(),
+            Expression::Other(label) => {
+                let percent = match &label[..] {
+                    "left" | "top" => 0.0,
+                    "center" => 50.0,
+                    "right" | "bottom" => 100.0,
+                    _ => return None,
+                };
+                Some(OnLinePos::new(percent, OnLinePosKind::Percentage))
+            }
+            _ => None,
+        }
+    }
+
     pub fn relative_dir(&self) -> Option<RelativeDir> {
         match self {
             Expression::Other(label) => match &label[..] {
@@ -176,43 +225,62 @@ impl Expression {
         if args.is_empty() {
             return None;
         }
-        let (radial, repeat) = match &name[..] {
-            "repeating-linear-gradient" => (false, true),
-            "linear-gradient" => (false, false),
-            "radial-gradient" => (true, false),
-            "repeating-radial-gradient" => (true, true),
+        let (shape, repeat) = match &name[..] {
+            "repeating-linear-gradient" => ("linear", true),
+            "linear-gradient" => ("linear", false),
+            "radial-gradient" => ("radial", false),
+            "repeating-radial-gradient" => ("radial", true),
+            "conic-gradient" => ("conic", false),
+            "repeating-conic-gradient" => ("conic", true),
             _ => {
                 return None;
             }
         };
         let mut i = 0;
-        let kind;
-        if radial {
-            // TODO: Implement radial gradients
-            return None;
-        } else {
-            let mut coords = LinearGradientCoords::Angle {
-                displacement,
-                angle: Angle::zero(),
-            };
-            if let Some(direction) = args[0].relative_dir() {
-                coords = LinearGradientCoords::Direction {
-                    direction,
-                    displacement,
-                };
-            } else if let Some(angle) = args[0].angle() {
-                coords = LinearGradientCoords::Angle {
-                    angle,
+        let kind = match shape {
+            "radial" => {
+                let mut coords = RadialGradientCoords::default();
+                if args[0].gradient_stop().is_none() {
+                    coords = radial_gradient_coords(args[0].tokens())?;
+                    i += 1;
+                }
+                GradientKind::Radial(coords)
+            }
+            "conic" => {
+                let mut coords = ConicGradientCoords::default();
+                if args[0].conic_gradient_stop().is_none() {
+                    coords = conic_gradient_coords(args[0].tokens())?;
+                    i += 1;
+                }
+                GradientKind::Conic(coords)
+            }
+            _ => {
+                let mut coords = LinearGradientCoords::Angle {
                     displacement,
+                    angle: Angle::zero(),
                 };
-                i += 1;
+                if let Some(direction) = args[0].relative_dir() {
+                    coords = LinearGradientCoords::Direction {
+                        direction,
+                        displacement,
+                    };
+                } else if let Some(angle) = args[0].angle() {
+                    coords = LinearGradientCoords::Angle {
+                        angle,
+                        displacement,
+                    };
+                    i += 1;
+                }
+                GradientKind::Linear(coords)
             }
-            kind = GradientKind::Linear(coords);
-        }
+        };
         let stops: Vec<GradientStop> = args
             .iter()
             .skip(i)
-            .filter_map(|stop| stop.gradient_stop())
+            .filter_map(|stop| match kind {
+                GradientKind::Conic(_) => stop.conic_gradient_stop(),
+                _ => stop.gradient_stop(),
+            })
             .collect();
         if stops.is_empty() {
             return None;
@@ -250,6 +318,55 @@ impl From<Expression> for Number {
     }
 }
 
+// Reads a position like `at 30% 40%` from the given tokens.
+fn plane_pos(tokens: &[Expression]) -> Option<OnPlanePos> {
+    match tokens {
+        [Expression::Other(at), x, y] if at == "at" => {
+            Some(OnPlanePos::new(x.line_pos()?, y.line_pos()?))
+        }
+        _ => None,
+    }
+}
+
+// Parses the shape of a radial gradient e.g. `circle 40px at 30% 40%`.
+fn radial_gradient_coords(mut tokens: &[Expression]) -> Option<RadialGradientCoords> {
+    let mut coords = RadialGradientCoords::default();
+    if let Some(Expression::Other(shape)) = tokens.first() {
+        if shape == "circle" || shape == "ellipse" {
+            tokens = &tokens[1..];
+        }
+    }
+    match tokens.first() {
+        Some(Expression::Other(size)) if size == "farthest-corner" => {
+            tokens = &tokens[1..];
+        }
+        Some(size @ Expression::Number(..)) => {
+            coords.radius = size.line_pos()?;
+            tokens = &tokens[1..];
+        }
+        _ => {}
+    }
+    if !tokens.is_empty() {
+        coords.center = plane_pos(tokens)?;
+    }
+    Some(coords)
+}
+
+// Parses the shape of a conic gradient e.g. `from 90deg at 30% 40%`.
+fn conic_gradient_coords(mut tokens: &[Expression]) -> Option<ConicGradientCoords> {
+    let mut coords = ConicGradientCoords::default();
+    if let [Expression::Other(from), angle, ..] = tokens {
+        if from == "from" {
+            coords.angle = angle.angle()?;
+            tokens = &tokens[2..];
+        }
+    }
+    if !tokens.is_empty() {
+        coords.center = plane_pos(tokens)?;
+    }
+    Some(coords)
+}
+
 pub(crate) fn parse_expression_with_complex(chrs: &mut Peekable<Chars>) -> Option<Expression> {
     let mut v = Vec::new();
     while let Some(c) = chrs.peek() {
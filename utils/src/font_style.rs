@@ -0,0 +1,64 @@
+use crate::Value;
+
+/// Describes the style (slant) of a font.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FontStyle {
+    Normal,
+    Italic,
+    Oblique,
+}
+
+impl Default for FontStyle {
+    fn default() -> Self {
+        FontStyle::Normal
+    }
+}
+
+impl ToString for FontStyle {
+    fn to_string(&self) -> String {
+        match self {
+            FontStyle::Normal => "normal".to_string(),
+            FontStyle::Italic => "italic".to_string(),
+            FontStyle::Oblique => "oblique".to_string(),
+        }
+    }
+}
+
+impl From<&str> for FontStyle {
+    fn from(t: &str) -> Self {
+        match t {
+            "Italic" | "italic" => FontStyle::Italic,
+            "Oblique" | "oblique" => FontStyle::Oblique,
+            _ => FontStyle::Normal,
+        }
+    }
+}
+
+impl From<String> for FontStyle {
+    fn from(s: String) -> FontStyle {
+        Self::from(&s[..])
+    }
+}
+
+impl From<Value> for FontStyle {
+    fn from(v: Value) -> Self {
+        let value = v.get::<String>();
+        FontStyle::from(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(FontStyle::from("italic"), FontStyle::Italic);
+        assert_eq!(FontStyle::from("Oblique"), FontStyle::Oblique);
+        assert_eq!(FontStyle::from("unknown"), FontStyle::Normal);
+        assert_eq!(
+            FontStyle::from(FontStyle::Italic.to_string()),
+            FontStyle::Italic
+        );
+    }
+}
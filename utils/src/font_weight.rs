@@ -0,0 +1,140 @@
+use crate::Value;
+
+/// Describes the weight (boldness) of a font.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum FontWeight {
+    Thin,
+    ExtraLight,
+    Light,
+    Normal,
+    Medium,
+    SemiBold,
+    Bold,
+    ExtraBold,
+    Black,
+}
+
+impl FontWeight {
+    /// Returns the numeric weight between 100 (thin) and 900 (black).
+    pub fn value(self) -> u16 {
+        match self {
+            FontWeight::Thin => 100,
+            FontWeight::ExtraLight => 200,
+            FontWeight::Light => 300,
+            FontWeight::Normal => 400,
+            FontWeight::Medium => 500,
+            FontWeight::SemiBold => 600,
+            FontWeight::Bold => 700,
+            FontWeight::ExtraBold => 800,
+            FontWeight::Black => 900,
+        }
+    }
+
+    /// Returns the weight that is nearest to the given numeric weight.
+    pub fn from_value(value: u16) -> Self {
+        match (value.saturating_add(50) / 100).clamp(1, 9) {
+            1 => FontWeight::Thin,
+            2 => FontWeight::ExtraLight,
+            3 => FontWeight::Light,
+            4 => FontWeight::Normal,
+            5 => FontWeight::Medium,
+            6 => FontWeight::SemiBold,
+            7 => FontWeight::Bold,
+            8 => FontWeight::ExtraBold,
+            _ => FontWeight::Black,
+        }
+    }
+}
+
+impl Default for FontWeight {
+    fn default() -> Self {
+        FontWeight::Normal
+    }
+}
+
+impl ToString for FontWeight {
+    fn to_string(&self) -> String {
+        match self {
+            FontWeight::Thin => "thin".to_string(),
+            FontWeight::ExtraLight => "extra_light".to_string(),
+            FontWeight::Light => "light".to_string(),
+            FontWeight::Normal => "normal".to_string(),
+            FontWeight::Medium => "medium".to_string(),
+            FontWeight::SemiBold => "semi_bold".to_string(),
+            FontWeight::Bold => "bold".to_string(),
+            FontWeight::ExtraBold => "extra_bold".to_string(),
+            FontWeight::Black => "black".to_string(),
+        }
+    }
+}
+
+impl From<&str> for FontWeight {
+    fn from(t: &str) -> Self {
+        match t {
+            "Thin" | "thin" => FontWeight::Thin,
+            "ExtraLight" | "extra_light" => FontWeight::ExtraLight,
+            "Light" | "light" => FontWeight::Light,
+            "Medium" | "medium" => FontWeight::Medium,
+            "SemiBold" | "semi_bold" => FontWeight::SemiBold,
+            "Bold" | "bold" => FontWeight::Bold,
+            "ExtraBold" | "extra_bold" => FontWeight::ExtraBold,
+            "Black" | "black" => FontWeight::Black,
+            _ => t
+                .parse::<u16>()
+                .map_or(FontWeight::Normal, FontWeight::from_value),
+        }
+    }
+}
+
+impl From<String> for FontWeight {
+    fn from(s: String) -> FontWeight {
+        Self::from(&s[..])
+    }
+}
+
+impl From<u16> for FontWeight {
+    fn from(value: u16) -> FontWeight {
+        FontWeight::from_value(value)
+    }
+}
+
+impl From<Value> for FontWeight {
+    fn from(v: Value) -> Self {
+        // the weight could be given as name or as number
+        if let Ok(value) = v.0.clone().into_rust::<String>() {
+            return FontWeight::from(value);
+        }
+
+        FontWeight::from_value(v.get::<f64>().max(0.0) as u16)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(FontWeight::from("bold"), FontWeight::Bold);
+        assert_eq!(FontWeight::from("SemiBold"), FontWeight::SemiBold);
+        assert_eq!(FontWeight::from("300"), FontWeight::Light);
+        assert_eq!(FontWeight::from("unknown"), FontWeight::Normal);
+        assert_eq!(
+            FontWeight::from(FontWeight::ExtraBold.to_string()),
+            FontWeight::ExtraBold
+        );
+    }
+
+    #[test]
+    fn test_from_value() {
+        assert_eq!(FontWeight::from_value(0), FontWeight::Thin);
+        assert_eq!(FontWeight::from_value(640), FontWeight::SemiBold);
+        assert_eq!(FontWeight::from_value(650), FontWeight::Bold);
+        assert_eq!(FontWeight::from_value(1000), FontWeight::Black);
+        assert_eq!(FontWeight::Medium.value(), 500);
+        assert_eq!(
+            FontWeight::from(Value(ron::Value::Number(ron::Number::from(700.0)))),
+            FontWeight::Bold
+        );
+    }
+}
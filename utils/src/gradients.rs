@@ -1,4 +1,4 @@
-use crate::{Angle, Color, OnLinePos, OnPlanePos, Point, RelativeDir};
+use crate::{Angle, Color, OnLinePos, OnLinePosKind, OnPlanePos, Point, RelativeDir};
 
 /// Describes a position on a colorful gradient.
 #[derive(Copy, Clone, PartialEq, Debug)]
@@ -103,6 +103,82 @@ impl Default for LinearGradientCoords {
     }
 }
 
+/// Describes the coordinates of a colorful radial gradient.
+///
+/// The gradient starts at the `focal` point and ends at the circle around `center` with the given `radius`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct RadialGradientCoords {
+    /// Defines the center of the ending circle relative to the target figure.
+    pub center: OnPlanePos,
+    /// Defines the radius of the ending circle. Percentages refer to the distance from the center to the farthest corner.
+    pub radius: OnLinePos,
+    /// Defines the start point of the gradient relative to the target figure. If `None` the center is used.
+    pub focal: Option<OnPlanePos>,
+}
+
+impl RadialGradientCoords {
+    /// Creates a `RadialGradientCoords` from its center and radius.
+    pub fn new(
+        center: impl Into<OnPlanePos>,
+        radius: impl Into<OnLinePos>,
+    ) -> RadialGradientCoords {
+        RadialGradientCoords {
+            center: center.into(),
+            radius: radius.into(),
+            focal: None,
+        }
+    }
+
+    /// Sets the focal point of the gradient.
+    pub fn with_focal(mut self, focal: impl Into<OnPlanePos>) -> Self {
+        self.focal = Some(focal.into());
+        self
+    }
+}
+
+impl Default for RadialGradientCoords {
+    fn default() -> RadialGradientCoords {
+        RadialGradientCoords {
+            center: OnPlanePos::new(
+                OnLinePos::new(50.0, OnLinePosKind::Percentage),
+                OnLinePos::new(50.0, OnLinePosKind::Percentage),
+            ),
+            radius: OnLinePos::new(100.0, OnLinePosKind::Percentage),
+            focal: None,
+        }
+    }
+}
+
+/// Describes the coordinates of a colorful conic gradient.
+///
+/// The colors of a conic gradient are rotated clockwise around the `center`, starting at the top.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ConicGradientCoords {
+    /// Defines the center of the gradient relative to the target figure.
+    pub center: OnPlanePos,
+    /// Defines the clockwise rotation of the gradient.
+    pub angle: Angle,
+}
+
+impl ConicGradientCoords {
+    /// Creates a `ConicGradientCoords` from its center and start angle.
+    pub fn new(center: impl Into<OnPlanePos>, angle: Angle) -> ConicGradientCoords {
+        ConicGradientCoords {
+            center: center.into(),
+            angle,
+        }
+    }
+}
+
+impl Default for ConicGradientCoords {
+    fn default() -> ConicGradientCoords {
+        ConicGradientCoords {
+            center: RadialGradientCoords::default().center,
+            angle: Angle::zero(),
+        }
+    }
+}
+
 /// Describes a colorful gradient.
 #[derive(Clone, PartialEq, Debug)]
 pub struct Gradient {
@@ -130,10 +206,12 @@ impl Default for Gradient {
     }
 }
 
+/// Describes the shape of a colorful gradient.
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub enum GradientKind {
     Linear(LinearGradientCoords),
-    // Radial(RadialGradient),
+    Radial(RadialGradientCoords),
+    Conic(ConicGradientCoords),
 }
 
 impl Default for GradientKind {
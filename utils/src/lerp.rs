@@ -0,0 +1,130 @@
+use crate::{Brush, Color, Point, Rectangle, Size, Thickness};
+
+/// Linear interpolation between two values. It is used to animate properties.
+pub trait Lerp {
+    /// Returns the value between `self` (`t` is 0.0) and `other` (`t` is 1.0).
+    fn lerp(&self, other: &Self, t: f64) -> Self;
+}
+
+impl Lerp for f64 {
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Lerp for f32 {
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        self + (other - self) * t as f32
+    }
+}
+
+impl Lerp for Point {
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        Point::new(self.x().lerp(&other.x(), t), self.y().lerp(&other.y(), t))
+    }
+}
+
+impl Lerp for Size {
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        Size::new(
+            self.width().lerp(&other.width(), t),
+            self.height().lerp(&other.height(), t),
+        )
+    }
+}
+
+impl Lerp for Rectangle {
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        Rectangle::new(
+            self.position().lerp(&other.position(), t),
+            self.size().lerp(&other.size(), t),
+        )
+    }
+}
+
+impl Lerp for Thickness {
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        Thickness::new(
+            self.left.lerp(&other.left, t),
+            self.top.lerp(&other.top, t),
+            self.right.lerp(&other.right, t),
+            self.bottom.lerp(&other.bottom, t),
+        )
+    }
+}
+
+impl Lerp for Color {
+    // Colors are interpolated with premultiplied alpha, so a fade to `transparent` does not
+    // darken the color.
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        let alpha = (self.a() as f64).lerp(&(other.a() as f64), t);
+
+        if alpha <= 0.0 {
+            return Color::rgba(0, 0, 0, 0);
+        }
+
+        let channel = |from: u8, to: u8| {
+            let from = from as f64 * self.a() as f64;
+            let to = to as f64 * other.a() as f64;
+            (from.lerp(&to, t) / alpha).round().clamp(0.0, 255.0) as u8
+        };
+
+        Color::rgba(
+            channel(self.r(), other.r()),
+            channel(self.g(), other.g()),
+            channel(self.b(), other.b()),
+            alpha.round() as u8,
+        )
+    }
+}
+
+impl Lerp for Brush {
+    // Only solid colors are interpolated, gradients switch in the middle.
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        match (self, other) {
+            (Brush::SolidColor(from), Brush::SolidColor(to)) => Brush::SolidColor(from.lerp(to, t)),
+            _ if t < 0.5 => self.clone(),
+            _ => other.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_lerp() {
+        assert!(f64_cmp(2.0.lerp(&4.0, 0.5), 3.0));
+        f32_cmp(0.0_f32.lerp(&1.0, 0.25), 0.25);
+        assert_eq!(
+            Point::new(0.0, 10.0).lerp(&Point::new(10.0, 0.0), 0.5),
+            Point::new(5.0, 5.0)
+        );
+        assert_eq!(
+            Thickness::new(0.0, 0.0, 0.0, 0.0).lerp(&Thickness::new(4.0, 8.0, 0.0, 2.0), 0.5),
+            Thickness::new(2.0, 4.0, 0.0, 1.0)
+        );
+        assert_eq!(
+            Rectangle::new((0.0, 0.0), (10.0, 10.0))
+                .lerp(&Rectangle::new((10.0, 0.0), (20.0, 10.0)), 0.5),
+            Rectangle::new((5.0, 0.0), (15.0, 10.0))
+        );
+    }
+
+    #[test]
+    fn test_lerp_color() {
+        let black = Color::rgb(0, 0, 0);
+        let white = Color::rgb(255, 255, 255);
+        assert_eq!(black.lerp(&white, 0.5), Color::rgb(128, 128, 128));
+
+        // the color does not change during a fade out
+        let fade = white.lerp(&Color::rgba(0, 0, 0, 0), 0.5);
+        assert_eq!(fade, Color::rgba(255, 255, 255, 128));
+
+        assert_eq!(
+            Brush::from(black).lerp(&Brush::from(white), 1.0),
+            Brush::from(white)
+        );
+    }
+}
@@ -5,17 +5,22 @@ pub use self::brush::*;
 pub use self::color::*;
 pub use self::constraint::*;
 pub use self::dirty_size::*;
+pub use self::easing::*;
 pub use self::expression::*;
 pub use self::f32_cmp::*;
 pub use self::f64_cmp::*;
 pub use self::filter::*;
+pub use self::font_style::*;
+pub use self::font_weight::*;
 pub use self::gradients::*;
+pub use self::lerp::*;
 pub use self::number::*;
 pub use self::orientation::*;
 pub use self::point::*;
 pub use self::rectangle::*;
 pub use self::relative_direction::*;
 pub use self::selection_mode::*;
+pub use self::shadow::*;
 pub use self::size::*;
 pub use self::string16::*;
 pub use self::text_alignment::*;
@@ -31,11 +36,15 @@ mod brush;
 mod color;
 mod constraint;
 mod dirty_size;
+mod easing;
 mod expression;
 mod f32_cmp;
 mod f64_cmp;
 mod filter;
+mod font_style;
+mod font_weight;
 mod gradients;
+mod lerp;
 mod number;
 mod orientation;
 mod point;
@@ -43,6 +52,7 @@ pub mod prelude;
 mod rectangle;
 mod relative_direction;
 mod selection_mode;
+mod shadow;
 mod size;
 mod spacer;
 mod string16;
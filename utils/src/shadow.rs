@@ -0,0 +1,174 @@
+use crate::{Color, Expression, Point, Value};
+
+/// Describes a drop shadow of a shape with `offset`, `blur` radius, `spread` and `color`.
+///
+/// A shadow could also be defined by a string in the form of
+/// `{X offset} {Y offset} [{Blur radius}] [{Spread}] {Color}`, the lengths could have a `px`
+/// unit e.g. `0px 4px 12px rgba(0, 0, 0, 0.4)`. `none` defines no shadow.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Shadow {
+    offset: Point,
+    blur: f64,
+    spread: f64,
+    color: Color,
+}
+
+impl Shadow {
+    /// Creates a new shadow.
+    pub fn new(offset: impl Into<Point>, blur: f64, spread: f64, color: impl Into<Color>) -> Self {
+        Shadow {
+            offset: offset.into(),
+            blur,
+            spread,
+            color: color.into(),
+        }
+    }
+
+    /// Gets the offset.
+    pub fn offset(&self) -> Point {
+        self.offset
+    }
+
+    /// Sets the offset.
+    pub fn set_offset(&mut self, offset: impl Into<Point>) {
+        self.offset = offset.into();
+    }
+
+    /// Gets the blur radius.
+    pub fn blur(&self) -> f64 {
+        self.blur
+    }
+
+    /// Sets the blur radius.
+    pub fn set_blur(&mut self, blur: f64) {
+        self.blur = blur;
+    }
+
+    /// Gets the spread. The shadow is expanded by a positive spread and shrunk by a negative one.
+    pub fn spread(&self) -> f64 {
+        self.spread
+    }
+
+    /// Sets the spread.
+    pub fn set_spread(&mut self, spread: f64) {
+        self.spread = spread;
+    }
+
+    /// Gets the color.
+    pub fn color(&self) -> Color {
+        self.color
+    }
+
+    /// Sets the color.
+    pub fn set_color(&mut self, color: impl Into<Color>) {
+        self.color = color.into();
+    }
+
+    /// Returns `true` if the shadow is not visible.
+    pub fn is_none(&self) -> bool {
+        self.color.a() == 0
+    }
+}
+
+impl Default for Shadow {
+    fn default() -> Self {
+        Shadow {
+            offset: Point::default(),
+            blur: 0.0,
+            spread: 0.0,
+            color: Color::rgba(0, 0, 0, 0),
+        }
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for Shadow {
+    fn from(s: &str) -> Self {
+        let expressions = match Expression::from(s) {
+            Expression::Complex(expressions) => expressions,
+            _ => return Shadow::default(),
+        };
+
+        let (color, lengths) = match expressions.split_last() {
+            Some((color, lengths)) => (color.color(), lengths),
+            None => return Shadow::default(),
+        };
+
+        let lengths: Option<Vec<f64>> = lengths
+            .iter()
+            .map(|length| match length {
+                Expression::Number(n, unit) if unit.is_empty() || unit == "px" => Some((*n).into()),
+                _ => None,
+            })
+            .collect();
+
+        match (color, lengths) {
+            (Some(color), Some(lengths)) if lengths.len() >= 2 && lengths.len() <= 4 => {
+                Shadow::new(
+                    (lengths[0], lengths[1]),
+                    lengths.get(2).copied().unwrap_or_default(),
+                    lengths.get(3).copied().unwrap_or_default(),
+                    color,
+                )
+            }
+            _ => Shadow::default(),
+        }
+    }
+}
+
+impl From<String> for Shadow {
+    fn from(s: String) -> Self {
+        Self::from(&s[..])
+    }
+}
+
+impl From<Value> for Shadow {
+    fn from(v: Value) -> Self {
+        let value = v.get::<String>();
+        Shadow::from(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into() {
+        let shadow: Shadow = "0px 4px 12px 2px rgba(0, 0, 0, 0.5)".into();
+        assert_eq!(
+            shadow,
+            Shadow::new((0.0, 4.0), 12.0, 2.0, Color::rgba(0, 0, 0, 127))
+        );
+
+        let shadow: Shadow = "1 2 #ff0000".into();
+        assert_eq!(shadow, Shadow::new((1.0, 2.0), 0.0, 0.0, "#ff0000"));
+
+        let shadow: Shadow = "none".into();
+        assert!(shadow.is_none());
+
+        let shadow: Shadow = "1 black".into();
+        assert!(shadow.is_none());
+
+        let shadow: Shadow = "1% 2px black".into();
+        assert!(shadow.is_none());
+    }
+
+    #[test]
+    fn test_set() {
+        let mut shadow = Shadow::default();
+        assert!(shadow.is_none());
+
+        shadow.set_offset((1.0, 2.0));
+        shadow.set_blur(3.0);
+        shadow.set_spread(4.0);
+        shadow.set_color(Color::rgb(1, 2, 3));
+
+        assert!(!shadow.is_none());
+        assert_eq!(shadow.offset(), Point::new(1.0, 2.0));
+        assert!(crate::f64_cmp(shadow.blur(), 3.0));
+        assert!(crate::f64_cmp(shadow.spread(), 4.0));
+        assert_eq!(shadow.color(), Color::rgb(1, 2, 3));
+    }
+}
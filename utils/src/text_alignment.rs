@@ -1,3 +1,5 @@
+use crate::Value;
+
 /// Used to align a text.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum TextAlignment {
@@ -8,6 +10,12 @@ pub enum TextAlignment {
     End,
 }
 
+impl Default for TextAlignment {
+    fn default() -> Self {
+        TextAlignment::Left
+    }
+}
+
 impl ToString for TextAlignment {
     fn to_string(&self) -> String {
         match self {
@@ -19,3 +27,44 @@ impl ToString for TextAlignment {
         }
     }
 }
+
+impl From<&str> for TextAlignment {
+    fn from(t: &str) -> Self {
+        match t {
+            "Right" | "right" => TextAlignment::Right,
+            "Center" | "center" => TextAlignment::Center,
+            "Start" | "start" => TextAlignment::Start,
+            "End" | "end" => TextAlignment::End,
+            _ => TextAlignment::Left,
+        }
+    }
+}
+
+impl From<String> for TextAlignment {
+    fn from(s: String) -> TextAlignment {
+        Self::from(&s[..])
+    }
+}
+
+impl From<Value> for TextAlignment {
+    fn from(v: Value) -> Self {
+        let value = v.get::<String>();
+        TextAlignment::from(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(TextAlignment::from("center"), TextAlignment::Center);
+        assert_eq!(TextAlignment::from("End"), TextAlignment::End);
+        assert_eq!(TextAlignment::from("unknown"), TextAlignment::Left);
+        assert_eq!(
+            TextAlignment::from(TextAlignment::Right.to_string()),
+            TextAlignment::Right
+        );
+    }
+}